@@ -249,6 +249,10 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 parameter_types! {
@@ -262,6 +266,9 @@ impl pallet_transaction_payment::Config for Runtime {
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
+	type BlackListAccounts = ();
+	type FeeExemption = ();
+	type FreeTransactionsPerBlock = ConstU32<0>;
 }
 
 impl pallet_sudo::Config for Runtime {
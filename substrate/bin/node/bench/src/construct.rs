@@ -67,6 +67,8 @@ impl core::BenchmarkDescription for ConstructionBenchmarkDescription {
 			BlockType::RandomTransfersKeepAlive => path.push("transfer"),
 			BlockType::RandomTransfersReaping => path.push("transfer_reaping"),
 			BlockType::Noop => path.push("noop"),
+			BlockType::RandomTransfersWithMemo => path.push("transfer_with_memo"),
+			BlockType::LiquidStakes => path.push("liquid_stake"),
 		}
 
 		match self.database_type {
@@ -97,6 +97,8 @@ fn main() {
 			BlockType::RandomTransfersKeepAlive,
 			BlockType::RandomTransfersReaping,
 			BlockType::Noop,
+			BlockType::RandomTransfersWithMemo,
+			BlockType::LiquidStakes,
 		] {
 			for database_type in [BenchDataBaseType::RocksDb, BenchDataBaseType::ParityDb] {
 				import_benchmarks.push((size, block_type, database_type));
@@ -21,10 +21,12 @@
 use grandpa_primitives::AuthorityId as GrandpaId;
 use kitchensink_runtime::{
 	constants::currency::*, wasm_binary_unwrap, BabeConfig, BalancesConfig, Block, CouncilConfig,
-	DemocracyConfig, ElectionsConfig, ImOnlineConfig, IndicesConfig, MaxNominations,
-	NominationPoolsConfig, SessionConfig, SessionKeys, SocietyConfig, StakerStatus, StakingConfig,
-	SudoConfig, SystemConfig, TechnicalCommitteeConfig,
+	DemocracyConfig, ElectionsConfig, ImOnlineConfig, IndicesConfig, LiquidStakingConfig,
+	MaxNominations, NominationPoolsConfig, RewardConfig, SessionConfig, SessionKeys,
+	SocietyConfig, StakerStatus, StakingConfig, SudoConfig, SystemConfig,
+	TechnicalCommitteeConfig,
 };
+use liquid_staking_primitives::SSAITA;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sc_chain_spec::ChainSpecExtension;
 use sc_service::ChainType;
@@ -291,6 +293,9 @@ pub fn testnet_genesis(
 		system: SystemConfig { code: wasm_binary_unwrap().to_vec(), ..Default::default() },
 		balances: BalancesConfig {
 			balances: endowed_accounts.iter().cloned().map(|x| (x, ENDOWMENT)).collect(),
+			// The root key may mint and burn up to an endowment per mint period.
+			members: vec![(root_key.clone(), ENDOWMENT, ENDOWMENT)],
+			..Default::default()
 		},
 		indices: IndicesConfig { indices: vec![] },
 		session: SessionConfig {
@@ -331,7 +336,7 @@ pub fn testnet_genesis(
 				.collect(),
 			phantom: Default::default(),
 		},
-		sudo: SudoConfig { key: Some(root_key) },
+		sudo: SudoConfig { key: Some(root_key.clone()) },
 		babe: BabeConfig {
 			epoch_config: Some(kitchensink_runtime::BABE_GENESIS_EPOCH_CONFIG),
 			..Default::default()
@@ -344,8 +349,13 @@ pub fn testnet_genesis(
 		society: SocietyConfig { pot: 0 },
 		vesting: Default::default(),
 		assets: pallet_assets::GenesisConfig {
-			// This asset is used by the NIS pallet as counterpart currency.
-			assets: vec![(9, get_account_id_from_seed::<sr25519::Public>("Alice"), true, 1)],
+			assets: vec![
+				// This asset is used by the NIS pallet as counterpart currency.
+				(9, get_account_id_from_seed::<sr25519::Public>("Alice"), true, 1),
+				// The liquid currency minted by the liquid staking pallet.
+				(SSAITA, root_key, true, 1),
+			],
+			metadata: vec![(SSAITA, b"Staked SAITA".to_vec(), b"sSAITA".to_vec(), 12)],
 			..Default::default()
 		},
 		pool_assets: Default::default(),
@@ -361,6 +371,8 @@ pub fn testnet_genesis(
 			..Default::default()
 		},
 		glutton: Default::default(),
+		reward: RewardConfig { reward_percent: 8, treasury_funds: ENDOWMENT },
+		liquid_staking: LiquidStakingConfig { vault_funds: DOLLARS, ..Default::default() },
 	}
 }
 
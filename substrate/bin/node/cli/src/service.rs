@@ -26,8 +26,9 @@ use frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE;
 use frame_system_rpc_runtime_api::AccountNonceApi;
 use futures::prelude::*;
 use kitchensink_runtime::RuntimeApi;
+use liquid_staking_primitives::CurrencyId;
 use node_executor::ExecutorDispatch;
-use node_primitives::Block;
+use node_primitives::{AccountId, Balance, Block};
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_babe::{self, SlotProportion};
 use sc_executor::NativeElseWasmExecutor;
@@ -609,6 +610,15 @@ pub fn new_full_base(
 		);
 	}
 
+	task_manager.spawn_handle().spawn(
+		"memo-index",
+		None,
+		sc_memo_index::start::<_, _, _, AccountId, Balance, CurrencyId>(
+			client.clone(),
+			backend.clone(),
+		),
+	);
+
 	network_starter.start_network();
 	Ok(NewFullBase {
 		task_manager,
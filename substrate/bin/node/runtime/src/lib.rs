@@ -31,6 +31,7 @@ use sp_runtime::traits::Zero;
 use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
+	genesis_builder_helper::{build_config, create_default_config},
 	instances::{Instance1, Instance2},
 	ord_parameter_types,
 	pallet_prelude::Get,
@@ -75,6 +76,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
 	create_runtime_str,
+	curve::PiecewiseLinear,
 	generic, impl_opaque_keys,
 	traits::{
 		self, AccountIdConversion, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, NumberFor,
@@ -95,6 +97,8 @@ pub use frame_system::Call as SystemCall;
 #[cfg(any(feature = "std", test))]
 pub use pallet_balances::Call as BalancesCall;
 #[cfg(any(feature = "std", test))]
+pub use pallet_liquid_staking::Call as LiquidStakingCall;
+#[cfg(any(feature = "std", test))]
 pub use pallet_staking::StakerStatus;
 #[cfg(any(feature = "std", test))]
 pub use pallet_sudo::Call as SudoCall;
@@ -505,6 +509,8 @@ parameter_types! {
 	// This number may need to be adjusted in the future if this assumption no longer holds true.
 	pub const MaxLocks: u32 = 50;
 	pub const MaxReserves: u32 = 50;
+	// Long enough for an exchange deposit reference or an invoice number.
+	pub const MaxMemoLen: u32 = 256;
 }
 
 impl pallet_balances::Config for Runtime {
@@ -517,10 +523,17 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = frame_system::Pallet<Runtime>;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
+	type FreezeIdentifier = [u8; 8];
+	type MaxFreezes = ConstU32<4>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxHolds = ConstU32<2>;
+	type BlacklistOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+	>;
+	type MaxMemoLen = MaxMemoLen;
+	type MaxMembers = ConstU32<100>;
+	type MaxPendingMints = ConstU32<10>;
 }
 
 parameter_types! {
@@ -530,6 +543,20 @@ parameter_types! {
 	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100_000);
 	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000u128);
 	pub MaximumMultiplier: Multiplier = Bounded::max_value();
+	pub const FreeTransactionsPerBlock: u32 = 4;
+}
+
+/// Validators don't pay for running their validator: the staking, session and im-online calls
+/// of a validator's stash or controller are free, up to `FreeTransactionsPerBlock` per block.
+pub struct ValidatorFeeExemption;
+impl pallet_transaction_payment::FeeExemption<AccountId, RuntimeCall> for ValidatorFeeExemption {
+	fn is_exempt(who: &AccountId, call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Staking(..) | RuntimeCall::Session(..) | RuntimeCall::ImOnline(..)
+		) && pallet_staking::StashOf::<Runtime>::convert(who.clone())
+			.map_or(false, |stash| pallet_staking::Validators::<Runtime>::contains_key(stash))
+	}
 }
 
 impl pallet_transaction_payment::Config for Runtime {
@@ -546,9 +573,8 @@ impl pallet_transaction_payment::Config for Runtime {
 		MinimumMultiplier,
 		MaximumMultiplier,
 	>;
-	type ValidatorIdOf = pallet_staking::StashOf<Self>;
-	type ValidatorSet = Historical;
-	type DataProvider = <Runtime as pallet_election_provider_multi_phase::Config>::DataProvider;
+	type FeeExemption = ValidatorFeeExemption;
+	type FreeTransactionsPerBlock = FreeTransactionsPerBlock;
 }
 
 impl pallet_asset_tx_payment::Config for Runtime {
@@ -659,7 +685,7 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = HistoryDepth;
-	type EventListeners = NominationPools;
+	type EventListeners = (NominationPools, LiquidStaking);
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
 	type RewardDistribution = Reward;
@@ -670,6 +696,9 @@ parameter_types! {
 	pub const NativeCurrencyId: CurrencyId = SAITA;
 	pub const StakingPalletId: PalletId = PalletId(*b"par/lqsk");
 	pub const MinStake: Balance = 10;
+	pub const InstantUnstakeFee: Permill = Permill::from_parts(3_000);
+	pub const MaxLiquidStakingCommission: Perbill = Perbill::from_percent(20);
+	pub const MaxLiquidStakingFee: Permill = Permill::from_percent(1);
 }
 
 pub struct Decimal;
@@ -690,10 +719,60 @@ impl DecimalProvider<CurrencyId> for Decimal {
 }
 
 impl pallet_currency_adapter::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type Assets = Assets;
     type Balances = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FreezeIdentifier = [u8; 8];
+    type MaxHolds = ConstU32<2>;
+    type MaxFreezes = ConstU32<4>;
     type GetNativeCurrencyId = NativeCurrencyId;
     type LockOrigin =EnsureRoot<AccountId>;
+    type MaxMemoLen = MaxMemoLen;
+    type WeightInfo = pallet_currency_adapter::weights::SubstrateWeight<Runtime>;
+}
+
+/// Creates the liquid currency the liquid staking benchmarks mint and burn.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct LiquidStakingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_liquid_staking::BenchmarkHelper<u32> for LiquidStakingBenchmarkHelper {
+	fn create_liquid_currency() {
+		let owner: AccountId = StakingPalletId::get().into_account_truncating();
+		let _ = Assets::force_create(
+			RuntimeOrigin::root(),
+			LiquidCurrency::get().into(),
+			owner.into(),
+			true,
+			1,
+		);
+		let _ = Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			LiquidCurrency::get().into(),
+			b"Staked SAITA".to_vec(),
+			b"sSAITA".to_vec(),
+			12,
+			false,
+		);
+	}
+
+	fn create_ticket_collection() -> u32 {
+		let owner: AccountId = StakingPalletId::get().into_account_truncating();
+		let collection = pallet_nfts::NextCollectionId::<Runtime>::get().unwrap_or_default();
+		let _ = Nfts::force_create(
+			RuntimeOrigin::root(),
+			owner.into(),
+			pallet_nfts::CollectionConfig {
+				settings: pallet_nfts::CollectionSettings::from_disabled(
+					pallet_nfts::CollectionSetting::DepositRequired.into(),
+				),
+				max_supply: None,
+				mint_settings: Default::default(),
+			},
+		);
+		collection
+	}
 }
 
 impl pallet_liquid_staking::Config for Runtime{
@@ -704,13 +783,54 @@ impl pallet_liquid_staking::Config for Runtime{
 	type PalletId = StakingPalletId;
 	type MinStake = MinStake;
 	type Balances = Balances;
-	
-}
+	type StrategyOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+	>;
+	type MaxTargets = ConstU32<MAX_QUOTA_NOMINATIONS>;
+	type MaxUnlockings = ConstU32<32>;
+	type InstantUnstakeFee = InstantUnstakeFee;
+	type FeeOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
+	>;
+	type FeeCollector = TreasuryAccount;
+	type MaxCommission = MaxLiquidStakingCommission;
+	type MaxFee = MaxLiquidStakingFee;
+	type Nfts = Nfts;
+	type TicketItemConfig = pallet_nfts::ItemConfig;
+	type TicketCollectionId = <Self as pallet_nfts::Config>::CollectionId;
+	type WeightInfo = pallet_liquid_staking::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = LiquidStakingBenchmarkHelper;
+}
+
+/// The yearly era payout as a share of the issuance, keyed on the staking ratio: 2.5% with
+/// nothing at stake, rising to 10% at the ideal ratio of 50% and falling back towards 2.5%
+/// beyond it, halving the excess every 5%.
+const REWARD_CURVE: PiecewiseLinear<'static> = PiecewiseLinear {
+	points: &[
+		(Perbill::from_parts(0), Perbill::from_parts(25_000_000)),
+		(Perbill::from_parts(500_000_000), Perbill::from_parts(100_000_000)),
+		(Perbill::from_parts(550_000_000), Perbill::from_parts(62_500_000)),
+		(Perbill::from_parts(600_000_000), Perbill::from_parts(43_750_000)),
+		(Perbill::from_parts(650_000_000), Perbill::from_parts(34_375_000)),
+		(Perbill::from_parts(700_000_000), Perbill::from_parts(29_687_500)),
+		(Perbill::from_parts(800_000_000), Perbill::from_parts(25_937_500)),
+		(Perbill::from_parts(1_000_000_000), Perbill::from_parts(25_000_000)),
+	],
+	maximum: Perbill::from_parts(100_000_000),
+};
 
 parameter_types! {
 	pub const EraMinutes:u32 = 1;
-	pub const DecimalPrecision:u32 = 12;
 	pub const TotalMinutesPerYear:u32 = 525600; 
+	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
+	pub const MaxEraPayout: Balance = 1_000_000 * DOLLARS;
+	// Half of every era payout is new issuance, the other half comes out of the treasury.
+	pub const MintedShare: Perbill = Perbill::from_percent(50);
+	pub const OnTreasuryShortfall: pallet_reward::ShortfallFallback =
+		pallet_reward::ShortfallFallback::Mint;
 }
 impl pallet_reward::Config for Runtime{
 	type Validators = Historical;
@@ -720,10 +840,15 @@ impl pallet_reward::Config for Runtime{
 	type ValidatorSet = Historical;
 	type TreasuryAccount = Treasury;
 	type RewardCurrency = Balances;
-	type Precision = DecimalPrecision;
 	type LiquidStakeVault = LiquidStaking;
 	type TotalMinutesPerYear = TotalMinutesPerYear;
 	type EraMinutes = EraMinutes;
+	type DistributionStepsPerBlock = ConstU32<4>;
+	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type MaxEraPayout = MaxEraPayout;
+	type MintedShare = MintedShare;
+	type OnTreasuryShortfall = OnTreasuryShortfall;
+	type WeightInfo = pallet_reward::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_fast_unstake::Config for Runtime {
@@ -1632,13 +1757,15 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
-	type Freezer = ();
+	type Freezer = OtherCurrencyAdapter;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type Blacklist = Balances;
+	type MaxMemoLen = MaxMemoLen;
 }
 
 ord_parameter_types! {
@@ -1666,6 +1793,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type CallbackHandle = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type Blacklist = Balances;
+	type MaxMemoLen = MaxMemoLen;
 }
 
 parameter_types! {
@@ -2177,6 +2306,14 @@ pub type SignedExtra = (
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+
+/// Whether the signer of `uxt` gets to dispatch it without paying a fee.
+fn is_fee_exempt(uxt: &UncheckedExtrinsic) -> bool {
+	uxt.signature
+		.as_ref()
+		.and_then(|(address, _, _)| Indices::lookup(address.clone()).ok())
+		.map_or(false, |who| TransactionPayment::is_fee_exempt(&who, &uxt.function))
+}
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
@@ -2197,6 +2334,13 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_liquid_staking::migration::v1::MigrateToV1<Runtime>,
+	pallet_liquid_staking::migration::v2::MigrateToV2<Runtime>,
+	pallet_balances::migration::v2::MigrateBlacklistToMap<Runtime>,
+	pallet_balances::migration::v3::BoundMembers<Runtime>,
+	pallet_liquid_staking::migration::v3::MigrateToV3<Runtime>,
+	pallet_reward::migration::v1::MigrateToV1<Runtime>,
+	pallet_reward::migration::v2::MigrateToV2<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2231,6 +2375,7 @@ mod benches {
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_contracts, Contracts]
 		[pallet_core_fellowship, CoreFellowship]
+		[pallet_currency_adapter, OtherCurrencyAdapter]
 		[pallet_democracy, Democracy]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
@@ -2242,6 +2387,7 @@ mod benches {
 		[pallet_identity, Identity]
 		[pallet_im_online, ImOnline]
 		[pallet_indices, Indices]
+		[pallet_liquid_staking, LiquidStaking]
 		[pallet_lottery, Lottery]
 		[pallet_membership, TechnicalMembership]
 		[pallet_message_queue, MessageQueue]
@@ -2255,6 +2401,7 @@ mod benches {
 		[pallet_referenda, Referenda]
 		[pallet_recovery, Recovery]
 		[pallet_remark, Remark]
+		[pallet_reward, Reward]
 		[pallet_salary, Salary]
 		[pallet_scheduler, Scheduler]
 		[pallet_glutton, Glutton]
@@ -2408,6 +2555,91 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_liquid_staking_runtime_api::LiquidStakingApi<Block, AccountId, Balance> for Runtime {
+		fn exchange_rate() -> pallet_liquid_staking_runtime_api::Rate {
+			LiquidStaking::api_exchange_rate()
+		}
+
+		fn staking_position(who: AccountId) -> pallet_liquid_staking_runtime_api::StakingPosition<Balance> {
+			LiquidStaking::api_staking_position(who)
+		}
+	}
+
+	impl pallet_reward_runtime_api::RewardApi<Block, AccountId, Balance> for Runtime {
+		fn validator_pending_reward(validator: AccountId) -> Balance {
+			Reward::api_validator_pending_reward(validator)
+		}
+
+		fn nominator_pending_rewards(nominator: AccountId) -> Vec<(AccountId, Balance)> {
+			Reward::api_nominator_pending_rewards(nominator)
+		}
+
+		fn total_rewards(who: AccountId) -> Balance {
+			Reward::total_rewards(who)
+		}
+
+		fn queued_validators() -> Vec<AccountId> {
+			Reward::api_queued_validators()
+		}
+
+		fn reward_percent() -> pallet_reward_runtime_api::RewardPercentInfo {
+			Reward::api_reward_percent()
+		}
+
+		fn projected_apr(validator: AccountId) -> Perbill {
+			Reward::api_projected_apr(validator)
+		}
+
+		fn reward_history(
+			who: AccountId,
+		) -> Vec<pallet_reward_runtime_api::EraRewardRecord<AccountId, Balance>> {
+			Reward::api_reward_history(who)
+		}
+	}
+
+	impl sp_memo_index::MemoApi<Block, AccountId, Balance, CurrencyId> for Runtime {
+		fn memo_transfers() -> Vec<sp_memo_index::MemoTransfer<AccountId, Balance, CurrencyId>> {
+			System::read_events_no_consensus()
+				.enumerate()
+				.filter_map(|(index, record)| {
+					let (asset, from, to, amount, memo) = match record.event {
+						RuntimeEvent::Balances(pallet_balances::Event::TransferWithMemo {
+							from,
+							to,
+							amount,
+							memo,
+						}) => (None, from, to, amount, memo),
+						RuntimeEvent::Assets(pallet_assets::Event::TransferredWithMemo {
+							asset_id,
+							from,
+							to,
+							amount,
+							memo,
+						}) => (Some(asset_id), from, to, amount, memo),
+						RuntimeEvent::OtherCurrencyAdapter(
+							pallet_currency_adapter::Event::TransferredWithMemo {
+								asset,
+								from,
+								to,
+								amount,
+								memo,
+							},
+						) => ((asset != NativeCurrencyId::get()).then_some(asset), from, to, amount, memo),
+						_ => return None,
+					};
+					Some(sp_memo_index::MemoTransfer {
+						event_index: index as u32,
+						asset,
+						from,
+						to,
+						amount,
+						memo: memo.into_inner(),
+					})
+				})
+				.collect()
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -2560,9 +2792,17 @@ impl_runtime_apis! {
 		Balance,
 	> for Runtime {
 		fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
-			TransactionPayment::query_info(uxt, len)
+			let exempt = is_fee_exempt(&uxt);
+			let mut info = TransactionPayment::query_info(uxt, len);
+			if exempt {
+				info.partial_fee = 0;
+			}
+			info
 		}
 		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> FeeDetails<Balance> {
+			if is_fee_exempt(&uxt) {
+				return FeeDetails { inclusion_fee: None, tip: 0 }
+			}
 			TransactionPayment::query_fee_details(uxt, len)
 		}
 		fn query_weight_to_fee(weight: Weight) -> Balance {
@@ -2716,6 +2956,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+		fn create_default_config() -> Vec<u8> {
+			create_default_config::<RuntimeGenesisConfig>()
+		}
+
+		fn build_config(config: Vec<u8>) -> sp_genesis_builder::Result {
+			build_config::<RuntimeGenesisConfig>(config)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
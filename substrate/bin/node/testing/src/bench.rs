@@ -35,8 +35,8 @@ use crate::{
 use codec::{Decode, Encode};
 use futures::executor;
 use kitchensink_runtime::{
-	constants::currency::DOLLARS, AccountId, BalancesCall, CheckedExtrinsic, MinimumPeriod,
-	RuntimeCall, Signature, SystemCall, UncheckedExtrinsic,
+	constants::currency::DOLLARS, AccountId, BalancesCall, CheckedExtrinsic, LiquidStakingCall,
+	MinimumPeriod, RuntimeCall, Signature, SystemCall, UncheckedExtrinsic,
 };
 use node_primitives::Block;
 use sc_block_builder::BlockBuilderProvider;
@@ -190,6 +190,10 @@ pub enum BlockType {
 	RandomTransfersReaping,
 	/// Bunch of "no-op" calls.
 	Noop,
+	/// Bunch of random transfers carrying a memo.
+	RandomTransfersWithMemo,
+	/// Bunch of stakes into the liquid staking pool.
+	LiquidStakes,
 }
 
 impl BlockType {
@@ -315,6 +319,17 @@ impl<'a> Iterator for BlockContentIterator<'a> {
 					},
 					BlockType::Noop =>
 						RuntimeCall::System(SystemCall::remark { remark: Vec::new() }),
+					BlockType::RandomTransfersWithMemo =>
+						RuntimeCall::Balances(BalancesCall::transfer_with_memo {
+							dest: sp_runtime::MultiAddress::Id(receiver),
+							value: kitchensink_runtime::ExistentialDeposit::get() + 1,
+							memo: format!("bench transfer {}", self.iteration)
+								.into_bytes()
+								.try_into()
+								.expect("memo is shorter than MaxMemoLen; qed"),
+						}),
+					BlockType::LiquidStakes =>
+						RuntimeCall::LiquidStaking(LiquidStakingCall::stake { amount: DOLLARS }),
 				},
 			},
 			self.runtime_version.spec_version,
@@ -53,7 +53,7 @@ pub fn config_endowed(code: Option<&[u8]>, extra_endowed: Vec<AccountId>) -> Run
 			..Default::default()
 		},
 		indices: IndicesConfig { indices: vec![] },
-		balances: BalancesConfig { balances: endowed },
+		balances: BalancesConfig { balances: endowed, ..Default::default() },
 		session: SessionConfig {
 			keys: vec![
 				(alice(), dave(), to_session_keys(&Ed25519Keyring::Alice, &Sr25519Keyring::Alice)),
@@ -94,7 +94,12 @@ pub fn config_endowed(code: Option<&[u8]>, extra_endowed: Vec<AccountId>) -> Run
 		treasury: Default::default(),
 		society: SocietyConfig { pot: 0 },
 		vesting: Default::default(),
-		assets: AssetsConfig { assets: vec![(9, alice(), true, 1)], ..Default::default() },
+		assets: AssetsConfig {
+			// Asset 1 is the liquid staking currency, minted by the liquid staking benchmarks.
+			assets: vec![(1, alice(), true, 1), (9, alice(), true, 1)],
+			metadata: vec![(1, b"Staked SAITA".to_vec(), b"sSAITA".to_vec(), 12)],
+			..Default::default()
+		},
 		pool_assets: Default::default(),
 		transaction_storage: Default::default(),
 		transaction_payment: Default::default(),
@@ -109,5 +114,7 @@ pub fn config_endowed(code: Option<&[u8]>, extra_endowed: Vec<AccountId>) -> Run
 			trash_data_count: Default::default(),
 			..Default::default()
 		},
+		reward: Default::default(),
+		liquid_staking: Default::default(),
 	}
 }
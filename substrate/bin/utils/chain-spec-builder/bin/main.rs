@@ -17,7 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use chain_spec_builder::{
-	generate_authority_keys_and_store, generate_chain_spec, print_seeds, ChainSpecBuilder,
+	generate_authority_keys_and_store, generate_chain_spec, generate_chain_spec_for_runtime,
+	patch_chain_spec_genesis, print_seeds, verify_chain_spec, ChainSpecBuilder,
 };
 use clap::Parser;
 use node_cli::chain_spec;
@@ -34,7 +35,7 @@ fn main() -> Result<(), String> {
 	);
 
 	let builder = ChainSpecBuilder::parse();
-	let chain_spec_path = builder.chain_spec_path().to_path_buf();
+	let chain_spec_path = builder.chain_spec_path().map(|path| path.to_path_buf());
 
 	let (authority_seeds, nominator_accounts, endowed_accounts, sudo_account) = match builder {
 		ChainSpecBuilder::Generate { authorities, nominators, endowed, keystore_path, .. } => {
@@ -80,10 +81,37 @@ fn main() -> Result<(), String> {
 			sudo_account,
 			..
 		} => (authority_seeds, nominator_accounts, endowed_accounts, sudo_account),
+		ChainSpecBuilder::CreateFromRuntime {
+			runtime_wasm_path,
+			patch_path,
+			name,
+			id,
+			chain_type,
+			chain_spec_path,
+		} => {
+			let json = generate_chain_spec_for_runtime(
+				&runtime_wasm_path,
+				patch_path.as_deref(),
+				&name,
+				&id,
+				&chain_type,
+			)?;
+			return fs::write(chain_spec_path, json).map_err(|err| err.to_string())
+		},
+		ChainSpecBuilder::PatchGenesis { input_chain_spec, patch_path, chain_spec_path } => {
+			let json = patch_chain_spec_genesis(&input_chain_spec, &patch_path)?;
+			return fs::write(chain_spec_path, json).map_err(|err| err.to_string())
+		},
+		ChainSpecBuilder::Verify { input_chain_spec } => {
+			verify_chain_spec(&input_chain_spec)?;
+			println!("{} is valid", input_chain_spec.display());
+			return Ok(())
+		},
 	};
 
 	let json =
 		generate_chain_spec(authority_seeds, nominator_accounts, endowed_accounts, sudo_account)?;
 
+	let chain_spec_path = chain_spec_path.expect("`New` and `Generate` save the chain spec; qed");
 	fs::write(chain_spec_path, json).map_err(|err| err.to_string())
 }
@@ -24,18 +24,30 @@
 //! substrate-based nodes. This particular binary is capable of building a more sophisticated chain
 //! specification that can be used with the substrate-node, ie. [`node-cli`].
 //!
+//! The `create-from-runtime`, `patch-genesis` and `verify` commands work with nothing but a
+//! runtime Wasm blob: the genesis storage is built by the runtime itself, through its
+//! `GenesisBuilder` API.
+//!
 //! See [`ChainSpecBuilder`] for a list of available commands.
 //!
 //! [`sc-chain-spec`]: ../sc_chain_spec/index.html
 //! [`node-cli`]: ../node_cli/index.html
 
-use std::path::{Path, PathBuf};
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
 
 use ansi_term::Style;
 use clap::Parser;
 
 use node_cli::chain_spec::{self, AccountId};
+use sc_chain_spec::{
+	json_merge, ChainType, GenericChainSpec, GenesisConfigBuilderRuntimeCaller,
+	RuntimeGenesisConfigJson,
+};
 use sc_keystore::LocalKeystore;
+use serde_json::Value;
 use sp_core::crypto::{ByteArray, Ss58Codec};
 use sp_keystore::KeystorePtr;
 
@@ -89,16 +101,162 @@ pub enum ChainSpecBuilder {
 		#[arg(long, short)]
 		keystore_path: Option<PathBuf>,
 	},
+	/// Create a new chain spec from a runtime Wasm blob, no native runtime involved.
+	///
+	/// The genesis config is the runtime's default one, or the given patch of it.
+	CreateFromRuntime {
+		/// The path to the runtime Wasm blob.
+		#[arg(long, short)]
+		runtime_wasm_path: PathBuf,
+		/// The path to a JSON patch of the runtime's default genesis config.
+		///
+		/// Without it the complete default genesis config is written to the chain spec.
+		#[arg(long, short)]
+		patch_path: Option<PathBuf>,
+		/// The name of the chain.
+		#[arg(long, short, default_value = "Custom")]
+		name: String,
+		/// The id of the chain.
+		#[arg(long, short, default_value = "custom")]
+		id: String,
+		/// The type of the chain: `development`, `local`, `live` or any custom type.
+		#[arg(long, short = 't', default_value = "live")]
+		chain_type: String,
+		/// The path where the chain spec should be saved.
+		#[arg(long, short, default_value = "./chain_spec.json")]
+		chain_spec_path: PathBuf,
+	},
+	/// Merge a JSON patch into the genesis config of a chain spec.
+	///
+	/// Objects are merged key by key, a `null` removes the key, any other value replaces the
+	/// existing one.
+	PatchGenesis {
+		/// The path to the chain spec to patch.
+		#[arg(long, short)]
+		input_chain_spec: PathBuf,
+		/// The path to the JSON patch.
+		#[arg(long, short)]
+		patch_path: PathBuf,
+		/// The path where the patched chain spec should be saved.
+		#[arg(long, short, default_value = "./chain_spec.json")]
+		chain_spec_path: PathBuf,
+	},
+	/// Check that the runtime of a chain spec builds its genesis storage from the genesis
+	/// config of the chain spec.
+	Verify {
+		/// The path to the chain spec to verify.
+		#[arg(long, short)]
+		input_chain_spec: PathBuf,
+	},
 }
 
 impl ChainSpecBuilder {
-	/// Returns the path where the chain spec should be saved.
-	pub fn chain_spec_path(&self) -> &Path {
+	/// Returns the path where the chain spec should be saved, `None` if the command saves none.
+	pub fn chain_spec_path(&self) -> Option<&Path> {
 		match self {
-			ChainSpecBuilder::New { chain_spec_path, .. } => chain_spec_path.as_path(),
-			ChainSpecBuilder::Generate { chain_spec_path, .. } => chain_spec_path.as_path(),
+			ChainSpecBuilder::New { chain_spec_path, .. } |
+			ChainSpecBuilder::Generate { chain_spec_path, .. } |
+			ChainSpecBuilder::CreateFromRuntime { chain_spec_path, .. } |
+			ChainSpecBuilder::PatchGenesis { chain_spec_path, .. } => Some(chain_spec_path.as_path()),
+			ChainSpecBuilder::Verify { .. } => None,
+		}
+	}
+}
+
+/// A chain spec with any extensions, which are kept as they are.
+type RuntimeChainSpec = GenericChainSpec<(), serde_json::Map<String, Value>>;
+
+fn read_json(path: &Path) -> Result<Value, String> {
+	let json = fs::read(path)
+		.map_err(|err| format!("Failed to read `{}`: {}", path.display(), err))?;
+	serde_json::from_slice(&json)
+		.map_err(|err| format!("Failed to parse `{}`: {}", path.display(), err))
+}
+
+fn parse_chain_type(chain_type: &str) -> ChainType {
+	match chain_type.to_lowercase().as_str() {
+		"development" | "dev" => ChainType::Development,
+		"local" => ChainType::Local,
+		"live" => ChainType::Live,
+		_ => ChainType::Custom(chain_type.to_owned()),
+	}
+}
+
+/// Generate a chain spec whose genesis storage is built by the runtime at `runtime_wasm_path`.
+///
+/// The genesis config is the runtime's default one patched by the JSON at `patch_path`, or the
+/// complete default one if no patch is given.
+pub fn generate_chain_spec_for_runtime(
+	runtime_wasm_path: &Path,
+	patch_path: Option<&Path>,
+	name: &str,
+	id: &str,
+	chain_type: &str,
+) -> Result<String, String> {
+	let code = fs::read(runtime_wasm_path).map_err(|err| {
+		format!("Failed to read the runtime `{}`: {}", runtime_wasm_path.display(), err)
+	})?;
+
+	let genesis = match patch_path {
+		Some(patch_path) => RuntimeGenesisConfigJson::Patch(read_json(patch_path)?),
+		None => RuntimeGenesisConfigJson::Config(
+			GenesisConfigBuilderRuntimeCaller::<()>::new(&code).get_default_config()?,
+		),
+	};
+
+	let chain_spec = RuntimeChainSpec::from_runtime_genesis(
+		name,
+		id,
+		parse_chain_type(chain_type),
+		code,
+		genesis,
+		None,
+		Default::default(),
+	);
+
+	chain_spec.as_json(false)
+}
+
+/// Merge the JSON patch at `patch_path` into the genesis config of the chain spec at
+/// `input_chain_spec`.
+///
+/// A patch of the runtime's default genesis config is patched further, a complete genesis
+/// config is patched in place. Raw genesis storage can't be patched.
+pub fn patch_chain_spec_genesis(
+	input_chain_spec: &Path,
+	patch_path: &Path,
+) -> Result<String, String> {
+	let mut chain_spec = read_json(input_chain_spec)?;
+	let patch = read_json(patch_path)?;
+
+	let genesis = chain_spec
+		.get_mut("genesis")
+		.ok_or_else(|| "The chain spec has no genesis".to_string())?;
+	let config = if genesis.get("runtimeGenesis").is_some() {
+		let runtime_genesis = &mut genesis["runtimeGenesis"];
+		if runtime_genesis.get("patch").is_some() {
+			runtime_genesis.get_mut("patch")
+		} else {
+			runtime_genesis.get_mut("config")
 		}
+	} else {
+		genesis.get_mut("runtime")
 	}
+	.ok_or_else(|| "Only a genesis config can be patched, not raw genesis storage".to_string())?;
+
+	json_merge(config, patch);
+
+	serde_json::to_string_pretty(&chain_spec)
+		.map_err(|err| format!("Failed to serialize the chain spec: {}", err))
+}
+
+/// Build the genesis storage of the chain spec at `input_chain_spec`.
+///
+/// For a genesis config built through the `GenesisBuilder` API this checks that the runtime
+/// of the chain spec accepts it.
+pub fn verify_chain_spec(input_chain_spec: &Path) -> Result<(), String> {
+	let chain_spec = RuntimeChainSpec::from_json_file(input_chain_spec.to_path_buf())?;
+	chain_spec.as_json(true).map(drop)
 }
 
 fn genesis_constructor(
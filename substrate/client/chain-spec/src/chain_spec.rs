@@ -19,7 +19,10 @@
 //! Substrate chain configurations.
 #![warn(missing_docs)]
 
-use crate::{extension::GetExtension, ChainType, Properties, RuntimeGenesis};
+use crate::{
+	extension::GetExtension, genesis_config_builder::GenesisConfigBuilderRuntimeCaller,
+	ChainType, Properties, RuntimeGenesis,
+};
 use sc_network::config::MultiaddrWithPeerId;
 use sc_telemetry::TelemetryEndpoints;
 use serde::{Deserialize, Serialize};
@@ -36,6 +39,7 @@ enum GenesisSource<G> {
 	Binary(Cow<'static, [u8]>),
 	Factory(Arc<dyn Fn() -> G + Send + Sync>),
 	Storage(Storage),
+	GenesisBuilderApi(RuntimeGenesisInner),
 }
 
 impl<G> Clone for GenesisSource<G> {
@@ -45,6 +49,7 @@ impl<G> Clone for GenesisSource<G> {
 			Self::Binary(ref d) => Self::Binary(d.clone()),
 			Self::Factory(ref f) => Self::Factory(f.clone()),
 			Self::Storage(ref s) => Self::Storage(s.clone()),
+			Self::GenesisBuilderApi(ref inner) => Self::GenesisBuilderApi(inner.clone()),
 		}
 	}
 }
@@ -104,6 +109,7 @@ impl<G: RuntimeGenesis> GenesisSource<G> {
 
 				Ok(Genesis::Raw(RawGenesis { top, children_default }))
 			},
+			Self::GenesisBuilderApi(inner) => Ok(Genesis::RuntimeGenesis(inner.clone())),
 		}
 	}
 }
@@ -112,6 +118,7 @@ impl<G: RuntimeGenesis, E> BuildStorage for ChainSpec<G, E> {
 	fn assimilate_storage(&self, storage: &mut Storage) -> Result<(), String> {
 		match self.genesis.resolve()? {
 			Genesis::Runtime(gc) => gc.assimilate_storage(storage),
+			Genesis::RuntimeGenesis(inner) => inner.assimilate_storage(storage),
 			Genesis::Raw(RawGenesis { top: map, children_default: children_map }) => {
 				storage.top.extend(map.into_iter().map(|(k, v)| (k.0, v.0)));
 				children_map.into_iter().for_each(|(k, v)| {
@@ -144,6 +151,63 @@ pub struct RawGenesis {
 	pub children_default: BTreeMap<StorageKey, GenesisStorage>,
 }
 
+impl From<Storage> for RawGenesis {
+	fn from(storage: Storage) -> Self {
+		let top = storage.top.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect();
+		let children_default = storage
+			.children_default
+			.into_iter()
+			.map(|(sk, child)| {
+				(
+					StorageKey(sk),
+					child.data.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect(),
+				)
+			})
+			.collect();
+
+		RawGenesis { top, children_default }
+	}
+}
+
+/// A genesis config of a runtime in JSON, which the runtime turns into the genesis storage
+/// through its `GenesisBuilder` API.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum RuntimeGenesisConfigJson {
+	/// The complete genesis config. Every field has to be given, no defaults are used.
+	Config(json::Value),
+	/// A patch of the runtime's default genesis config, see [`crate::json_merge`] for how it
+	/// is applied.
+	Patch(json::Value),
+}
+
+/// The runtime code together with the genesis config it builds the genesis storage from.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeGenesisInner {
+	#[serde(with = "sp_core::bytes")]
+	code: Vec<u8>,
+	#[serde(flatten)]
+	json_blob: RuntimeGenesisConfigJson,
+}
+
+impl BuildStorage for RuntimeGenesisInner {
+	fn assimilate_storage(&self, storage: &mut Storage) -> Result<(), String> {
+		let caller = GenesisConfigBuilderRuntimeCaller::<()>::new(&self.code[..]);
+		let built = match &self.json_blob {
+			RuntimeGenesisConfigJson::Config(config) =>
+				caller.get_storage_for_config(config.clone())?,
+			RuntimeGenesisConfigJson::Patch(patch) => caller.get_storage_for_patch(patch.clone())?,
+		};
+		built.assimilate_storage(storage)?;
+		// The config may leave the code out, the chain starts with the code that built it.
+		storage
+			.top
+			.insert(sp_core::storage::well_known_keys::CODE.to_vec(), self.code.clone());
+		Ok(())
+	}
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -152,6 +216,8 @@ enum Genesis<G> {
 	Raw(RawGenesis),
 	/// State root hash of the genesis storage.
 	StateRootHash(StorageData),
+	/// The runtime code and its genesis config, built through the `GenesisBuilder` runtime API.
+	RuntimeGenesis(RuntimeGenesisInner),
 }
 
 /// A configuration of a client. Does not include runtime storage initialization.
@@ -290,6 +356,44 @@ impl<G, E> ChainSpec<G, E> {
 		ChainSpec { client_spec, genesis: GenesisSource::Factory(Arc::new(constructor)) }
 	}
 
+	/// Create a spec whose genesis storage is built by the runtime `code` from `genesis`,
+	/// through the `GenesisBuilder` runtime API of the runtime.
+	///
+	/// Unlike [`Self::from_genesis`], this needs no native genesis config, the runtime blob is
+	/// all it takes.
+	pub fn from_runtime_genesis(
+		name: &str,
+		id: &str,
+		chain_type: ChainType,
+		code: Vec<u8>,
+		genesis: RuntimeGenesisConfigJson,
+		properties: Option<Properties>,
+		extensions: E,
+	) -> Self {
+		let client_spec = ClientSpec {
+			name: name.to_owned(),
+			id: id.to_owned(),
+			chain_type,
+			boot_nodes: Vec::new(),
+			telemetry_endpoints: None,
+			protocol_id: None,
+			fork_id: None,
+			properties,
+			extensions,
+			consensus_engine: (),
+			genesis: Default::default(),
+			code_substitutes: BTreeMap::new(),
+		};
+
+		ChainSpec {
+			client_spec,
+			genesis: GenesisSource::GenesisBuilderApi(RuntimeGenesisInner {
+				code,
+				json_blob: genesis,
+			}),
+		}
+	}
+
 	/// Type of the chain.
 	fn chain_type(&self) -> ChainType {
 		self.client_spec.chain_type.clone()
@@ -335,27 +439,8 @@ struct JsonContainer<G, E> {
 impl<G: RuntimeGenesis, E: serde::Serialize + Clone + 'static> ChainSpec<G, E> {
 	fn json_container(&self, raw: bool) -> Result<JsonContainer<G, E>, String> {
 		let genesis = match (raw, self.genesis.resolve()?) {
-			(true, Genesis::Runtime(g)) => {
-				let storage = g.build_storage()?;
-				let top =
-					storage.top.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect();
-				let children_default = storage
-					.children_default
-					.into_iter()
-					.map(|(sk, child)| {
-						(
-							StorageKey(sk),
-							child
-								.data
-								.into_iter()
-								.map(|(k, v)| (StorageKey(k), StorageData(v)))
-								.collect(),
-						)
-					})
-					.collect();
-
-				Genesis::Raw(RawGenesis { top, children_default })
-			},
+			(true, Genesis::Runtime(g)) => Genesis::Raw(g.build_storage()?.into()),
+			(true, Genesis::RuntimeGenesis(inner)) => Genesis::Raw(inner.build_storage()?.into()),
 			(_, genesis) => genesis,
 		};
 		Ok(JsonContainer { client_spec: self.client_spec.clone(), genesis })
@@ -506,6 +591,56 @@ mod tests {
 		assert_eq!(spec.extensions().my_property, "Test Extension");
 	}
 
+	#[test]
+	fn runtime_genesis_patch_round_trips() {
+		let spec = TestSpec::from_json_bytes(Cow::Owned(
+			include_bytes!("../res/chain_spec_runtime_genesis_patch.json").to_vec(),
+		))
+		.unwrap();
+
+		let json: json::Value = json::from_str(&spec.as_json(false).unwrap()).unwrap();
+		let expected: json::Value =
+			json::from_slice(include_bytes!("../res/chain_spec_runtime_genesis_patch.json"))
+				.unwrap();
+		assert_eq!(json["genesis"], expected["genesis"]);
+		assert_eq!(spec.chain_type(), ChainType::Local);
+	}
+
+	#[test]
+	fn runtime_genesis_patch_is_built_by_the_runtime() {
+		let code = substrate_test_runtime::wasm_binary_unwrap().to_vec();
+		let spec = ChainSpec::<(), NoExtension>::from_runtime_genesis(
+			"Test",
+			"test",
+			ChainType::Local,
+			code.clone(),
+			RuntimeGenesisConfigJson::Patch(json::json!({
+				"substrateTest": {
+					"authorities": ["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"],
+				},
+			})),
+			None,
+			None,
+		);
+
+		let storage = spec.build_storage().unwrap();
+		assert_eq!(storage.top.get(sp_core::storage::well_known_keys::CODE), Some(&code));
+		// SubstrateTest|Authorities
+		assert!(storage.top.contains_key(
+			&array_bytes::hex2bytes(
+				"00771836bebdd29870ff246d305c578c5e0621c4869aa60c02be9adcc98a0d1d"
+			)
+			.unwrap()
+		));
+
+		// The raw spec holds the very same storage.
+		let raw: json::Value = json::from_str(&spec.as_json(true).unwrap()).unwrap();
+		assert_eq!(
+			raw["genesis"]["raw"]["top"]["0x3a636f6465"],
+			json::Value::String(array_bytes::bytes2hex("0x", &code))
+		);
+	}
+
 	#[test]
 	fn chain_spec_raw_output_should_be_deterministic() {
 		let mut spec = TestSpec2::from_json_bytes(Cow::Owned(
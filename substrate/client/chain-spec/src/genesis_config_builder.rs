@@ -0,0 +1,176 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Building the genesis storage through the `GenesisBuilder` runtime API of a runtime blob.
+
+use codec::{Decode, Encode};
+use sc_executor::{error::Result, WasmExecutor};
+use serde_json::{from_slice, Value};
+use sp_core::{
+	storage::Storage,
+	traits::{CallContext, CodeExecutor, Externalities, FetchRuntimeCode, RuntimeCode},
+};
+use sp_genesis_builder::Result as BuildResult;
+use sp_state_machine::BasicExternalities;
+use sp_wasm_interface::HostFunctions;
+use std::borrow::Cow;
+
+/// Calls the `GenesisBuilder` runtime API of a runtime Wasm blob, no node required.
+///
+/// `EHF` are the host functions the runtime needs on top of `sp_io::SubstrateHostFunctions`.
+/// Missing host functions are stubbed, building the genesis storage should not need them.
+pub struct GenesisConfigBuilderRuntimeCaller<'a, EHF = ()>
+where
+	EHF: HostFunctions,
+{
+	code: Cow<'a, [u8]>,
+	code_hash: Vec<u8>,
+	executor: WasmExecutor<(sp_io::SubstrateHostFunctions, EHF)>,
+}
+
+impl<'a, EHF> FetchRuntimeCode for GenesisConfigBuilderRuntimeCaller<'a, EHF>
+where
+	EHF: HostFunctions,
+{
+	fn fetch_runtime_code(&self) -> Option<Cow<[u8]>> {
+		Some(self.code.as_ref().into())
+	}
+}
+
+impl<'a, EHF> GenesisConfigBuilderRuntimeCaller<'a, EHF>
+where
+	EHF: HostFunctions,
+{
+	/// Create a caller for the runtime `code`.
+	pub fn new(code: &'a [u8]) -> Self {
+		GenesisConfigBuilderRuntimeCaller {
+			code: code.into(),
+			code_hash: sp_core::blake2_256(code).to_vec(),
+			executor: WasmExecutor::<(sp_io::SubstrateHostFunctions, EHF)>::builder()
+				.with_allow_missing_host_functions(true)
+				.build(),
+		}
+	}
+
+	fn call(&self, ext: &mut dyn Externalities, method: &str, data: &[u8]) -> Result<Vec<u8>> {
+		self.executor
+			.call(
+				ext,
+				&RuntimeCode { heap_pages: None, code_fetcher: self, hash: self.code_hash.clone() },
+				method,
+				data,
+				false,
+				CallContext::Offchain,
+			)
+			.0
+	}
+
+	/// The default genesis config of the runtime, as JSON.
+	pub fn get_default_config(&self) -> core::result::Result<Value, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let call_result = self
+			.call(&mut ext, "GenesisBuilder_create_default_config", &[])
+			.map_err(|e| format!("wasm call error {e}"))?;
+		let default_config = Vec::<u8>::decode(&mut &call_result[..])
+			.map_err(|e| format!("scale codec error: {e}"))?;
+		from_slice(&default_config[..])
+			.map_err(|e| format!("the default genesis config is not valid JSON: {e}"))
+	}
+
+	/// The genesis storage the runtime builds from the complete genesis `config`.
+	pub fn get_storage_for_config(&self, config: Value) -> core::result::Result<Storage, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let call_result = self
+			.call(&mut ext, "GenesisBuilder_build_config", &config.to_string().encode())
+			.map_err(|e| format!("wasm call error {e}"))?;
+		BuildResult::decode(&mut &call_result[..])
+			.map_err(|e| format!("scale codec error: {e}"))?
+			.map_err(|e| format!("genesis config build error: {e}"))?;
+		Ok(ext.into_storages())
+	}
+
+	/// The genesis storage the runtime builds from its default genesis config with `patch`
+	/// merged into it.
+	pub fn get_storage_for_patch(&self, patch: Value) -> core::result::Result<Storage, String> {
+		let mut config = self.get_default_config()?;
+		crate::json_patch::merge(&mut config, patch);
+		self.get_storage_for_config(config)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::{from_str, json};
+	use sp_consensus_babe::{AllowedSlots, BabeEpochConfiguration};
+
+	#[test]
+	fn get_default_config_works() {
+		let config =
+			<GenesisConfigBuilderRuntimeCaller>::new(substrate_test_runtime::wasm_binary_unwrap())
+				.get_default_config()
+				.unwrap();
+		let expected = r#"{"system":{"code":"0x"},"babe":{"authorities":[],"epochConfig":null},"substrateTest":{"authorities":[]},"balances":{"balances":[],"members":[],"blacklist":[]}}"#;
+		assert_eq!(from_str::<Value>(expected).unwrap(), config);
+	}
+
+	#[test]
+	fn get_storage_for_patch_works() {
+		let patch = json!({
+			"babe": {
+				"epochConfig": {
+					"c": [69, 696],
+					"allowed_slots": "PrimaryAndSecondaryPlainSlots"
+				}
+			},
+		});
+
+		let storage =
+			<GenesisConfigBuilderRuntimeCaller>::new(substrate_test_runtime::wasm_binary_unwrap())
+				.get_storage_for_patch(patch)
+				.unwrap();
+
+		// Babe|EpochConfig
+		let value: Vec<u8> = storage
+			.top
+			.get(
+				&array_bytes::hex2bytes(
+					"1cb6f36e027abb2091cfb5110ab5087fdc6b171b77304263c292cc3ea5ed31ef",
+				)
+				.unwrap(),
+			)
+			.unwrap()
+			.clone();
+
+		assert_eq!(
+			BabeEpochConfiguration::decode(&mut &value[..]).unwrap(),
+			BabeEpochConfiguration {
+				c: (69, 696),
+				allowed_slots: AllowedSlots::PrimaryAndSecondaryPlainSlots
+			}
+		);
+	}
+
+	#[test]
+	fn get_storage_for_invalid_config_fails() {
+		let result =
+			<GenesisConfigBuilderRuntimeCaller>::new(substrate_test_runtime::wasm_binary_unwrap())
+				.get_storage_for_config(json!({ "system": {} }));
+		assert!(result.unwrap_err().starts_with("genesis config build error"));
+	}
+}
@@ -0,0 +1,90 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Patching of JSON genesis configs.
+
+use serde_json::Value;
+
+/// Recursively merge `b` into `a`.
+///
+/// Keys of `b` override the same keys of `a`, objects present in both are merged key by key.
+/// A `null` in `b` removes the key from `a`. Any other value of `b`, arrays included, replaces
+/// the value of `a` as a whole.
+pub fn merge(a: &mut Value, b: Value) {
+	match (a, b) {
+		(Value::Object(a), Value::Object(b)) =>
+			for (k, v) in b {
+				if v.is_null() {
+					a.remove(&k);
+				} else {
+					merge(a.entry(k).or_insert(Value::Null), v);
+				}
+			},
+		(a, b) => *a = b,
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn nested_objects_are_merged() {
+		let mut a = json!({
+			"system": { "code": "0x" },
+			"balances": { "balances": [["Alice", 1]], "members": [] },
+		});
+		merge(
+			&mut a,
+			json!({
+				"balances": { "members": [["Alice", 10, 10]] },
+				"sudo": { "key": "Alice" },
+			}),
+		);
+		assert_eq!(
+			a,
+			json!({
+				"system": { "code": "0x" },
+				"balances": { "balances": [["Alice", 1]], "members": [["Alice", 10, 10]] },
+				"sudo": { "key": "Alice" },
+			})
+		);
+	}
+
+	#[test]
+	fn arrays_and_scalars_are_replaced() {
+		let mut a = json!({ "staking": { "validatorCount": 1, "invulnerables": ["Alice", "Bob"] } });
+		merge(&mut a, json!({ "staking": { "validatorCount": 3, "invulnerables": ["Charlie"] } }));
+		assert_eq!(a, json!({ "staking": { "validatorCount": 3, "invulnerables": ["Charlie"] } }));
+	}
+
+	#[test]
+	fn null_removes_the_key() {
+		let mut a = json!({ "sudo": { "key": "Alice" }, "society": { "pot": 0 } });
+		merge(&mut a, json!({ "sudo": null, "society": { "pot": null } }));
+		assert_eq!(a, json!({ "society": {} }));
+	}
+
+	#[test]
+	fn empty_patch_changes_nothing() {
+		let mut a = json!({ "system": { "code": "0x" } });
+		merge(&mut a, json!({}));
+		assert_eq!(a, json!({ "system": { "code": "0x" } }));
+	}
+}
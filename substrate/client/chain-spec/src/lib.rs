@@ -165,6 +165,10 @@
 //! //   fields is a map of `key => value`. These key/value pairs represent the genesis storage.
 //! // - `stateRootHash` is a single hex encoded hash that represents the genesis hash. The hash
 //! //   type depends on the hash used by the chain.
+//! // - `runtimeGenesis` is a `json` object with the hex encoded runtime `code` and either a
+//! //   `config`, the complete `GenesisConfig` of the runtime, or a `patch`, which is merged into
+//! //   the default `GenesisConfig` of the runtime. The runtime builds the genesis storage from
+//! //   it through its `GenesisBuilder` API, so no native runtime is needed.
 //! //
 //! "genesis": { "runtime": {} },
 //!
@@ -189,14 +193,18 @@
 mod chain_spec;
 mod extension;
 mod genesis;
+mod genesis_config_builder;
+mod json_patch;
 
 pub use self::{
-	chain_spec::{ChainSpec as GenericChainSpec, NoExtension},
+	chain_spec::{ChainSpec as GenericChainSpec, NoExtension, RuntimeGenesisConfigJson},
 	extension::{get_extension, get_extension_mut, Extension, Fork, Forks, GetExtension, Group},
 	genesis::{
 		construct_genesis_block, resolve_state_version_from_wasm, BuildGenesisBlock,
 		GenesisBlockBuilder,
 	},
+	genesis_config_builder::GenesisConfigBuilderRuntimeCaller,
+	json_patch::merge as json_merge,
 };
 pub use sc_chain_spec_derive::{ChainSpecExtension, ChainSpecGroup};
 
@@ -16,8 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::config::DEFAULT_TRANSACTION_POOL_FILE;
 use clap::Args;
-use sc_service::config::TransactionPoolOptions;
+use sc_service::config::{TransactionPoolOptions, TransactionPoolPersistenceOptions};
+use std::time::Duration;
 
 /// Parameters used to create the pool configuration.
 #[derive(Debug, Clone, Args)]
@@ -33,6 +35,19 @@ pub struct TransactionPoolParams {
 	/// How long a transaction is banned for, if it is considered invalid. Defaults to 1800s.
 	#[arg(long, value_name = "SECONDS")]
 	pub tx_ban_seconds: Option<u64>,
+
+	/// Persist the transaction pool on shutdown and restore it on startup.
+	///
+	/// The pool is stored in the chain directory of the base path. Restored transactions
+	/// are validated again against the best block and dropped if no longer valid.
+	#[arg(long)]
+	pub pool_persist: bool,
+
+	/// How often the transaction pool is persisted while the node is running.
+	///
+	/// By default, the pool is only persisted on shutdown.
+	#[arg(long, value_name = "SECONDS", requires = "pool_persist")]
+	pub pool_persist_interval: Option<u64>,
 }
 
 impl TransactionPoolParams {
@@ -50,13 +65,18 @@ impl TransactionPoolParams {
 		opts.future.total_bytes = self.pool_kbytes * 1024 / factor;
 
 		opts.ban_time = if let Some(ban_seconds) = self.tx_ban_seconds {
-			std::time::Duration::from_secs(ban_seconds)
+			Duration::from_secs(ban_seconds)
 		} else if is_dev {
-			std::time::Duration::from_secs(0)
+			Duration::from_secs(0)
 		} else {
-			std::time::Duration::from_secs(30 * 60)
+			Duration::from_secs(30 * 60)
 		};
 
+		opts.persistence = self.pool_persist.then(|| TransactionPoolPersistenceOptions {
+			path: DEFAULT_TRANSACTION_POOL_FILE.into(),
+			interval: self.pool_persist_interval.map(Duration::from_secs),
+		});
+
 		opts
 	}
 }
@@ -0,0 +1,169 @@
+//! RPC methods to look up transfers in the memo index.
+
+#![warn(missing_docs)]
+
+use std::{convert::TryInto, marker::PhantomData};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sc_memo_index::{IndexedTransfer, MemoIndex as OffchainMemoIndex};
+use serde::{Deserialize, Serialize};
+use sp_core::{offchain::OffchainStorage, Bytes};
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, NumberFor};
+
+/// A transfer as the memo index stores it for `Block`.
+type Transfer<Block, AccountId, Balance, AssetId> = IndexedTransfer<
+	<Block as BlockT>::Hash,
+	NumberFor<Block>,
+	AccountId,
+	Balance,
+	AssetId,
+>;
+
+/// The number of transfers a lookup returns when no limit is given.
+const DEFAULT_LIMIT: u32 = 100;
+/// The largest number of transfers a single lookup returns.
+const MAX_LIMIT: u32 = 1_000;
+
+/// A transfer found in the memo index.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoTransferInfo<BlockHash, BlockNumber, AccountId, AssetId> {
+	/// The hash of the finalized block the transfer was made in.
+	pub block_hash: BlockHash,
+	/// The number of the finalized block the transfer was made in.
+	pub block_number: BlockNumber,
+	/// The index of the memo event among the events of the block.
+	pub event_index: u32,
+	/// The transferred asset, `None` for the native currency.
+	pub asset: Option<AssetId>,
+	/// The sender.
+	pub from: AccountId,
+	/// The receiver.
+	pub to: AccountId,
+	/// The amount that was transferred.
+	pub amount: NumberOrHex,
+	/// The memo attached to the transfer.
+	pub memo: Bytes,
+}
+
+/// Memo index RPC methods.
+#[rpc(client, server)]
+pub trait MemoIndexApi<BlockHash, BlockNumber, AccountId, AssetId> {
+	/// Get the finalized transfers carrying `memo`, newest first.
+	///
+	/// At most `limit` transfers are returned, 100 by default and 1000 at most.
+	#[method(name = "memo_transfersByMemo")]
+	fn transfers_by_memo(
+		&self,
+		memo: Bytes,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<MemoTransferInfo<BlockHash, BlockNumber, AccountId, AssetId>>>;
+
+	/// Get the finalized transfers with a memo to `account`, newest first.
+	///
+	/// At most `limit` transfers are returned, 100 by default and 1000 at most.
+	#[method(name = "memo_transfersTo")]
+	fn transfers_to(
+		&self,
+		account: AccountId,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<MemoTransferInfo<BlockHash, BlockNumber, AccountId, AssetId>>>;
+}
+
+/// Implements the [`MemoIndexApiServer`] RPC trait on top of the offchain database the memo
+/// index gets written to.
+pub struct MemoIndex<S, T> {
+	index: OffchainMemoIndex<S>,
+	_marker: PhantomData<T>,
+}
+
+impl<S: OffchainStorage, T> MemoIndex<S, T> {
+	/// Create a new instance of the memo index RPC helper.
+	pub fn new(offchain_storage: S) -> Self {
+		Self { index: OffchainMemoIndex::new(offchain_storage), _marker: Default::default() }
+	}
+}
+
+fn invalid_params(message: String) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+		ErrorCode::InvalidParams.code(),
+		message,
+		None::<()>,
+	)))
+}
+
+fn check_limit(limit: Option<u32>) -> RpcResult<u32> {
+	match limit.unwrap_or(DEFAULT_LIMIT) {
+		limit if limit <= MAX_LIMIT => Ok(limit),
+		limit => Err(invalid_params(format!("limit {} is above the maximum of {}", limit, MAX_LIMIT))),
+	}
+}
+
+fn into_info<Hash, Number, AccountId, Balance, AssetId>(
+	indexed: IndexedTransfer<Hash, Number, AccountId, Balance, AssetId>,
+) -> RpcResult<MemoTransferInfo<Hash, Number, AccountId, AssetId>>
+where
+	Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>,
+{
+	let IndexedTransfer { block_hash, block_number, transfer } = indexed;
+	let amount = transfer.amount.try_into().map_err(|_| {
+		invalid_params(format!("{} doesn't fit in NumberOrHex representation", transfer.amount))
+	})?;
+	Ok(MemoTransferInfo {
+		block_hash,
+		block_number,
+		event_index: transfer.event_index,
+		asset: transfer.asset,
+		from: transfer.from,
+		to: transfer.to,
+		amount,
+		memo: transfer.memo.into(),
+	})
+}
+
+impl<S, Block, AccountId, Balance, AssetId>
+	MemoIndexApiServer<<Block as BlockT>::Hash, NumberFor<Block>, AccountId, AssetId>
+	for MemoIndex<S, (Block, AccountId, Balance, AssetId)>
+where
+	S: OffchainStorage + 'static,
+	Block: BlockT,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+	AssetId: Codec + Send + Sync + 'static,
+{
+	fn transfers_by_memo(
+		&self,
+		memo: Bytes,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<MemoTransferInfo<Block::Hash, NumberFor<Block>, AccountId, AssetId>>> {
+		self.index
+			.transfers_by_memo::<Transfer<Block, AccountId, Balance, AssetId>>(
+				&memo,
+				check_limit(limit)?,
+			)
+			.into_iter()
+			.map(into_info)
+			.collect()
+	}
+
+	fn transfers_to(
+		&self,
+		account: AccountId,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<MemoTransferInfo<Block::Hash, NumberFor<Block>, AccountId, AssetId>>> {
+		self.index
+			.transfers_to::<Transfer<Block, AccountId, Balance, AssetId>>(
+				&account,
+				check_limit(limit)?,
+			)
+			.into_iter()
+			.map(into_info)
+			.collect()
+	}
+}
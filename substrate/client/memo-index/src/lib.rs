@@ -0,0 +1,263 @@
+//! # Memo index
+//!
+//! Files the transfers that carry a memo under their memo and under their destination account,
+//! so that exchanges and wallets can find the deposits they are waiting for without scanning
+//! the chain.
+//!
+//! The indexer is driven by block finality: for every newly finalized block it asks the runtime
+//! for the memo transfers of the block through [`MemoApi`] and appends them to the offchain
+//! database of the node. Only finalized blocks are indexed, so entries never have to be
+//! reverted. Transfers finalized before the indexer first ran are not indexed.
+
+#![warn(missing_docs)]
+
+use codec::{Codec, Decode, Encode};
+use futures::StreamExt;
+use log::{debug, warn};
+use sc_client_api::{Backend, BlockchainEvents};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::{Block as BlockT, Header, NumberFor, One, Saturating};
+use std::sync::Arc;
+
+pub use sp_memo_index::{MemoApi, MemoTransfer};
+
+/// Logging target for the memo index.
+pub const LOG_TARGET: &str = "memo-index";
+
+/// Prefix of every key the memo index writes to the offchain database.
+const INDEX_PREFIX: &[u8] = b"memo-index/";
+
+/// A memo transfer together with the finalized block it was made in.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct IndexedTransfer<Hash, Number, AccountId, Balance, AssetId> {
+	/// The hash of the block.
+	pub block_hash: Hash,
+	/// The number of the block.
+	pub block_number: Number,
+	/// The transfer.
+	pub transfer: MemoTransfer<AccountId, Balance, AssetId>,
+}
+
+fn memo_key(memo: &[u8]) -> Vec<u8> {
+	[INDEX_PREFIX, b"memo/", &sp_core::blake2_256(memo)[..]].concat()
+}
+
+fn account_key(account: &impl Encode) -> Vec<u8> {
+	[INDEX_PREFIX, b"to/", &account.encode()[..]].concat()
+}
+
+fn entry_key(key: &[u8], position: u32) -> Vec<u8> {
+	[key, &position.to_be_bytes()[..]].concat()
+}
+
+fn cursor_key() -> Vec<u8> {
+	[INDEX_PREFIX, b"last-indexed"].concat()
+}
+
+/// The memo index, kept in the offchain database.
+///
+/// Every list of transfers is stored as its length under the list key, and its entries under
+/// the list key suffixed with their position, so appending to a list never rewrites it.
+#[derive(Clone)]
+pub struct MemoIndex<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> MemoIndex<S> {
+	/// Create a memo index on top of the given offchain database.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	/// The indexed transfers carrying `memo`, newest first, at most `limit` of them.
+	pub fn transfers_by_memo<T: Decode>(&self, memo: &[u8], limit: u32) -> Vec<T> {
+		self.read(&memo_key(memo), limit)
+	}
+
+	/// The indexed transfers to `account`, newest first, at most `limit` of them.
+	pub fn transfers_to<T: Decode>(&self, account: &impl Encode, limit: u32) -> Vec<T> {
+		self.read(&account_key(account), limit)
+	}
+
+	/// The number of the last block that got indexed.
+	pub fn last_indexed<N: Decode>(&self) -> Option<N> {
+		self.storage
+			.get(STORAGE_PREFIX, &cursor_key())
+			.and_then(|raw| N::decode(&mut &raw[..]).ok())
+	}
+
+	fn set_last_indexed(&mut self, number: impl Encode) {
+		self.storage.set(STORAGE_PREFIX, &cursor_key(), &number.encode());
+	}
+
+	fn insert<Hash, Number, AccountId, Balance, AssetId>(
+		&mut self,
+		transfer: &IndexedTransfer<Hash, Number, AccountId, Balance, AssetId>,
+	) where
+		IndexedTransfer<Hash, Number, AccountId, Balance, AssetId>: Encode,
+		AccountId: Encode,
+	{
+		let value = transfer.encode();
+		self.append(&memo_key(&transfer.transfer.memo), &value);
+		self.append(&account_key(&transfer.transfer.to), &value);
+	}
+
+	fn len(&self, key: &[u8]) -> u32 {
+		self.storage
+			.get(STORAGE_PREFIX, key)
+			.and_then(|raw| u32::decode(&mut &raw[..]).ok())
+			.unwrap_or_default()
+	}
+
+	fn append(&mut self, key: &[u8], value: &[u8]) {
+		let len = self.len(key);
+		self.storage.set(STORAGE_PREFIX, &entry_key(key, len), value);
+		self.storage.set(STORAGE_PREFIX, key, &len.saturating_add(1).encode());
+	}
+
+	fn read<T: Decode>(&self, key: &[u8], limit: u32) -> Vec<T> {
+		let len = self.len(key);
+		(len.saturating_sub(limit)..len)
+			.rev()
+			.filter_map(|position| self.storage.get(STORAGE_PREFIX, &entry_key(key, position)))
+			.filter_map(|raw| T::decode(&mut &raw[..]).ok())
+			.collect()
+	}
+
+	/// Index every finalized block up to `finalized` that is not indexed yet.
+	fn index_finalized<B, C, AccountId, Balance, AssetId>(
+		&mut self,
+		client: &C,
+		finalized: NumberFor<B>,
+	) where
+		B: BlockT,
+		C: HeaderBackend<B> + ProvideRuntimeApi<B>,
+		C::Api: MemoApi<B, AccountId, Balance, AssetId>,
+		AccountId: Codec,
+		Balance: Codec,
+		AssetId: Codec,
+	{
+		let mut number = match self.last_indexed::<NumberFor<B>>() {
+			Some(last) => last.saturating_add(One::one()),
+			None => finalized,
+		};
+		while number <= finalized {
+			let block_hash = match client.hash(number) {
+				Ok(Some(hash)) => hash,
+				other => {
+					warn!(target: LOG_TARGET, "Can't find finalized block #{:?}: {:?}", number, other);
+					return
+				},
+			};
+			// The runtime may predate the memo API or the state may be pruned already.
+			match client.runtime_api().memo_transfers(block_hash) {
+				Ok(transfers) =>
+					for transfer in transfers {
+						self.insert(&IndexedTransfer { block_hash, block_number: number, transfer });
+					},
+				Err(e) => debug!(target: LOG_TARGET, "Skipping block #{:?}: {}", number, e),
+			}
+			self.set_last_indexed(number);
+			number = number.saturating_add(One::one());
+		}
+	}
+}
+
+/// Keep the memo index in the offchain database of `backend` up to date with the finalized
+/// chain of `client`.
+pub async fn start<B, BE, C, AccountId, Balance, AssetId>(client: Arc<C>, backend: Arc<BE>)
+where
+	B: BlockT,
+	BE: Backend<B>,
+	C: BlockchainEvents<B> + HeaderBackend<B> + ProvideRuntimeApi<B>,
+	C::Api: MemoApi<B, AccountId, Balance, AssetId>,
+	AccountId: Codec,
+	Balance: Codec,
+	AssetId: Codec,
+{
+	let mut index = match backend.offchain_storage() {
+		Some(storage) => MemoIndex::new(storage),
+		None => {
+			warn!(target: LOG_TARGET, "Can't run the memo index on a node without offchain storage.");
+			return
+		},
+	};
+
+	let mut finality_notifications = client.finality_notification_stream();
+	while let Some(notification) = finality_notifications.next().await {
+		index.index_finalized::<B, C, AccountId, Balance, AssetId>(
+			&*client,
+			*notification.header.number(),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	type Transfer = IndexedTransfer<u64, u64, u64, u128, u32>;
+
+	fn transfer(block_number: u64, to: u64, memo: &[u8]) -> Transfer {
+		IndexedTransfer {
+			block_hash: block_number * 100,
+			block_number,
+			transfer: MemoTransfer {
+				event_index: 0,
+				asset: None,
+				from: 1,
+				to,
+				amount: 10,
+				memo: memo.to_vec(),
+			},
+		}
+	}
+
+	#[test]
+	fn transfers_are_found_by_memo_and_by_destination() {
+		let mut index = MemoIndex::new(InMemOffchainStorage::default());
+		index.insert(&transfer(1, 2, b"invoice 1"));
+		index.insert(&transfer(2, 3, b"invoice 2"));
+		index.insert(&transfer(3, 2, b"invoice 2"));
+
+		assert_eq!(
+			index.transfers_by_memo::<Transfer>(b"invoice 2", 10),
+			vec![transfer(3, 2, b"invoice 2"), transfer(2, 3, b"invoice 2")],
+		);
+		assert_eq!(
+			index.transfers_to::<Transfer>(&2u64, 10),
+			vec![transfer(3, 2, b"invoice 2"), transfer(1, 2, b"invoice 1")],
+		);
+		assert!(index.transfers_by_memo::<Transfer>(b"invoice 3", 10).is_empty());
+		assert!(index.transfers_to::<Transfer>(&4u64, 10).is_empty());
+	}
+
+	#[test]
+	fn lookups_return_the_newest_transfers_up_to_the_limit() {
+		let mut index = MemoIndex::new(InMemOffchainStorage::default());
+		for block_number in 1..=5 {
+			index.insert(&transfer(block_number, 2, b"deposit"));
+		}
+
+		assert_eq!(
+			index.transfers_to::<Transfer>(&2u64, 2),
+			vec![transfer(5, 2, b"deposit"), transfer(4, 2, b"deposit")],
+		);
+		assert_eq!(index.transfers_by_memo::<Transfer>(b"deposit", 100).len(), 5);
+		assert!(index.transfers_by_memo::<Transfer>(b"deposit", 0).is_empty());
+	}
+
+	#[test]
+	fn last_indexed_block_is_tracked() {
+		let mut index = MemoIndex::new(InMemOffchainStorage::default());
+		assert_eq!(index.last_indexed::<u64>(), None);
+
+		index.set_last_indexed(7u64);
+		assert_eq!(index.last_indexed::<u64>(), Some(7));
+		index.set_last_indexed(8u64);
+		assert_eq!(index.last_indexed::<u64>(), Some(8));
+	}
+}
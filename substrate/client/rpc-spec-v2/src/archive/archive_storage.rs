@@ -0,0 +1,257 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `archive_storage` method.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use sc_client_api::{Backend, ChildInfo, StorageKey, StorageProvider};
+use sp_api::BlockT;
+use sp_core::storage::well_known_keys;
+
+use crate::{
+	archive::event::{ArchiveStorageResult, PaginatedStorageQuery},
+	chain_head::{
+		event::{StorageQueryType, StorageResult, StorageResultType},
+		hex_string,
+	},
+};
+
+/// The query type of an iteration.
+enum IterQueryType {
+	/// Iterating over (key, value) pairs.
+	Value,
+	/// Iterating over (key, hash) pairs.
+	Hash,
+}
+
+/// Answers the queries of the `archive_storage` method.
+pub struct ArchiveStorage<Client, Block, BE> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// The maximum number of items reported by a descendants query before
+	/// pagination is required.
+	storage_max_descendant_responses: usize,
+	/// The maximum number of queried items allowed for the `archive_storage` at a time.
+	storage_max_queried_items: usize,
+	_phandom: PhantomData<(BE, Block)>,
+}
+
+impl<Client, Block, BE> ArchiveStorage<Client, Block, BE> {
+	/// Constructs a new [`ArchiveStorage`].
+	pub fn new(
+		client: Arc<Client>,
+		storage_max_descendant_responses: usize,
+		storage_max_queried_items: usize,
+	) -> Self {
+		Self {
+			client,
+			storage_max_descendant_responses,
+			storage_max_queried_items,
+			_phandom: PhantomData,
+		}
+	}
+}
+
+/// Checks if the provided key (main or child key) is valid
+/// for queries.
+///
+/// Keys that are identical to `:child_storage:` or `:child_storage:default:`
+/// are not queryable.
+fn is_key_queryable(key: &[u8]) -> bool {
+	!well_known_keys::is_default_child_storage_key(key) &&
+		!well_known_keys::is_child_storage_key(key)
+}
+
+/// The result of making a query call.
+type QueryResult = Result<Option<StorageResult>, String>;
+
+impl<Client, Block, BE> ArchiveStorage<Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: StorageProvider<Block, BE> + 'static,
+{
+	/// Fetch the value from storage.
+	fn query_storage_value(
+		&self,
+		hash: Block::Hash,
+		key: &StorageKey,
+		child_key: Option<&ChildInfo>,
+	) -> QueryResult {
+		let result = if let Some(child_key) = child_key {
+			self.client.child_storage(hash, child_key, key)
+		} else {
+			self.client.storage(hash, key)
+		};
+
+		result
+			.map(|opt| {
+				opt.map(|storage_data| StorageResult {
+					key: hex_string(&key.0),
+					result: StorageResultType::Value(hex_string(&storage_data.0)),
+				})
+			})
+			.map_err(|error| error.to_string())
+	}
+
+	/// Fetch the hash of a value from storage.
+	fn query_storage_hash(
+		&self,
+		hash: Block::Hash,
+		key: &StorageKey,
+		child_key: Option<&ChildInfo>,
+	) -> QueryResult {
+		let result = if let Some(child_key) = child_key {
+			self.client.child_storage_hash(hash, child_key, key)
+		} else {
+			self.client.storage_hash(hash, key)
+		};
+
+		result
+			.map(|opt| {
+				opt.map(|storage_data| StorageResult {
+					key: hex_string(&key.0),
+					result: StorageResultType::Hash(hex_string(&storage_data.as_ref())),
+				})
+			})
+			.map_err(|error| error.to_string())
+	}
+
+	/// Fetch the merkle value of the closest descendant of the key.
+	fn query_storage_merkle_value(
+		&self,
+		hash: Block::Hash,
+		key: &StorageKey,
+		child_key: Option<&ChildInfo>,
+	) -> QueryResult {
+		let result = if let Some(child_key) = child_key {
+			self.client.child_closest_merkle_value(hash, child_key, key)
+		} else {
+			self.client.closest_merkle_value(hash, key)
+		};
+
+		result
+			.map(|opt| {
+				opt.map(|merkle_value| StorageResult {
+					key: hex_string(&key.0),
+					result: StorageResultType::ClosestDescendantMerkleValue(hex_string(
+						&merkle_value.as_ref(),
+					)),
+				})
+			})
+			.map_err(|error| error.to_string())
+	}
+
+	/// Iterate over at most `storage_max_descendant_responses` descendants of the key,
+	/// starting after `pagination_start_key` if provided.
+	fn query_storage_iter_pagination(
+		&self,
+		hash: Block::Hash,
+		key: &StorageKey,
+		pagination_start_key: Option<&StorageKey>,
+		ty: IterQueryType,
+		child_key: Option<&ChildInfo>,
+	) -> Result<Vec<StorageResult>, String> {
+		let keys_iter = if let Some(child_key) = child_key {
+			self.client.child_storage_keys(
+				hash,
+				child_key.to_owned(),
+				Some(key),
+				pagination_start_key,
+			)
+		} else {
+			self.client.storage_keys(hash, Some(key), pagination_start_key)
+		}
+		.map_err(|err| err.to_string())?;
+
+		let mut ret = Vec::with_capacity(self.storage_max_descendant_responses);
+		for key in keys_iter.take(self.storage_max_descendant_responses) {
+			let result = match ty {
+				IterQueryType::Value => self.query_storage_value(hash, &key, child_key),
+				IterQueryType::Hash => self.query_storage_hash(hash, &key, child_key),
+			}?;
+
+			if let Some(value) = result {
+				ret.push(value);
+			}
+		}
+
+		Ok(ret)
+	}
+
+	/// Answer the queries of the `archive_storage` method.
+	///
+	/// Only the first `storage_max_queried_items` are answered, the number of discarded
+	/// queries is reported back.
+	pub fn handle_query(
+		&self,
+		hash: Block::Hash,
+		mut items: Vec<PaginatedStorageQuery<StorageKey>>,
+		child_key: Option<ChildInfo>,
+	) -> ArchiveStorageResult {
+		let discarded_items = items.len().saturating_sub(self.storage_max_queried_items);
+		items.truncate(self.storage_max_queried_items);
+
+		if let Some(child_key) = child_key.as_ref() {
+			if !is_key_queryable(child_key.storage_key()) {
+				return ArchiveStorageResult::ok(Vec::new(), discarded_items)
+			}
+		}
+
+		let mut storage_results = Vec::with_capacity(items.len());
+		for item in items {
+			if !is_key_queryable(&item.key.0) {
+				continue
+			}
+
+			let result = match item.query_type {
+				StorageQueryType::Value => self
+					.query_storage_value(hash, &item.key, child_key.as_ref())
+					.map(Vec::from_iter),
+				StorageQueryType::Hash => self
+					.query_storage_hash(hash, &item.key, child_key.as_ref())
+					.map(Vec::from_iter),
+				StorageQueryType::ClosestDescendantMerkleValue => self
+					.query_storage_merkle_value(hash, &item.key, child_key.as_ref())
+					.map(Vec::from_iter),
+				StorageQueryType::DescendantsValues => self.query_storage_iter_pagination(
+					hash,
+					&item.key,
+					item.pagination_start_key.as_ref(),
+					IterQueryType::Value,
+					child_key.as_ref(),
+				),
+				StorageQueryType::DescendantsHashes => self.query_storage_iter_pagination(
+					hash,
+					&item.key,
+					item.pagination_start_key.as_ref(),
+					IterQueryType::Hash,
+					child_key.as_ref(),
+				),
+			};
+
+			match result {
+				Ok(results) => storage_results.extend(results),
+				Err(error) => return ArchiveStorageResult::err(error),
+			}
+		}
+
+		ArchiveStorageResult::ok(storage_results, discarded_items)
+	}
+}
@@ -0,0 +1,210 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The archive's results returned as json compatible objects.
+
+use crate::chain_head::event::{StorageQueryType, StorageResult};
+use serde::{Deserialize, Serialize};
+
+/// The result of an RPC method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MethodResult {
+	/// Method generated a result.
+	Ok(MethodResultOk),
+	/// Method encountered an error.
+	Err(MethodResultErr),
+}
+
+impl MethodResult {
+	/// Constructs a successful result.
+	pub fn ok(result: impl Into<String>) -> MethodResult {
+		MethodResult::Ok(MethodResultOk { success: true, value: result.into() })
+	}
+
+	/// Constructs an error result.
+	pub fn err(error: impl Into<String>) -> MethodResult {
+		MethodResult::Err(MethodResultErr { success: false, error: error.into() })
+	}
+}
+
+/// The successful result of an RPC method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodResultOk {
+	/// Method was successful, always `true`.
+	pub success: bool,
+	/// The result of the method.
+	pub value: String,
+}
+
+/// The error result of an RPC method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodResultErr {
+	/// Method encountered an error, always `false`.
+	pub success: bool,
+	/// The error of the method.
+	pub error: String,
+}
+
+/// The storage item received as parameter by `archive_unstable_storage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginatedStorageQuery<Key> {
+	/// The provided key.
+	pub key: Key,
+	/// The type of the storage query.
+	#[serde(rename = "type")]
+	pub query_type: StorageQueryType,
+	/// The key from which the descendants iteration resumes, excluding the key itself.
+	///
+	/// Only valid for the `descendantsValues` and `descendantsHashes` queries.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub pagination_start_key: Option<Key>,
+}
+
+/// The result of `archive_unstable_storage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArchiveStorageResult {
+	/// The storage queries were answered.
+	Ok(ArchiveStorageMethodOk),
+	/// The storage queries could not be answered due to an error.
+	Err(ArchiveStorageMethodErr),
+}
+
+impl ArchiveStorageResult {
+	/// Constructs a successful result.
+	pub fn ok(result: Vec<StorageResult>, discarded_items: usize) -> Self {
+		Self::Ok(ArchiveStorageMethodOk { result, discarded_items })
+	}
+
+	/// Constructs an error result.
+	pub fn err(error: impl Into<String>) -> Self {
+		Self::Err(ArchiveStorageMethodErr { error: error.into() })
+	}
+}
+
+/// The answered storage queries of `archive_unstable_storage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageMethodOk {
+	/// The items of the storage.
+	pub result: Vec<StorageResult>,
+	/// The number of trailing queries that were not processed.
+	pub discarded_items: usize,
+}
+
+/// The error of `archive_unstable_storage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageMethodErr {
+	/// Reason of the error.
+	pub error: String,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_head::event::StorageResultType;
+
+	#[test]
+	fn method_result() {
+		// Item with Ok.
+		let item = MethodResult::ok("res");
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"success":true,"value":"res"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: MethodResult = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+
+		// Item with error.
+		let item = MethodResult::err("err");
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"success":false,"error":"err"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: MethodResult = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+	}
+
+	#[test]
+	fn paginated_storage_query() {
+		// Item without pagination.
+		let item = PaginatedStorageQuery {
+			key: "0x1",
+			query_type: StorageQueryType::Value,
+			pagination_start_key: None,
+		};
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"key":"0x1","type":"value"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: PaginatedStorageQuery<&str> = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+
+		// Item with pagination.
+		let item = PaginatedStorageQuery {
+			key: "0x1",
+			query_type: StorageQueryType::DescendantsValues,
+			pagination_start_key: Some("0x2"),
+		};
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"key":"0x1","type":"descendantsValues","paginationStartKey":"0x2"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: PaginatedStorageQuery<&str> = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+	}
+
+	#[test]
+	fn archive_storage_result() {
+		// Item with Ok.
+		let item = ArchiveStorageResult::ok(
+			vec![StorageResult {
+				key: "0x1".into(),
+				result: StorageResultType::Value("res".into()),
+			}],
+			1,
+		);
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"result":[{"key":"0x1","value":"res"}],"discardedItems":1}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveStorageResult = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+
+		// Item with error.
+		let item = ArchiveStorageResult::err("err");
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"error":"err"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveStorageResult = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+	}
+}
@@ -0,0 +1,645 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::chain_head::{
+	event::{StorageQueryType, StorageResult, StorageResultType},
+	hex_string,
+};
+
+use super::*;
+
+use assert_matches::assert_matches;
+use codec::{Decode, Encode};
+use jsonrpsee::{
+	core::error::Error,
+	rpc_params,
+	types::{error::CallError, EmptyServerParams as EmptyParams},
+	RpcModule,
+};
+use sc_block_builder::BlockBuilderProvider;
+use sc_client_api::ChildInfo;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::BlockOrigin;
+use sp_core::{Blake2Hasher, Hasher};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{collections::HashSet, sync::Arc};
+use substrate_test_runtime::Transfer;
+use substrate_test_runtime_client::{
+	prelude::*, runtime, Backend, BlockBuilderExt, Client, ClientBlockImportExt,
+};
+
+type Header = substrate_test_runtime_client::runtime::Header;
+type Block = substrate_test_runtime_client::runtime::Block;
+const MAX_PAGINATION_LIMIT: usize = 5;
+const MAX_QUERIED_LIMIT: usize = 5;
+const KEY: &[u8] = b":mock";
+const VALUE: &[u8] = b"hello world";
+const CHILD_STORAGE_KEY: &[u8] = b"child";
+const CHILD_VALUE: &[u8] = b"child value";
+
+fn setup_api(
+	max_descendant_responses: usize,
+	max_queried_items: usize,
+) -> (Arc<Client<Backend>>, RpcModule<Archive<Backend, Block, Client<Backend>>>) {
+	let child_info = ChildInfo::new_default(CHILD_STORAGE_KEY);
+	let builder = TestClientBuilder::new().add_extra_child_storage(
+		&child_info,
+		KEY.to_vec(),
+		CHILD_VALUE.to_vec(),
+	);
+	let backend = builder.backend();
+	let client = Arc::new(builder.build());
+
+	let api = Archive::new(
+		client.clone(),
+		backend,
+		ArchiveConfig { max_descendant_responses, max_queried_items },
+	)
+	.into_rpc();
+
+	(client, api)
+}
+
+#[tokio::test]
+async fn archive_body() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	// Invalid block hash.
+	let invalid_hash = hex_string(&[1; 32]);
+	let body: Option<Vec<String>> =
+		api.call("archive_unstable_body", [invalid_hash]).await.unwrap();
+	assert!(body.is_none());
+
+	// Import a new block with an extrinsic.
+	let mut builder = client.new_block(Default::default()).unwrap();
+	builder
+		.push_transfer(runtime::Transfer {
+			from: AccountKeyring::Alice.into(),
+			to: AccountKeyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let expected_tx = hex_string(&block.extrinsics[0].encode());
+
+	let body: Vec<String> = api.call("archive_unstable_body", [block_hash]).await.unwrap();
+	assert_eq!(vec![expected_tx], body);
+}
+
+#[tokio::test]
+async fn archive_header() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	// Invalid block hash.
+	let invalid_hash = hex_string(&[1; 32]);
+	let header: Option<String> = api.call("archive_unstable_header", [invalid_hash]).await.unwrap();
+	assert!(header.is_none());
+
+	// Import a new block.
+	let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let header: String = api.call("archive_unstable_header", [block_hash]).await.unwrap();
+	let bytes = array_bytes::hex2bytes(&header).unwrap();
+	let header: Header = Decode::decode(&mut &bytes[..]).unwrap();
+	assert_eq!(header, block.header);
+}
+
+#[tokio::test]
+async fn archive_finalized_height() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	let client_height = client.info().finalized_number;
+
+	let height: u64 =
+		api.call("archive_unstable_finalizedHeight", EmptyParams::new()).await.unwrap();
+	assert_eq!(client_height, height);
+
+	// Import and finalize a new block.
+	let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_hash = block.header.hash();
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	// The best block is not yet finalized.
+	let height: u64 =
+		api.call("archive_unstable_finalizedHeight", EmptyParams::new()).await.unwrap();
+	assert_eq!(client_height, height);
+
+	client.finalize_block(block_hash, None).unwrap();
+	let height: u64 =
+		api.call("archive_unstable_finalizedHeight", EmptyParams::new()).await.unwrap();
+	assert_eq!(client_height + 1, height);
+}
+
+#[tokio::test]
+async fn archive_hash_by_height() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	// Genesis height.
+	let hashes: Vec<String> = api.call("archive_unstable_hashByHeight", [0]).await.unwrap();
+	assert_eq!(hashes, vec![format!("{:?}", client.genesis_hash())]);
+
+	// Block tree:
+	// genesis -> finalized -> block 1 -> block 2 -> block 3
+	//                      -> block 1 -> block 4
+	//
+	//            ^^^ h = N
+	//                         ^^^ h = N + 1
+	//                                    ^^^ h = N + 2
+	//                                               ^^^ h = N + 3
+	let finalized = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let finalized_hash = finalized.header.hash();
+	client.import(BlockOrigin::Own, finalized.clone()).await.unwrap();
+	client.finalize_block(finalized_hash, None).unwrap();
+	let finalized_height = client.info().finalized_number;
+
+	let block_1 = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_1_hash = block_1.header.hash();
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	let block_2 = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_2_hash = block_2.header.hash();
+	client.import(BlockOrigin::Own, block_2.clone()).await.unwrap();
+
+	let block_3 = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_3_hash = block_3.header.hash();
+	client.import(BlockOrigin::Own, block_3.clone()).await.unwrap();
+
+	let mut block_builder =
+		client.new_block_at(block_1.header.hash(), Default::default(), false).unwrap();
+	// This push is required as otherwise block 4 has the same hash as block 2 and won't get
+	// imported
+	block_builder
+		.push_transfer(Transfer {
+			from: AccountKeyring::Alice.into(),
+			to: AccountKeyring::Ferdie.into(),
+			amount: 41,
+			nonce: 0,
+		})
+		.unwrap();
+	let block_4 = block_builder.build().unwrap().block;
+	let block_4_hash = block_4.header.hash();
+	client.import(BlockOrigin::Own, block_4.clone()).await.unwrap();
+
+	// Check finalized height.
+	let hashes: Vec<String> =
+		api.call("archive_unstable_hashByHeight", [finalized_height]).await.unwrap();
+	assert_eq!(hashes, vec![format!("{:?}", finalized_hash)]);
+
+	// Test nonfinalized heights.
+	// Height (N + 1) must include block 1.
+	let mut height = finalized_height + 1;
+	let hashes: Vec<String> = api.call("archive_unstable_hashByHeight", [height]).await.unwrap();
+	assert_eq!(hashes, vec![format!("{:?}", block_1_hash)]);
+
+	// Height (N + 2) must include block 2 and 4.
+	height += 1;
+	let hashes: HashSet<String> = api
+		.call::<_, Vec<String>>("archive_unstable_hashByHeight", [height])
+		.await
+		.unwrap()
+		.into_iter()
+		.collect();
+	let expected: HashSet<String> = [format!("{:?}", block_2_hash), format!("{:?}", block_4_hash)]
+		.into_iter()
+		.collect();
+	assert_eq!(hashes, expected);
+
+	// Height (N + 3) must include block 3.
+	height += 1;
+	let hashes: Vec<String> = api.call("archive_unstable_hashByHeight", [height]).await.unwrap();
+	assert_eq!(hashes, vec![format!("{:?}", block_3_hash)]);
+
+	// Height (N + 4) has no blocks.
+	height += 1;
+	let hashes: Vec<String> = api.call("archive_unstable_hashByHeight", [height]).await.unwrap();
+	assert!(hashes.is_empty());
+
+	// Once finalized, only the canonical block is reported.
+	client.finalize_block(block_2_hash, None).unwrap();
+	let hashes: Vec<String> =
+		api.call("archive_unstable_hashByHeight", [finalized_height + 2]).await.unwrap();
+	assert_eq!(hashes, vec![format!("{:?}", block_2_hash)]);
+}
+
+#[tokio::test]
+async fn archive_call() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+	let invalid_hash = hex_string(&[1; 32]);
+
+	// Pass an invalid parameters that cannot be decode.
+	let err = api
+		.call::<_, serde_json::Value>(
+			"archive_unstable_call",
+			// 0x0 is invalid.
+			[&invalid_hash, "BabeApi_current_epoch", "0x0"],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::Call(CallError::Custom(ref err)) if err.code() == 3001 && err.message().contains("Invalid parameter")
+	);
+
+	// Invalid hash.
+	let result: MethodResult = api
+		.call("archive_unstable_call", [&invalid_hash, "BabeApi_current_epoch", "0x00"])
+		.await
+		.unwrap();
+	assert_matches!(result, MethodResult::Err(_));
+
+	let block_1 = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	let block_1_hash = block_1.header.hash();
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	// Valid call.
+	let alice_id = AccountKeyring::Alice.to_account_id();
+	// Hex encoded scale encoded bytes representing the call parameters.
+	let call_parameters = hex_string(&alice_id.encode());
+	let result: MethodResult = api
+		.call(
+			"archive_unstable_call",
+			[&format!("{:?}", block_1_hash), "AccountNonceApi_account_nonce", &call_parameters],
+		)
+		.await
+		.unwrap();
+	let expected = MethodResult::ok("0x0000000000000000");
+	assert_eq!(result, expected);
+
+	// The `current_epoch` takes no parameters and not draining the input buffer
+	// will cause the execution to fail.
+	let result: MethodResult = api
+		.call(
+			"archive_unstable_call",
+			[&format!("{:?}", block_1_hash), "BabeApi_current_epoch", "0x00"],
+		)
+		.await
+		.unwrap();
+	assert_matches!(result, MethodResult::Err(err) if err.error.contains("Execution failed"));
+}
+
+#[tokio::test]
+async fn archive_storage_hashes_values() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+	let block_hash = format!("{:?}", block.header.hash());
+	let key = hex_string(&KEY);
+
+	let items: Vec<PaginatedStorageQuery<String>> = vec![
+		PaginatedStorageQuery {
+			key: key.clone(),
+			query_type: StorageQueryType::DescendantsHashes,
+			pagination_start_key: None,
+		},
+		PaginatedStorageQuery {
+			key: key.clone(),
+			query_type: StorageQueryType::DescendantsValues,
+			pagination_start_key: None,
+		},
+		PaginatedStorageQuery {
+			key: key.clone(),
+			query_type: StorageQueryType::Hash,
+			pagination_start_key: None,
+		},
+		PaginatedStorageQuery {
+			key: key.clone(),
+			query_type: StorageQueryType::Value,
+			pagination_start_key: None,
+		},
+	];
+
+	let result: ArchiveStorageResult = api
+		.call("archive_unstable_storage", rpc_params![&block_hash, items.clone()])
+		.await
+		.unwrap();
+
+	match result {
+		ArchiveStorageResult::Ok(ArchiveStorageMethodOk { result, discarded_items }) => {
+			// Key has not been imported yet.
+			assert_eq!(result.len(), 0);
+			assert_eq!(discarded_items, 0);
+		},
+		_ => panic!("Unexpected result"),
+	};
+
+	// Import a block with the given key value pair.
+	let mut builder = client.new_block_at(block.hash(), Default::default(), false).unwrap();
+	builder.push_storage_change(KEY.to_vec(), Some(VALUE.to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let block_hash = format!("{:?}", block.header.hash());
+	let expected_hash = format!("{:?}", Blake2Hasher::hash(&VALUE));
+	let expected_value = hex_string(&VALUE);
+
+	let result: ArchiveStorageResult = api
+		.call("archive_unstable_storage", rpc_params![&block_hash, items])
+		.await
+		.unwrap();
+
+	match result {
+		ArchiveStorageResult::Ok(ArchiveStorageMethodOk { result, discarded_items }) => {
+			assert_eq!(result.len(), 4);
+			assert_eq!(discarded_items, 0);
+
+			assert_eq!(result[0].key, key);
+			assert_eq!(result[0].result, StorageResultType::Hash(expected_hash.clone()));
+			assert_eq!(result[1].key, key);
+			assert_eq!(result[1].result, StorageResultType::Value(expected_value.clone()));
+			assert_eq!(result[2].key, key);
+			assert_eq!(result[2].result, StorageResultType::Hash(expected_hash));
+			assert_eq!(result[3].key, key);
+			assert_eq!(result[3].result, StorageResultType::Value(expected_value));
+		},
+		_ => panic!("Unexpected result"),
+	};
+
+	// Child value set in `setup_api`.
+	let child_info = hex_string(&CHILD_STORAGE_KEY);
+	let genesis_hash = format!("{:?}", client.genesis_hash());
+	let expected_value = hex_string(&CHILD_VALUE);
+
+	let result: ArchiveStorageResult = api
+		.call(
+			"archive_unstable_storage",
+			rpc_params![
+				&genesis_hash,
+				vec![PaginatedStorageQuery {
+					key: key.clone(),
+					query_type: StorageQueryType::Value,
+					pagination_start_key: None,
+				}],
+				&child_info
+			],
+		)
+		.await
+		.unwrap();
+
+	match result {
+		ArchiveStorageResult::Ok(ArchiveStorageMethodOk { result, discarded_items }) => {
+			assert_eq!(
+				result,
+				vec![StorageResult { key, result: StorageResultType::Value(expected_value) }]
+			);
+			assert_eq!(discarded_items, 0);
+		},
+		_ => panic!("Unexpected result"),
+	};
+}
+
+#[tokio::test]
+async fn archive_storage_closest_merkle_value() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	/// The core of this test.
+	///
+	/// Checks keys that are exact match, keys with descendant and keys that should not return
+	/// values.
+	///
+	/// Returns (key, merkle value) pairs.
+	async fn expect_merkle_request(
+		api: &RpcModule<Archive<Backend, Block, Client<Backend>>>,
+		block_hash: String,
+	) -> Vec<(String, String)> {
+		let keys = [":AAAA", ":AAAB", ":A", ":AA", ":AAA", ":Z"];
+		let items = keys
+			.iter()
+			.map(|key| PaginatedStorageQuery {
+				key: hex_string(&key.as_bytes()),
+				query_type: StorageQueryType::ClosestDescendantMerkleValue,
+				pagination_start_key: None,
+			})
+			.collect::<Vec<_>>();
+
+		let result: ArchiveStorageResult = api
+			.call("archive_unstable_storage", rpc_params![&block_hash, items])
+			.await
+			.unwrap();
+
+		let merkle_values = match result {
+			ArchiveStorageResult::Ok(ArchiveStorageMethodOk { result, discarded_items }) => {
+				assert_eq!(discarded_items, 1);
+				result
+			},
+			_ => panic!("Unexpected result"),
+		};
+
+		// Key `:Z` was discarded and none of the other keys is missing a descendant.
+		assert_eq!(merkle_values.len(), 5);
+		merkle_values
+			.into_iter()
+			.map(|res| match res.result {
+				StorageResultType::ClosestDescendantMerkleValue(value) => (res.key, value),
+				_ => panic!("Unexpected StorageResultType"),
+			})
+			.collect()
+	}
+
+	// Import a new block with storage changes.
+	let mut builder = client.new_block(Default::default()).unwrap();
+	builder.push_storage_change(b":AAAA".to_vec(), Some(vec![1; 64])).unwrap();
+	builder.push_storage_change(b":AAAB".to_vec(), Some(vec![2; 64])).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let merkle_values_lhs = expect_merkle_request(&api, block_hash).await;
+	// The prefixes share the branch node holding both keys.
+	assert_eq!(merkle_values_lhs[2].1, merkle_values_lhs[3].1);
+	assert_eq!(merkle_values_lhs[3].1, merkle_values_lhs[4].1);
+	// The exact keys are separate leaves.
+	assert_ne!(merkle_values_lhs[0].1, merkle_values_lhs[1].1);
+
+	// Import a new block with a different value for `:AAAB`.
+	let mut builder = client.new_block_at(block.hash(), Default::default(), false).unwrap();
+	builder.push_storage_change(b":AAAB".to_vec(), Some(vec![3; 64])).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let merkle_values_rhs = expect_merkle_request(&api, block_hash).await;
+
+	// `:AAAA` is unchanged.
+	assert_eq!(merkle_values_lhs[0], merkle_values_rhs[0]);
+	// `:AAAB` and every node above it have changed.
+	assert_ne!(merkle_values_lhs[1].1, merkle_values_rhs[1].1);
+	assert_ne!(merkle_values_lhs[2].1, merkle_values_rhs[2].1);
+	assert_ne!(merkle_values_lhs[3].1, merkle_values_rhs[3].1);
+	assert_ne!(merkle_values_lhs[4].1, merkle_values_rhs[4].1);
+}
+
+#[tokio::test]
+async fn archive_storage_paginate_iterations() {
+	// 1 iteration allowed before pagination kicks in.
+	let (mut client, api) = setup_api(1, MAX_QUERIED_LIMIT);
+
+	// Import a new block with storage changes.
+	let mut builder = client.new_block(Default::default()).unwrap();
+	builder.push_storage_change(b":m".to_vec(), Some(b"a".to_vec())).unwrap();
+	builder.push_storage_change(b":mo".to_vec(), Some(b"ab".to_vec())).unwrap();
+	builder.push_storage_change(b":moc".to_vec(), Some(b"abc".to_vec())).unwrap();
+	builder.push_storage_change(b":moD".to_vec(), Some(b"abcmoD".to_vec())).unwrap();
+	builder.push_storage_change(b":mock".to_vec(), Some(b"abcd".to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	// Each call reports a single item and the next one resumes after it.
+	let mut pagination_start_key = None;
+	let mut reported = Vec::new();
+	loop {
+		let result: ArchiveStorageResult = api
+			.call(
+				"archive_unstable_storage",
+				rpc_params![
+					&block_hash,
+					vec![PaginatedStorageQuery {
+						key: hex_string(b":m"),
+						query_type: StorageQueryType::DescendantsValues,
+						pagination_start_key: pagination_start_key.clone(),
+					}]
+				],
+			)
+			.await
+			.unwrap();
+
+		let result = match result {
+			ArchiveStorageResult::Ok(ArchiveStorageMethodOk { result, discarded_items }) => {
+				assert_eq!(discarded_items, 0);
+				result
+			},
+			_ => panic!("Unexpected result"),
+		};
+		assert!(result.len() <= 1);
+
+		let Some(item) = result.into_iter().next() else { break };
+		pagination_start_key = Some(item.key.clone());
+		reported.push(item);
+	}
+
+	let expected =
+		[(":m", "a"), (":mo", "ab"), (":moD", "abcmoD"), (":moc", "abc"), (":mock", "abcd")]
+			.into_iter()
+			.map(|(key, value)| StorageResult {
+				key: hex_string(&key.as_bytes()),
+				result: StorageResultType::Value(hex_string(&value.as_bytes())),
+			})
+			.collect::<Vec<_>>();
+	assert_eq!(reported, expected);
+}
+
+#[tokio::test]
+async fn archive_storage_discarded_items() {
+	// One query at a time
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, 1);
+
+	// Import a new block with storage changes.
+	let mut builder = client.new_block(Default::default()).unwrap();
+	builder.push_storage_change(b":m".to_vec(), Some(b"a".to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	// Valid call with storage at the key.
+	let result: ArchiveStorageResult = api
+		.call(
+			"archive_unstable_storage",
+			rpc_params![
+				&block_hash,
+				vec![
+					PaginatedStorageQuery {
+						key: hex_string(b":m"),
+						query_type: StorageQueryType::Value,
+						pagination_start_key: None,
+					},
+					PaginatedStorageQuery {
+						key: hex_string(b":m"),
+						query_type: StorageQueryType::Hash,
+						pagination_start_key: None,
+					},
+					PaginatedStorageQuery {
+						key: hex_string(b":m"),
+						query_type: StorageQueryType::Hash,
+						pagination_start_key: None,
+					}
+				]
+			],
+		)
+		.await
+		.unwrap();
+	match result {
+		ArchiveStorageResult::Ok(ArchiveStorageMethodOk { result, discarded_items }) => {
+			assert_eq!(result.len(), 1);
+			assert_eq!(discarded_items, 2);
+
+			assert_eq!(result[0].key, hex_string(b":m"));
+			assert_eq!(result[0].result, StorageResultType::Value(hex_string(b"a")));
+		},
+		_ => panic!("Unexpected result"),
+	};
+}
+
+#[tokio::test]
+async fn archive_storage_invalid_params() {
+	let (client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+	let genesis_hash = format!("{:?}", client.genesis_hash());
+
+	// The pagination start key is only valid for descendants queries.
+	let err = api
+		.call::<_, serde_json::Value>(
+			"archive_unstable_storage",
+			rpc_params![
+				&genesis_hash,
+				vec![PaginatedStorageQuery {
+					key: hex_string(b":m"),
+					query_type: StorageQueryType::Value,
+					pagination_start_key: Some(hex_string(b":mo")),
+				}]
+			],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::Call(CallError::Custom(ref err)) if err.code() == 3001 && err.message().contains("Invalid parameter")
+	);
+
+	// Keys must be hex-encoded.
+	let err = api
+		.call::<_, serde_json::Value>(
+			"archive_unstable_storage",
+			rpc_params![
+				&genesis_hash,
+				vec![PaginatedStorageQuery {
+					key: "0x0".to_string(),
+					query_type: StorageQueryType::Value,
+					pagination_start_key: None,
+				}]
+			],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::Call(CallError::Custom(ref err)) if err.code() == 3001 && err.message().contains("Invalid parameter")
+	);
+}
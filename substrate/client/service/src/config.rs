@@ -34,7 +34,9 @@ pub use sc_network::{
 use prometheus_endpoint::Registry;
 use sc_chain_spec::ChainSpec;
 pub use sc_telemetry::TelemetryEndpoints;
-pub use sc_transaction_pool::Options as TransactionPoolOptions;
+pub use sc_transaction_pool::{
+	Options as TransactionPoolOptions, PersistenceOptions as TransactionPoolPersistenceOptions,
+};
 use sp_core::crypto::SecretString;
 use std::{
 	io, iter,
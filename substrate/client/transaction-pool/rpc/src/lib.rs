@@ -0,0 +1,258 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods to inspect the transactions in the transaction pool.
+//!
+//! The sender and nonce of a transaction are read from the `(sender, nonce)` tag that FRAME's
+//! `CheckNonce` signed extension makes the transaction provide.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+
+use codec::{Codec, DecodeAll, Encode};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_rpc_api::DenyUnsafe;
+use sc_transaction_pool::InspectedTransaction;
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+use sp_runtime::transaction_validity::TransactionSource;
+
+pub use sc_transaction_pool::InspectTransactionPool;
+
+#[cfg(test)]
+mod tests;
+
+/// A tag required by a future transaction that no transaction in the pool provides yet.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingTag<AccountId, Nonce> {
+	/// The raw tag.
+	pub tag: Bytes,
+	/// The sender of the awaited transaction, if the tag is a `(sender, nonce)` tag.
+	pub sender: Option<AccountId>,
+	/// The nonce of the awaited transaction, if the tag is a `(sender, nonce)` tag.
+	pub nonce: Option<Nonce>,
+}
+
+/// A transaction in the pool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolTransaction<Hash, AccountId, Nonce> {
+	/// The hash of the transaction.
+	pub hash: Hash,
+	/// The sender of the transaction, if known.
+	pub sender: Option<AccountId>,
+	/// The nonce of the transaction, if known.
+	pub nonce: Option<Nonce>,
+	/// The priority of the transaction.
+	pub priority: u64,
+	/// Tags the transaction provides.
+	pub provides: Vec<Bytes>,
+	/// Tags the transaction requires.
+	pub requires: Vec<Bytes>,
+	/// The block number until which the transaction is valid.
+	pub valid_till: u64,
+	/// Where the transaction came from.
+	pub source: TransactionSource,
+	/// Whether the transaction is propagated to other peers.
+	pub propagate: bool,
+	/// The length of the encoded transaction.
+	pub bytes: usize,
+	/// How long the transaction has been in the pool, in milliseconds.
+	pub in_pool_ms: u64,
+	/// The required tags that block a future transaction. Empty for ready transactions.
+	pub missing_tags: Vec<MissingTag<AccountId, Nonce>>,
+}
+
+/// Summary statistics of a set of transactions in the pool.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSummary {
+	/// The number of ready transactions.
+	pub ready: usize,
+	/// The number of future transactions.
+	pub future: usize,
+	/// The total length of the encoded ready transactions.
+	pub ready_bytes: usize,
+	/// The total length of the encoded future transactions.
+	pub future_bytes: usize,
+	/// The number of distinct known senders.
+	pub senders: usize,
+	/// How long the oldest transaction has been in the pool, in milliseconds.
+	pub oldest_in_pool_ms: Option<u64>,
+}
+
+/// The transactions in the pool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolContent<Hash, AccountId, Nonce> {
+	/// Ready transactions, in the order they would be included in a block.
+	pub ready: Vec<PoolTransaction<Hash, AccountId, Nonce>>,
+	/// Future transactions.
+	pub future: Vec<PoolTransaction<Hash, AccountId, Nonce>>,
+	/// Summary statistics of the listed transactions.
+	pub summary: PoolSummary,
+}
+
+/// Transaction pool inspection RPC methods.
+#[rpc(client, server)]
+pub trait TxPoolInspectApi<Hash, AccountId, Nonce> {
+	/// List the ready and future transactions in the pool.
+	///
+	/// If `account` is given, only the transactions sent by it are listed.
+	///
+	/// This method is unsafe, since it also reveals the transactions submitted locally.
+	#[method(name = "txpool_inspect")]
+	fn inspect(&self, account: Option<AccountId>)
+		-> RpcResult<PoolContent<Hash, AccountId, Nonce>>;
+
+	/// Get summary statistics of all the transactions in the pool.
+	#[method(name = "txpool_summary")]
+	fn summary(&self) -> RpcResult<PoolSummary>;
+}
+
+/// Implements the [`TxPoolInspectApiServer`] RPC trait on top of a transaction pool that can be
+/// inspected.
+pub struct TxPoolInspect<P, T> {
+	pool: Arc<P>,
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<T>,
+}
+
+impl<P, T> TxPoolInspect<P, T> {
+	/// Create a new instance of the transaction pool inspection RPC helper.
+	pub fn new(pool: Arc<P>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { pool, deny_unsafe, _marker: Default::default() }
+	}
+}
+
+/// Decode a `(sender, nonce)` tag.
+fn decode_nonce_tag<AccountId: Codec, Nonce: Codec>(tag: &[u8]) -> Option<(AccountId, Nonce)> {
+	<(AccountId, Nonce)>::decode_all(&mut &tag[..]).ok()
+}
+
+fn split<AccountId, Nonce>(
+	decoded: Option<(AccountId, Nonce)>,
+) -> (Option<AccountId>, Option<Nonce>) {
+	match decoded {
+		Some((sender, nonce)) => (Some(sender), Some(nonce)),
+		None => (None, None),
+	}
+}
+
+fn into_info<Hash, Ex, AccountId, Nonce>(
+	inspected: InspectedTransaction<Hash, Ex>,
+) -> PoolTransaction<Hash, AccountId, Nonce>
+where
+	Hash: Clone,
+	AccountId: Codec,
+	Nonce: Codec,
+{
+	let InspectedTransaction { transaction, in_pool_for, missing_tags } = inspected;
+	// `CheckNonce` makes the `(sender, nonce)` tag the first one a transaction provides.
+	let (sender, nonce) = split(transaction.provides.first().and_then(|tag| decode_nonce_tag(tag)));
+	let missing_tags = missing_tags
+		.into_iter()
+		.map(|tag| {
+			let (sender, nonce) = split(decode_nonce_tag(&tag));
+			MissingTag { tag: tag.into(), sender, nonce }
+		})
+		.collect();
+
+	PoolTransaction {
+		hash: transaction.hash.clone(),
+		sender,
+		nonce,
+		priority: transaction.priority,
+		provides: transaction.provides.iter().cloned().map(Into::into).collect(),
+		requires: transaction.requires.iter().cloned().map(Into::into).collect(),
+		valid_till: transaction.valid_till,
+		source: transaction.source,
+		propagate: transaction.propagate,
+		bytes: transaction.bytes,
+		in_pool_ms: u64::try_from(in_pool_for.as_millis()).unwrap_or(u64::MAX),
+		missing_tags,
+	}
+}
+
+fn summarize<Hash, AccountId: Encode, Nonce>(
+	ready: &[PoolTransaction<Hash, AccountId, Nonce>],
+	future: &[PoolTransaction<Hash, AccountId, Nonce>],
+) -> PoolSummary {
+	let all = || ready.iter().chain(future.iter());
+
+	PoolSummary {
+		ready: ready.len(),
+		future: future.len(),
+		ready_bytes: ready.iter().map(|tx| tx.bytes).sum(),
+		future_bytes: future.iter().map(|tx| tx.bytes).sum(),
+		senders: all()
+			.filter_map(|tx| tx.sender.as_ref().map(Encode::encode))
+			.collect::<HashSet<_>>()
+			.len(),
+		oldest_in_pool_ms: all().map(|tx| tx.in_pool_ms).max(),
+	}
+}
+
+impl<P, AccountId, Nonce> TxPoolInspect<P, (AccountId, Nonce)>
+where
+	P: InspectTransactionPool,
+	P::Hash: Clone,
+	AccountId: Codec + PartialEq,
+	Nonce: Codec,
+{
+	fn content(&self, account: Option<AccountId>) -> PoolContent<P::Hash, AccountId, Nonce> {
+		let inspection = self.pool.inspect();
+		let select = |transactions: Vec<InspectedTransaction<P::Hash, P::Extrinsic>>| {
+			transactions
+				.into_iter()
+				.map(into_info::<_, _, AccountId, Nonce>)
+				.filter(|info| {
+					account.as_ref().map_or(true, |account| info.sender.as_ref() == Some(account))
+				})
+				.collect::<Vec<_>>()
+		};
+		let ready = select(inspection.ready);
+		let future = select(inspection.future);
+		let summary = summarize(&ready, &future);
+
+		PoolContent { ready, future, summary }
+	}
+}
+
+impl<P, AccountId, Nonce> TxPoolInspectApiServer<P::Hash, AccountId, Nonce>
+	for TxPoolInspect<P, (AccountId, Nonce)>
+where
+	P: InspectTransactionPool + 'static,
+	P::Hash: Clone + Send + Sync + 'static,
+	AccountId: Codec + PartialEq + Send + Sync + 'static,
+	Nonce: Codec + Send + Sync + 'static,
+{
+	fn inspect(
+		&self,
+		account: Option<AccountId>,
+	) -> RpcResult<PoolContent<P::Hash, AccountId, Nonce>> {
+		self.deny_unsafe.check_if_safe()?;
+		Ok(self.content(account))
+	}
+
+	fn summary(&self) -> RpcResult<PoolSummary> {
+		Ok(self.content(None).summary)
+	}
+}
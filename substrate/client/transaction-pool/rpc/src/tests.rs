@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use assert_matches::assert_matches;
+use futures::executor::block_on;
+use jsonrpsee::{core::Error as JsonRpseeError, types::error::CallError};
+use sc_transaction_pool::{BasicPool, FullPool};
+use sc_transaction_pool_api::TransactionPool;
+use sp_runtime::generic::BlockId;
+use substrate_test_runtime_client::{
+	runtime::{AccountId, Block, Extrinsic, Nonce, Transfer},
+	AccountKeyring::{self, Alice, Bob},
+	TestClient,
+};
+
+type Api = TxPoolInspect<FullPool<Block, TestClient>, (AccountId, Nonce)>;
+
+fn transfer(from: AccountKeyring, nonce: Nonce) -> Extrinsic {
+	Transfer { from: from.into(), to: AccountKeyring::Charlie.into(), amount: 5, nonce }
+		.into_unchecked_extrinsic()
+}
+
+fn setup_api(deny_unsafe: DenyUnsafe) -> Api {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let spawner = sp_core::testing::TaskExecutor::new();
+	let pool = BasicPool::new_full(Default::default(), true.into(), None, spawner, client);
+
+	// Two ready transactions of Alice and a future one of Bob, waiting for nonce 4.
+	for xt in [transfer(Alice, 0), transfer(Alice, 1), transfer(Bob, 5)] {
+		block_on(pool.submit_one(&BlockId::number(0), TransactionSource::External, xt)).unwrap();
+	}
+
+	TxPoolInspect::new(pool, deny_unsafe)
+}
+
+#[test]
+fn inspect_should_deny_unsafe() {
+	let api = setup_api(DenyUnsafe::Yes);
+
+	assert_matches!(api.inspect(None), Err(JsonRpseeError::Call(CallError::Custom(e))) => {
+		assert!(e.message().contains("RPC call is unsafe to be called externally"));
+	});
+}
+
+#[test]
+fn inspect_should_list_ready_and_future_transactions() {
+	let api = setup_api(DenyUnsafe::No);
+
+	let (alice, bob) = (AccountId::from(Alice), AccountId::from(Bob));
+
+	let content = api.inspect(None).unwrap();
+
+	let ready = content.ready.iter().map(|tx| (tx.sender, tx.nonce)).collect::<Vec<_>>();
+	assert_eq!(ready, vec![(Some(alice), Some(0)), (Some(alice), Some(1))]);
+	assert!(content.ready.iter().all(|tx| tx.missing_tags.is_empty()));
+	assert_eq!(content.ready[1].requires, vec![Bytes::from((alice, 0u64).encode())]);
+
+	assert_eq!(content.future.len(), 1);
+	let future = &content.future[0];
+	assert_eq!((future.sender, future.nonce), (Some(bob), Some(5)));
+	assert_eq!(future.source, TransactionSource::External);
+	assert_eq!(
+		future.missing_tags,
+		vec![MissingTag { tag: (bob, 4u64).encode().into(), sender: Some(bob), nonce: Some(4) }]
+	);
+
+	assert_eq!(content.summary.ready, 2);
+	assert_eq!(content.summary.future, 1);
+	assert_eq!(content.summary.senders, 2);
+	assert_eq!(content.summary.ready_bytes, content.ready.iter().map(|tx| tx.bytes).sum::<usize>());
+	assert!(content.summary.oldest_in_pool_ms.is_some());
+}
+
+#[test]
+fn inspect_should_filter_by_account() {
+	let api = setup_api(DenyUnsafe::No);
+
+	let content = api.inspect(Some(AccountId::from(Bob))).unwrap();
+
+	assert!(content.ready.is_empty());
+	assert_eq!(content.future.iter().map(|tx| tx.nonce).collect::<Vec<_>>(), vec![Some(5)]);
+	assert_eq!(content.summary.senders, 1);
+}
+
+#[test]
+fn summary_should_be_safe() {
+	let api = setup_api(DenyUnsafe::Yes);
+
+	let summary = api.summary().unwrap();
+
+	assert_eq!(summary.ready, 2);
+	assert_eq!(summary.future, 1);
+	assert_eq!(summary.senders, 2);
+}
@@ -20,7 +20,13 @@
 //!
 //! For a more full-featured pool, have a look at the `pool` module.
 
-use std::{cmp::Ordering, collections::HashSet, fmt, hash, sync::Arc};
+use std::{
+	cmp::Ordering,
+	collections::HashSet,
+	fmt, hash,
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
 use crate::LOG_TARGET;
 use log::{debug, trace, warn};
@@ -502,6 +508,34 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		PruneStatus { pruned, failed, promoted }
 	}
 
+	/// Returns a snapshot of the transactions in the pool.
+	pub fn inspect(&self) -> PoolInspection<Hash, Ex> {
+		let now = Instant::now();
+		let ready = self
+			.ready
+			.get()
+			.map(|transaction| {
+				let imported_at = self.ready.imported_at(&transaction.hash).unwrap_or(now);
+				InspectedTransaction {
+					transaction,
+					in_pool_for: now.saturating_duration_since(imported_at),
+					missing_tags: Vec::new(),
+				}
+			})
+			.collect();
+		let future = self
+			.future
+			.waiting()
+			.map(|waiting| InspectedTransaction {
+				transaction: waiting.transaction.clone(),
+				in_pool_for: now.saturating_duration_since(waiting.imported_at),
+				missing_tags: waiting.missing_tags.iter().cloned().collect(),
+			})
+			.collect();
+
+		PoolInspection { ready, future }
+	}
+
 	/// Get pool status.
 	pub fn status(&self) -> PoolStatus {
 		PoolStatus {
@@ -513,6 +547,38 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 	}
 }
 
+/// A transaction in the pool together with its state in the pool.
+#[derive(Debug)]
+pub struct InspectedTransaction<Hash, Ex> {
+	/// The transaction.
+	pub transaction: Arc<Transaction<Hash, Ex>>,
+	/// How long the transaction has been in the pool.
+	pub in_pool_for: Duration,
+	/// Required tags that are not provided by any transaction in the pool yet.
+	///
+	/// Always empty for ready transactions.
+	pub missing_tags: Vec<Tag>,
+}
+
+impl<Hash, Ex> Clone for InspectedTransaction<Hash, Ex> {
+	fn clone(&self) -> Self {
+		Self {
+			transaction: self.transaction.clone(),
+			in_pool_for: self.in_pool_for,
+			missing_tags: self.missing_tags.clone(),
+		}
+	}
+}
+
+/// Snapshot of the transactions in the pool.
+#[derive(Debug)]
+pub struct PoolInspection<Hash, Ex> {
+	/// Ready transactions, in the order they would be included in a block.
+	pub ready: Vec<InspectedTransaction<Hash, Ex>>,
+	/// Future transactions.
+	pub future: Vec<InspectedTransaction<Hash, Ex>>,
+}
+
 /// Queue limits
 #[derive(Debug, Clone)]
 pub struct Limit {
@@ -565,6 +631,43 @@ mod tests {
 		assert_eq!(pool.ready.len(), 1);
 	}
 
+	#[test]
+	fn should_inspect_ready_and_future_transactions() {
+		// given
+		let mut pool = pool();
+		pool.import(Transaction { data: vec![1u8], provides: vec![vec![1]], ..DEFAULT_TX.clone() })
+			.unwrap();
+		pool.import(Transaction {
+			data: vec![2u8],
+			hash: 2,
+			requires: vec![vec![1]],
+			provides: vec![vec![2]],
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		pool.import(Transaction {
+			data: vec![4u8],
+			hash: 4,
+			requires: vec![vec![2], vec![3]],
+			provides: vec![vec![4]],
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+
+		// when
+		let inspection = pool.inspect();
+
+		// then
+		assert_eq!(
+			inspection.ready.iter().map(|tx| tx.transaction.hash).collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+		assert!(inspection.ready.iter().all(|tx| tx.missing_tags.is_empty()));
+		assert_eq!(inspection.future.len(), 1);
+		assert_eq!(inspection.future[0].transaction.hash, 4);
+		assert_eq!(inspection.future[0].missing_tags, vec![vec![3]]);
+	}
+
 	#[test]
 	fn should_not_import_same_transaction_twice() {
 		// given
@@ -21,6 +21,7 @@ use std::{
 	collections::{BTreeSet, HashMap, HashSet},
 	hash,
 	sync::Arc,
+	time::Instant,
 };
 
 use crate::LOG_TARGET;
@@ -86,6 +87,8 @@ pub struct ReadyTx<Hash, Ex> {
 	/// Some transactions might be already pruned from the queue,
 	/// so when we compute ready set we may consider this transactions ready earlier.
 	pub requires_offset: usize,
+	/// Time of import to the pool.
+	pub imported_at: Instant,
 }
 
 impl<Hash: Clone, Ex> Clone for ReadyTx<Hash, Ex> {
@@ -94,6 +97,7 @@ impl<Hash: Clone, Ex> Clone for ReadyTx<Hash, Ex> {
 			transaction: self.transaction.clone(),
 			unlocks: self.unlocks.clone(),
 			requires_offset: self.requires_offset,
+			imported_at: self.imported_at,
 		}
 	}
 }
@@ -192,6 +196,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		self.insertion_id += 1;
 		let insertion_id = self.insertion_id;
 		let hash = tx.transaction.hash.clone();
+		let imported_at = tx.imported_at;
 		let transaction = tx.transaction;
 
 		let (replaced, unlocks) = self.replace_previous(&transaction)?;
@@ -227,7 +232,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		}
 
 		// insert to Ready
-		ready.insert(hash, ReadyTx { transaction, unlocks, requires_offset });
+		ready.insert(hash, ReadyTx { transaction, unlocks, requires_offset, imported_at });
 
 		Ok(replaced)
 	}
@@ -245,6 +250,11 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		self.ready.read().contains_key(hash)
 	}
 
+	/// Returns the time the given transaction was imported to the pool.
+	pub fn imported_at(&self, hash: &Hash) -> Option<Instant> {
+		self.ready.read().get(hash).map(|tx| tx.imported_at)
+	}
+
 	/// Retrieve transaction by hash
 	pub fn by_hash(&self, hash: &Hash) -> Option<Arc<Transaction<Hash, Ex>>> {
 		self.by_hashes(&[hash.clone()]).into_iter().next().unwrap_or(None)
@@ -35,7 +35,7 @@ use sp_runtime::{
 use std::time::Instant;
 
 use super::{
-	base_pool::{self as base, PruneStatus},
+	base_pool::{self as base, PoolInspection, PruneStatus},
 	listener::Listener,
 	pool::{
 		BlockHash, ChainApi, EventStream, ExtrinsicFor, ExtrinsicHash, Options, TransactionFor,
@@ -625,6 +625,11 @@ impl<B: ChainApi> ValidatedPool<B> {
 		self.pool.read().futures().map(|tx| (tx.hash, tx.data.clone())).collect()
 	}
 
+	/// Returns a snapshot of the transactions in the pool.
+	pub fn inspect(&self) -> PoolInspection<ExtrinsicHash<B>, ExtrinsicFor<B>> {
+		self.pool.read().inspect()
+	}
+
 	/// Returns pool status.
 	pub fn status(&self) -> PoolStatus {
 		self.pool.read().status()
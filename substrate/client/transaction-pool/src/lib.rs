@@ -27,11 +27,12 @@ mod enactment_state;
 pub mod error;
 mod graph;
 mod metrics;
+mod persistence;
 mod revalidation;
 #[cfg(test)]
 mod tests;
 
-pub use crate::api::FullChainApi;
+pub use crate::{api::FullChainApi, persistence::PersistenceOptions};
 use async_trait::async_trait;
 use enactment_state::{EnactmentAction, EnactmentState};
 use futures::{
@@ -40,7 +41,8 @@ use futures::{
 	prelude::*,
 };
 pub use graph::{
-	base_pool::Limit as PoolLimit, ChainApi, Options, Pool, Transaction, ValidatedTransaction,
+	base_pool::{InspectedTransaction, Limit as PoolLimit, PoolInspection},
+	ChainApi, Options, Pool, Transaction, ValidatedTransaction,
 };
 use parking_lot::Mutex;
 use std::{
@@ -198,6 +200,7 @@ where
 		best_block_hash: Block::Hash,
 		finalized_hash: Block::Hash,
 	) -> Self {
+		let persistence = options.persistence.clone();
 		let pool = Arc::new(graph::Pool::new(options, is_validator, pool_api.clone()));
 		let (revalidation_queue, background_task) = match revalidation_type {
 			RevalidationType::Light =>
@@ -213,6 +216,16 @@ where
 			spawner.spawn_essential("txpool-background", Some("transaction-pool"), background_task);
 		}
 
+		let metrics = PrometheusMetrics::new(prometheus);
+
+		if let Some(options) = persistence {
+			spawner.spawn_essential(
+				"txpool-persistence",
+				Some("transaction-pool"),
+				persistence::run(pool.clone(), options, best_block_hash, metrics.clone()).boxed(),
+			);
+		}
+
 		Self {
 			api: pool_api,
 			pool,
@@ -223,7 +236,7 @@ where
 				RevalidationType::Full => RevalidationStrategy::Always,
 			})),
 			ready_poll: Arc::new(Mutex::new(ReadyPoll::new(best_block_number))),
-			metrics: PrometheusMetrics::new(prometheus),
+			metrics,
 			enactment_state: Arc::new(Mutex::new(EnactmentState::new(
 				best_block_hash,
 				finalized_hash,
@@ -366,6 +379,30 @@ where
 	}
 }
 
+/// Provides a snapshot of the transactions in a transaction pool.
+pub trait InspectTransactionPool: Send + Sync {
+	/// Transaction hash type.
+	type Hash;
+	/// Extrinsic type.
+	type Extrinsic;
+
+	/// Returns the ready and future transactions in the pool together with their state.
+	fn inspect(&self) -> PoolInspection<Self::Hash, Self::Extrinsic>;
+}
+
+impl<PoolApi, Block> InspectTransactionPool for BasicPool<PoolApi, Block>
+where
+	Block: BlockT,
+	PoolApi: 'static + graph::ChainApi<Block = Block>,
+{
+	type Hash = graph::ExtrinsicHash<PoolApi>;
+	type Extrinsic = graph::ExtrinsicFor<PoolApi>;
+
+	fn inspect(&self) -> PoolInspection<Self::Hash, Self::Extrinsic> {
+		self.pool.validated_pool().inspect()
+	}
+}
+
 impl<Block, Client> FullPool<Block, Client>
 where
 	Block: BlockT,
@@ -0,0 +1,349 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool persistence.
+//!
+//! The ready and future queues of the pool can be stored to a file, so that pending
+//! transactions survive a restart of the node. After the restart the stored transactions
+//! are validated again against the best block and re-imported into the pool.
+
+use std::{
+	fs,
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
+
+use codec::{Decode, DecodeAll, Encode};
+use sp_runtime::{generic::BlockId, transaction_validity::TransactionSource};
+
+use crate::{
+	graph::{BlockHash, ChainApi, ExtrinsicFor, Pool},
+	metrics::MetricsLink as PrometheusMetrics,
+	LOG_TARGET,
+};
+
+/// Version of the persisted pool format.
+const PERSISTENCE_VERSION: u32 = 1;
+
+/// Pool persistence options.
+#[derive(Debug, Clone)]
+pub struct PersistenceOptions {
+	/// Path of the file the pool is persisted to.
+	///
+	/// A relative path is resolved against the chain's data directory by the CLI.
+	pub path: PathBuf,
+	/// How often the pool is persisted while the node is running.
+	///
+	/// If `None`, the pool is only persisted on shutdown.
+	pub interval: Option<Duration>,
+}
+
+/// Persistence error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Reading or writing the file failed.
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+	/// The file content could not be decoded.
+	#[error("Decoding error: {0}")]
+	Codec(#[from] codec::Error),
+	/// The file was written in an unknown format.
+	#[error("Unsupported persisted pool version {0}")]
+	UnsupportedVersion(u32),
+}
+
+/// Outcome of restoring the persisted transactions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RestoreOutcome {
+	/// Number of transactions imported back into the pool.
+	pub restored: usize,
+	/// Number of transactions that were no longer valid.
+	pub dropped: usize,
+}
+
+/// Store the ready and future transactions of the pool to the given file.
+///
+/// Ready transactions are stored first, in the order they would be included in a block.
+/// Returns the number of stored transactions.
+pub fn save<Api: ChainApi>(pool: &Pool<Api>, path: &Path) -> Result<usize, Error> {
+	let validated_pool = pool.validated_pool();
+	let mut transactions = validated_pool
+		.ready()
+		.map(|tx| (tx.source, tx.data.clone()))
+		.collect::<Vec<(TransactionSource, ExtrinsicFor<Api>)>>();
+	transactions
+		.extend(validated_pool.pool.read().futures().map(|tx| (tx.source, tx.data.clone())));
+
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+
+	// Write to a temporary file first, so that a crash in the middle of writing
+	// does not leave a truncated file behind.
+	let tmp_path = path.with_extension("tmp");
+	fs::write(&tmp_path, (PERSISTENCE_VERSION, &transactions).encode())?;
+	fs::rename(&tmp_path, path)?;
+
+	Ok(transactions.len())
+}
+
+/// Load the transactions stored in the given file.
+///
+/// Returns an empty list if the file does not exist.
+pub fn load<Api: ChainApi>(
+	path: &Path,
+) -> Result<Vec<(TransactionSource, ExtrinsicFor<Api>)>, Error> {
+	let encoded = match fs::read(path) {
+		Ok(encoded) => encoded,
+		Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let input = &mut &encoded[..];
+	let version = u32::decode(input)?;
+	if version != PERSISTENCE_VERSION {
+		return Err(Error::UnsupportedVersion(version))
+	}
+
+	Ok(Vec::decode_all(input)?)
+}
+
+/// Validate the given transactions at `at` and import the valid ones into the pool.
+pub async fn restore<Api: ChainApi>(
+	pool: &Pool<Api>,
+	at: &BlockId<Api::Block>,
+	transactions: Vec<(TransactionSource, ExtrinsicFor<Api>)>,
+) -> RestoreOutcome {
+	let mut outcome = RestoreOutcome::default();
+
+	for source in
+		[TransactionSource::Local, TransactionSource::External, TransactionSource::InBlock]
+	{
+		let xts = transactions
+			.iter()
+			.filter(|(tx_source, _)| *tx_source == source)
+			.map(|(_, xt)| xt.clone())
+			.collect::<Vec<_>>();
+		if xts.is_empty() {
+			continue
+		}
+
+		let count = xts.len();
+		match pool.resubmit_at(at, source, xts).await {
+			Ok(results) => {
+				let restored = results.iter().filter(|result| result.is_ok()).count();
+				outcome.restored += restored;
+				outcome.dropped += count - restored;
+			},
+			Err(e) => {
+				log::debug!(
+					target: LOG_TARGET,
+					"Error restoring {} persisted {:?} transactions: {}",
+					count,
+					source,
+					e,
+				);
+				outcome.dropped += count;
+			},
+		}
+	}
+
+	outcome
+}
+
+/// Store the pool to the given file, logging the outcome.
+pub fn persist<Api: ChainApi>(pool: &Pool<Api>, path: &Path) {
+	match save(pool, path) {
+		Ok(count) => log::debug!(
+			target: LOG_TARGET,
+			"Persisted {} transactions to {}",
+			count,
+			path.display(),
+		),
+		Err(e) => log::warn!(
+			target: LOG_TARGET,
+			"Failed to persist transactions to {}: {}",
+			path.display(),
+			e,
+		),
+	}
+}
+
+/// Persists the pool when dropped.
+///
+/// The task manager drops the essential tasks when the node shuts down, so this saves the
+/// pool at shutdown regardless of who still holds the pool itself.
+struct PersistOnDrop<Api: ChainApi> {
+	pool: Arc<Pool<Api>>,
+	path: PathBuf,
+}
+
+impl<Api: ChainApi> Drop for PersistOnDrop<Api> {
+	fn drop(&mut self) {
+		persist(&self.pool, &self.path);
+	}
+}
+
+/// Background task of the pool persistence.
+///
+/// Restores the previously persisted transactions at the block `at` and then persists
+/// the pool every [`PersistenceOptions::interval`], if set, and once more when the task
+/// is dropped at shutdown.
+pub async fn run<Api: ChainApi>(
+	pool: Arc<Pool<Api>>,
+	options: PersistenceOptions,
+	at: BlockHash<Api>,
+	metrics: PrometheusMetrics,
+) {
+	match load::<Api>(&options.path) {
+		Ok(transactions) if !transactions.is_empty() => {
+			let outcome = restore(&pool, &BlockId::Hash(at), transactions).await;
+			log::info!(
+				target: LOG_TARGET,
+				"Restored {} persisted transactions, dropped {} that are no longer valid",
+				outcome.restored,
+				outcome.dropped,
+			);
+			metrics.report(|metrics| {
+				metrics.persisted_transactions_restored.inc_by(outcome.restored as u64);
+				metrics.persisted_transactions_dropped.inc_by(outcome.dropped as u64);
+			});
+		},
+		Ok(_) => {},
+		Err(e) => log::warn!(
+			target: LOG_TARGET,
+			"Failed to load persisted transactions from {}: {}",
+			options.path.display(),
+			e,
+		),
+	}
+
+	// Created only after the restore, so that a shutdown in the middle of it does not
+	// overwrite the file with a partially restored pool.
+	let guard = PersistOnDrop { pool, path: options.path };
+
+	let interval = match options.interval {
+		Some(interval) => interval,
+		// This is an essential task, so it must not finish.
+		None => return futures::future::pending().await,
+	};
+
+	loop {
+		futures_timer::Delay::new(interval).await;
+		persist(&guard.pool, &guard.path);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{pool, uxt, TestApi};
+	use assert_matches::assert_matches;
+	use futures::{channel::oneshot, executor::block_on, future::Either, FutureExt};
+	use substrate_test_runtime::{AccountId, Transfer, H256};
+	use substrate_test_runtime_client::AccountKeyring::Alice;
+
+	fn transfer(nonce: u64) -> Transfer {
+		Transfer {
+			from: Alice.into(),
+			to: AccountId::from_h256(H256::from_low_u64_be(2)),
+			amount: 5,
+			nonce,
+		}
+	}
+
+	#[test]
+	fn should_persist_and_restore_transactions() {
+		// given
+		let tmp_dir = tempfile::tempdir().unwrap();
+		let path = tmp_dir.path().join("txpool.bin");
+		let pool = pool();
+		block_on(pool.submit_at(
+			&BlockId::Number(0),
+			TransactionSource::External,
+			vec![uxt(transfer(0)), uxt(transfer(1)), uxt(transfer(3))],
+		))
+		.unwrap();
+		block_on(pool.submit_one(&BlockId::Number(0), TransactionSource::Local, uxt(transfer(5))))
+			.unwrap();
+		assert_eq!(pool.validated_pool().status().ready, 2);
+		assert_eq!(pool.validated_pool().status().future, 2);
+
+		// when
+		assert_eq!(save(&pool, &path).unwrap(), 4);
+		let transactions = load::<TestApi>(&path).unwrap();
+		let restored_pool = pool();
+		let outcome = block_on(restore(&restored_pool, &BlockId::Number(1), transactions));
+
+		// then
+		// the transaction with nonce 0 is stale at block 1
+		assert_eq!(outcome, RestoreOutcome { restored: 3, dropped: 1 });
+		let status = restored_pool.validated_pool().status();
+		assert_eq!(status.ready, 1);
+		assert_eq!(status.future, 2);
+	}
+
+	#[test]
+	fn should_persist_pool_when_task_is_stopped_at_shutdown() {
+		// given
+		let tmp_dir = tempfile::tempdir().unwrap();
+		let path = tmp_dir.path().join("txpool.bin");
+		let pool = Arc::new(pool());
+		let options = PersistenceOptions { path: path.clone(), interval: None };
+		let task = run(pool.clone(), options, H256::default(), PrometheusMetrics::new(None));
+		block_on(pool.submit_one(&BlockId::Number(0), TransactionSource::Local, uxt(transfer(0))))
+			.unwrap();
+
+		// when
+		// the task manager races every essential task against its exit signal and drops
+		// the task once the signal fires
+		let (exit_tx, exit_rx) = oneshot::channel::<()>();
+		exit_tx.send(()).unwrap();
+		match block_on(futures::future::select(task.boxed(), exit_rx)) {
+			Either::Right((_, task)) => drop(task),
+			Either::Left(_) => panic!("the persistence task must not finish"),
+		}
+
+		// then
+		// the pool is still held here, so it was the dropped task that saved it
+		assert_eq!(Arc::strong_count(&pool), 1);
+		let transactions = load::<TestApi>(&path).unwrap();
+		assert_eq!(transactions, vec![(TransactionSource::Local, uxt(transfer(0)))]);
+	}
+
+	#[test]
+	fn should_load_nothing_if_file_is_missing() {
+		let tmp_dir = tempfile::tempdir().unwrap();
+		let path = tmp_dir.path().join("txpool.bin");
+
+		assert!(load::<TestApi>(&path).unwrap().is_empty());
+	}
+
+	#[test]
+	fn should_reject_unknown_version() {
+		let tmp_dir = tempfile::tempdir().unwrap();
+		let path = tmp_dir.path().join("txpool.bin");
+		fs::write(&path, (PERSISTENCE_VERSION + 1, Vec::<u8>::new()).encode()).unwrap();
+
+		assert_matches!(
+			load::<TestApi>(&path),
+			Err(Error::UnsupportedVersion(v)) if v == PERSISTENCE_VERSION + 1
+		);
+	}
+}
@@ -89,6 +89,10 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 const MOTION_DURATION_IN_BLOCKS: BlockNumber = 3;
@@ -264,6 +268,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			(8, 50),
 			(9, 50),
 		],
+		..Default::default()
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
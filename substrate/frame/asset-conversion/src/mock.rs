@@ -88,6 +88,10 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl pallet_assets::Config<Instance1> for Test {
@@ -112,6 +116,8 @@ impl pallet_assets::Config<Instance1> for Test {
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
+		type Blacklist = ();
+		type MaxMemoLen = ConstU32<256>;
 }
 
 impl pallet_assets::Config<Instance2> for Test {
@@ -137,6 +143,8 @@ impl pallet_assets::Config<Instance2> for Test {
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
+		type Blacklist = ();
+		type MaxMemoLen = ConstU32<256>;
 }
 
 parameter_types! {
@@ -182,6 +190,7 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 
 	pallet_balances::GenesisConfig::<Test> {
 		balances: vec![(1, 10000), (2, 20000), (3, 30000), (4, 40000)],
+		..Default::default()
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
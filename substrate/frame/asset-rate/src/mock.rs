@@ -76,6 +76,10 @@ impl pallet_balances::Config for Test {
 	type FreezeIdentifier = ();
 	type MaxHolds = ();
 	type MaxFreezes = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl pallet_asset_rate::Config for Test {
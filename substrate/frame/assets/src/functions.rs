@@ -135,6 +135,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if increase_supply && details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
+		if T::Blacklist::is_blacklisted(who) {
+			return DepositConsequence::Blocked
+		}
 		if let Some(account) = Account::<T, I>::get(id, who) {
 			if account.status.is_blocked() {
 				return DepositConsequence::Blocked
@@ -178,6 +181,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if amount.is_zero() {
 			return Success
 		}
+		if T::Blacklist::is_blacklisted(who) {
+			return Frozen
+		}
 		let account = match Account::<T, I>::get(&id, who) {
 			Some(a) => a,
 			None => return BalanceLow,
@@ -220,6 +226,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
+		ensure!(!T::Blacklist::is_blacklisted(who), TokenError::Frozen);
 
 		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
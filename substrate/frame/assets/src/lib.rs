@@ -170,6 +170,7 @@ use frame_support::{
 	pallet_prelude::DispatchResultWithPostInfo,
 	storage::KeyPrefixIterator,
 	traits::{
+		blacklist::BlackListAccounts,
 		tokens::{fungibles, DepositConsequence, WithdrawConsequence},
 		BalanceStatus::Reserved,
 		Currency, EnsureOriginWithArg, ReservableCurrency, StoredMap,
@@ -316,6 +317,13 @@ pub mod pallet {
 		/// Callback methods for asset state change (e.g. asset created or destroyed)
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// The accounts which may neither send nor receive any asset.
+		type Blacklist: BlackListAccounts<Self::AccountId>;
+
+		/// The maximum length of the memo attached to a transfer.
+		#[pallet::constant]
+		type MaxMemoLen: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -524,6 +532,14 @@ pub mod pallet {
 		Touched { asset_id: T::AssetId, who: T::AccountId, depositor: T::AccountId },
 		/// Some account `who` was blocked.
 		Blocked { asset_id: T::AssetId, who: T::AccountId },
+		/// Some assets were transferred along with a memo.
+		TransferredWithMemo {
+			asset_id: T::AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			memo: BoundedVec<u8, T::MaxMemoLen>,
+		},
 	}
 
 	#[pallet::error]
@@ -1636,6 +1652,44 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Blocked { asset_id: id, who });
 			Ok(())
 		}
+
+		/// Move some assets from the sender account to another, attaching a memo to the transfer.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
+		/// `target`'s balance increased.
+		/// - `memo`: A reference for the receiver, at most `MaxMemoLen` bytes long.
+		///
+		/// Emits `Transferred` with the actual amount transferred, followed by
+		/// `TransferredWithMemo`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		pub fn transfer_with_memo(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: T::Balance,
+			memo: BoundedVec<u8, T::MaxMemoLen>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+			let id: T::AssetId = id.into();
+
+			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+			let amount = Self::do_transfer(id.clone(), &origin, &dest, amount, None, f)?;
+			Self::deposit_event(Event::TransferredWithMemo {
+				asset_id: id,
+				from: origin,
+				to: dest,
+				amount,
+				memo,
+			});
+			Ok(())
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -86,6 +86,10 @@ impl pallet_balances::Config for Test {
 	type FreezeIdentifier = ();
 	type MaxHolds = ();
 	type MaxFreezes = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 pub struct AssetsCallbackHandle;
@@ -152,6 +156,8 @@ impl Config for Test {
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type Blacklist = Balances;
+	type MaxMemoLen = ConstU32<256>;
 }
 
 use std::collections::HashMap;
@@ -22,7 +22,8 @@ use crate::{mock::*, Error};
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::GetDispatchInfo,
-	traits::{fungibles::InspectEnumerable, tokens::Preservation::Protect, Currency},
+	traits::{fungibles::InspectEnumerable, tokens::Preservation::Protect, ConstU32, Currency},
+	BoundedVec,
 };
 use pallet_balances::Error as BalancesError;
 use sp_io::storage;
@@ -68,6 +69,38 @@ fn transfer_should_never_burn() {
 	});
 }
 
+#[test]
+fn transfer_with_memo_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		let memo: BoundedVec<u8, ConstU32<256>> = b"invoice 42".to_vec().try_into().unwrap();
+		assert_ok!(Assets::transfer_with_memo(RuntimeOrigin::signed(1), 0, 2, 40, memo.clone()));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::balance(0, 2), 40);
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 1,
+			to: 2,
+			amount: 40,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferredWithMemo {
+			asset_id: 0,
+			from: 1,
+			to: 2,
+			amount: 40,
+			memo,
+		}));
+
+		// A failed transfer emits no memo.
+		assert_noop!(
+			Assets::transfer_with_memo(RuntimeOrigin::signed(2), 0, 3, 50, Default::default()),
+			Error::<Test>::BalanceLow
+		);
+	});
+}
+
 #[test]
 fn basic_minting_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1733,6 +1766,27 @@ fn multiple_transfer_alls_work_ok() {
 	});
 }
 
+#[test]
+fn blacklisted_accounts_can_neither_send_nor_receive_assets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Balances::blacklist(RuntimeOrigin::root(), 2, 0, Some(10)));
+
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 50), TokenError::Frozen);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50), TokenError::Blocked);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 50), TokenError::Blocked);
+
+		// Once the entry lapses, the account may move its assets again.
+		System::set_block_number(10);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 50));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
+		assert_eq!(Assets::balance(0, 1), 130);
+		assert_eq!(Assets::balance(0, 2), 70);
+	});
+}
+
 #[test]
 fn weights_sane() {
 	let info = crate::Call::<Test>::create { id: 10, admin: 4, min_balance: 3 }.get_dispatch_info();
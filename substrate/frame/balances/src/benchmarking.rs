@@ -23,6 +23,7 @@ use super::*;
 use crate::Pallet as Balances;
 
 use frame_benchmarking::v2::*;
+use frame_support::traits::blacklist::BlackListAccounts;
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded;
 use types::ExtraFlags;
@@ -31,6 +32,17 @@ const SEED: u32 = 0;
 // existential deposit multiplier
 const ED_MULTIPLIER: u32 = 10;
 
+/// Make `who` a member that may mint and burn `allowance` per period.
+fn add_member<T: Config<I>, I: 'static>(who: &T::AccountId, allowance: T::Balance) {
+	let mut members = MemberShip::<T, I>::get().unwrap_or_default();
+	members.try_push(who.clone()).expect("benchmarks add fewer than `MaxMembers` members");
+	MemberShip::<T, I>::put(members);
+	Allowances::<T, I>::mutate(who, |member| {
+		member.mint_limit = allowance;
+		member.burn_limit = allowance;
+	});
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -108,6 +120,28 @@ mod benchmarks {
 		assert_eq!(Balances::<T, I>::free_balance(&recipient), transfer_amount);
 	}
 
+	// Benchmark `transfer_with_memo` with the worst possible condition:
+	// * The recipient account is created.
+	// * The memo is `MaxMemoLen` bytes long.
+	#[benchmark]
+	fn transfer_with_memo() {
+		let caller = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		let _ =
+			<Balances<T, I> as Currency<_>>::make_free_balance_be(&caller, T::Balance::max_value());
+		let existential_deposit = T::ExistentialDeposit::get();
+		let transfer_amount = existential_deposit.saturating_mul(ED_MULTIPLIER.into());
+		let memo: BoundedVec<u8, T::MaxMemoLen> =
+			vec![0u8; T::MaxMemoLen::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), recipient_lookup, transfer_amount, memo);
+
+		assert_eq!(Balances::<T, I>::free_balance(&recipient), transfer_amount);
+	}
+
 	// Benchmark `force_set_balance` coming from ROOT account. This always creates an account.
 	#[benchmark]
 	fn force_set_balance_creating() {
@@ -286,6 +320,196 @@ mod benchmarks {
 		}
 	}
 
+	// Benchmark `mint` with a member minting into a new account.
+	#[benchmark]
+	fn mint() {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let amount = T::ExistentialDeposit::get().saturating_mul(ED_MULTIPLIER.into());
+		add_member::<T, I>(&caller, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), amount, recipient_lookup);
+
+		assert_eq!(Balances::<T, I>::free_balance(&recipient), amount);
+	}
+
+	// Benchmark `burn` with a member burning part of an existing balance.
+	#[benchmark]
+	fn burn() {
+		let caller: T::AccountId = whitelisted_caller();
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup = T::Lookup::unlookup(source.clone());
+		let existential_deposit = T::ExistentialDeposit::get();
+		let balance = existential_deposit.saturating_mul(ED_MULTIPLIER.into());
+		let _ = <Balances<T, I> as Currency<_>>::make_free_balance_be(&source, balance);
+		let amount = balance.saturating_sub(existential_deposit);
+		add_member::<T, I>(&caller, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), amount, source_lookup);
+
+		assert_eq!(Balances::<T, I>::free_balance(&source), existential_deposit);
+	}
+
+	#[benchmark]
+	fn set_member() {
+		let member: T::AccountId = account("member", 0, SEED);
+		let member_lookup = T::Lookup::unlookup(member.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, member_lookup);
+
+		assert!(MemberShip::<T, I>::get().unwrap_or_default().contains(&member));
+	}
+
+	#[benchmark]
+	fn blacklist() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let origin =
+			T::BlacklistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target_lookup, 1, Some(expiry));
+
+		assert!(Balances::<T, I>::is_blacklisted(&target));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn whitelist() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		BlackList::<T, I>::insert(
+			&target,
+			BlacklistInfo { reason: 1, set_by: None, expiry: None },
+		);
+		let origin =
+			T::BlacklistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target_lookup);
+
+		assert!(!BlackList::<T, I>::contains_key(&target));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_member(p: Linear<0, { T::MaxPendingMints::get() }>) -> Result<(), BenchmarkError> {
+		let amount = T::ExistentialDeposit::get().saturating_mul(ED_MULTIPLIER.into());
+		let member: T::AccountId = account("member", 0, SEED);
+		add_member::<T, I>(&member, amount);
+		CurrentMintPolicy::<T, I>::put(MintPolicy {
+			period: Zero::zero(),
+			issuance_cap: None,
+			approval_threshold: Some(Zero::zero()),
+			required_approvals: 2,
+		});
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		for _ in 0..p {
+			Balances::<T, I>::mint(
+				RawOrigin::Signed(member.clone()).into(),
+				amount,
+				T::Lookup::unlookup(recipient.clone()),
+			)?;
+		}
+		let member_lookup = T::Lookup::unlookup(member.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, member_lookup);
+
+		assert!(!MemberShip::<T, I>::get().unwrap_or_default().contains(&member));
+		assert!(PendingMints::<T, I>::iter().next().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_allowance() {
+		let member: T::AccountId = account("member", 0, SEED);
+		add_member::<T, I>(&member, Zero::zero());
+		let member_lookup = T::Lookup::unlookup(member.clone());
+		let limit = T::ExistentialDeposit::get().saturating_mul(ED_MULTIPLIER.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, member_lookup, limit, limit);
+
+		assert_eq!(Allowances::<T, I>::get(&member).mint_limit, limit);
+	}
+
+	#[benchmark]
+	fn set_mint_policy() {
+		let policy = MintPolicy {
+			period: 100u32.into(),
+			issuance_cap: Some(T::Balance::max_value()),
+			approval_threshold: Some(T::ExistentialDeposit::get()),
+			required_approvals: 3,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, policy.clone());
+
+		assert_eq!(CurrentMintPolicy::<T, I>::get(), policy);
+	}
+
+	// Benchmark `approve_mint` with the approval that executes the mint.
+	#[benchmark]
+	fn approve_mint() -> Result<(), BenchmarkError> {
+		let amount = T::ExistentialDeposit::get().saturating_mul(ED_MULTIPLIER.into());
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		let approver: T::AccountId = account("approver", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		for member in [&proposer, &approver, &caller] {
+			add_member::<T, I>(member, amount);
+		}
+		CurrentMintPolicy::<T, I>::put(MintPolicy {
+			period: Zero::zero(),
+			issuance_cap: Some(T::Balance::max_value()),
+			approval_threshold: Some(Zero::zero()),
+			required_approvals: 3,
+		});
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		Balances::<T, I>::mint(
+			RawOrigin::Signed(proposer).into(),
+			amount,
+			T::Lookup::unlookup(recipient.clone()),
+		)?;
+		Balances::<T, I>::approve_mint(RawOrigin::Signed(approver).into(), 0)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert_eq!(Balances::<T, I>::free_balance(&recipient), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_mint() -> Result<(), BenchmarkError> {
+		let amount = T::ExistentialDeposit::get().saturating_mul(ED_MULTIPLIER.into());
+		let caller: T::AccountId = whitelisted_caller();
+		add_member::<T, I>(&caller, amount);
+		CurrentMintPolicy::<T, I>::put(MintPolicy {
+			period: Zero::zero(),
+			issuance_cap: None,
+			approval_threshold: Some(Zero::zero()),
+			required_approvals: 2,
+		});
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		Balances::<T, I>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			amount,
+			T::Lookup::unlookup(recipient),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(PendingMints::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Balances,
 		crate::tests::ExtBuilder::default().build(),
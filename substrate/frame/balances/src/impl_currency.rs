@@ -319,6 +319,8 @@ where
 		memo: Vec<u8>,
 		existence_requirement: ExistenceRequirement,
 	) -> DispatchResult {
+        let memo: BoundedVec<u8, T::MaxMemoLen> =
+			memo.try_into().map_err(|_| Error::<T, I>::MemoTooLong)?;
         if value.is_zero() || transactor == dest {
 			return Ok(())
 		}
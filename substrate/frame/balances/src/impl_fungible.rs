@@ -17,10 +17,13 @@
 
 //! Implementation of `fungible` traits for Balances pallet.
 use super::*;
-use frame_support::traits::tokens::{
-	Fortitude,
-	Preservation::{self, Preserve, Protect},
-	Provenance::{self, Minted},
+use frame_support::traits::{
+	blacklist::BlackListAccounts,
+	tokens::{
+		Fortitude,
+		Preservation::{self, Preserve, Protect},
+		Provenance::{self, Minted},
+	},
 };
 
 impl<T: Config<I>, I: 'static> fungible::Inspect<T::AccountId> for Pallet<T, I> {
@@ -77,6 +80,10 @@ impl<T: Config<I>, I: 'static> fungible::Inspect<T::AccountId> for Pallet<T, I>
 			return DepositConsequence::Success
 		}
 
+		if Self::is_blacklisted(who) {
+			return DepositConsequence::Blocked
+		}
+
 		if provenance == Minted && TotalIssuance::<T, I>::get().checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
@@ -106,6 +113,10 @@ impl<T: Config<I>, I: 'static> fungible::Inspect<T::AccountId> for Pallet<T, I>
 			return WithdrawConsequence::Success
 		}
 
+		if Self::is_blacklisted(who) {
+			return WithdrawConsequence::Frozen
+		}
+
 		if TotalIssuance::<T, I>::get().checked_sub(&amount).is_none() {
 			return WithdrawConsequence::Underflow
 		}
@@ -178,7 +178,7 @@ use frame_support::{
 		},
 		Currency, Defensive, Get, OnUnbalanced, ReservableCurrency, StoredMap,
 	},
-	BoundedSlice, WeakBoundedVec,
+	BoundedSlice, BoundedVec, WeakBoundedVec,
 };
 use frame_system as system;
 pub use impl_currency::{NegativeImbalance, PositiveImbalance};
@@ -192,7 +192,8 @@ use sp_runtime::{
 };
 use sp_std::{cmp, fmt::Debug, mem, prelude::*, result};
 pub use types::{
-	AccountData, BalanceLock, DustCleaner, ExtraFlags, IdAmount, Reasons, ReserveData,
+	AccountData, BalanceLock, BlacklistInfo, DustCleaner, ExtraFlags, IdAmount, MemberAllowance,
+	MintPolicy, PendingMint, Reasons, ReserveData,
 };
 pub use weights::WeightInfo;
 use frame_support::traits::ExistenceRequirement::KeepAlive;
@@ -208,7 +209,7 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{blacklist::BlackListAccounts, fungible::Credit, tokens::Precision},
+		traits::{blacklist::BlackListAccounts, fungible::Credit, tokens::Precision, Imbalance},
 	};
 	use frame_system::pallet_prelude::*;
 
@@ -238,6 +239,9 @@ pub mod pallet {
 			type MaxReserves = ();
 			type MaxFreezes = ();
 			type MaxHolds = ();
+			type MaxMemoLen = ConstU32<256>;
+			type MaxMembers = ConstU32<100>;
+			type MaxPendingMints = ConstU32<10>;
             // type Currency = Pallet<TestDefaultConfig>;
 			type WeightInfo = ();
 		}
@@ -318,15 +322,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxFreezes: Get<u32>;
 
+		/// The maximum length of the memo attached to a transfer.
+		#[pallet::constant]
+		type MaxMemoLen: Get<u32>;
+
+		/// The maximum number of members that may mint and burn.
+		#[pallet::constant]
+		type MaxMembers: Get<u32>;
+
+		/// The maximum number of mints a member may have waiting for approvals at once.
+		#[pallet::constant]
+		type MaxPendingMints: Get<u32>;
+
+		/// The origin which may blacklist and whitelist accounts.
+		#[pallet::no_default]
+		type BlacklistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	/// The current storage version.
 	const STORAGE_VERSION: frame_support::traits::StorageVersion =
-		frame_support::traits::StorageVersion::new(1);
+		frame_support::traits::StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::event]
@@ -382,15 +400,47 @@ pub mod pallet {
 		/// Some balance was thawed.
 		Thawed { who: T::AccountId, amount: T::Balance },
 		///Transfer with memo succeeded
-		TransferWithMemo { from: T::AccountId, to: T::AccountId, amount: T::Balance, memo: Vec<u8> },
+		TransferWithMemo {
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			memo: BoundedVec<u8, T::MaxMemoLen>,
+		},
 		/// Amount Minted
-		AmountMinted { who: T::AccountId, amount: T::Balance, account: T::AccountId },
+		AmountMinted {
+			who: T::AccountId,
+			amount: T::Balance,
+			account: T::AccountId,
+			remaining_allowance: T::Balance,
+		},
 		/// Amount Burned
-		AmountBurned { who: T::AccountId, amount: T::Balance, account: T::AccountId },
+		AmountBurned {
+			who: T::AccountId,
+			amount: T::Balance,
+			account: T::AccountId,
+			remaining_allowance: T::Balance,
+		},
 		/// Member Set
 		MemberSet { account: T::AccountId },
-        /// Account is Blacklisted
-		AccountBlacklisted { account: T::AccountId },
+		/// Member Removed
+		MemberRemoved { account: T::AccountId },
+		/// The mint and burn allowance of a member was set.
+		AllowanceSet { member: T::AccountId, mint_limit: T::Balance, burn_limit: T::Balance },
+		/// The rules for minting were changed.
+		MintPolicySet { policy: MintPolicy<T::Balance, BlockNumberFor<T>> },
+		/// A mint above the approval threshold awaits the approvals of the members.
+		MintProposed { id: u32, proposer: T::AccountId, account: T::AccountId, amount: T::Balance },
+		/// A member approved a pending mint, which has `approvals` approvals now.
+		MintApproved { id: u32, approver: T::AccountId, approvals: u32 },
+		/// A pending mint was cancelled.
+		MintCancelled { id: u32 },
+		/// Account is Blacklisted
+		AccountBlacklisted {
+			account: T::AccountId,
+			reason: u8,
+			set_by: Option<T::AccountId>,
+			expiry: Option<BlockNumberFor<T>>,
+		},
 		/// Account is Whitelisted
 		AccountWhitelisted { account: T::AccountId },
 	}
@@ -421,7 +471,31 @@ pub mod pallet {
 		/// Not a Number 
 		NotAMember,
 		/// Already a member
-		AlreadyAMember
+		AlreadyAMember,
+		/// The account is already blacklisted
+		AlreadyBlacklisted,
+		/// The account is not blacklisted
+		NotBlacklisted,
+		/// The expiry of the blacklisting is not in the future
+		InvalidExpiry,
+		/// The amount exceeds what the member may still mint in this period
+		MintAllowanceExceeded,
+		/// The amount exceeds what the member may still burn in this period
+		BurnAllowanceExceeded,
+		/// The mint would take the total issuance beyond the cap of the mint policy
+		IssuanceCapExceeded,
+		/// There is no pending mint with the given id
+		UnknownMint,
+		/// The member already approved the pending mint
+		AlreadyApproved,
+		/// Only the proposer or root may cancel a pending mint
+		NotProposer,
+		/// The memo is longer than `MaxMemoLen`
+		MemoTooLong,
+		/// There are `MaxMembers` members already
+		TooManyMembers,
+		/// The member has `MaxPendingMints` mints waiting for approvals already
+		TooManyPendingMints,
 	}
 
 	/// The total units issued in the system.
@@ -433,12 +507,63 @@ pub mod pallet {
 	/// The List of the members for mint and burn functionality
 	#[pallet::storage]
 	#[pallet::getter(fn membership)]
-	pub type MemberShip<T: Config<I>, I: 'static = ()> = StorageValue<_,Vec<T::AccountId>, OptionQuery>;
+	pub type MemberShip<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxMembers>, OptionQuery>;
+
+	/// The mint and burn allowances of the members.
+	#[pallet::storage]
+	#[pallet::getter(fn allowance)]
+	pub type Allowances<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		MemberAllowance<T::Balance, BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
+	/// The rules every mint of a member is subject to.
+	#[pallet::storage]
+	#[pallet::getter(fn mint_policy)]
+	pub type CurrentMintPolicy<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, MintPolicy<T::Balance, BlockNumberFor<T>>, ValueQuery>;
 
-	/// The List of the blacklisted accounts
+	/// The id of the next mint that needs approvals.
 	#[pallet::storage]
-	#[pallet::getter(fn blacklist_accounts)]
-	pub type BlackList<T: Config<I>, I: 'static = ()> = StorageValue<_,Vec<T::AccountId>, OptionQuery>;
+	pub type NextMintId<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The mints above the approval threshold still waiting for approvals.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_mint)]
+	pub type PendingMints<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, PendingMint<T::AccountId, T::Balance>, OptionQuery>;
+
+	/// The pending mints each member proposed.
+	#[pallet::storage]
+	pub type PendingMintsOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u32, T::MaxPendingMints>,
+		ValueQuery,
+	>;
+
+	/// The members that approved each pending mint.
+	#[pallet::storage]
+	pub type MintApprovals<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// The blacklisted accounts, with why, by whom and until when they are blacklisted.
+	///
+	/// An entry whose expiry has passed no longer blocks the account.
+	#[pallet::storage]
+	#[pallet::getter(fn blacklist_info)]
+	pub type BlackList<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BlacklistInfo<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
 
 	/// The total units of outstanding deactivated balance in the system.
 	#[pallet::storage]
@@ -521,11 +646,21 @@ pub mod pallet {
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		pub balances: Vec<(T::AccountId, T::Balance)>,
+		/// The members allowed to mint and burn, with their mint and burn limit per period.
+		#[serde(default)]
+		pub members: Vec<(T::AccountId, T::Balance, T::Balance)>,
+		/// The accounts blacklisted from genesis on, with the reason code of each.
+		#[serde(default)]
+		pub blacklist: Vec<(T::AccountId, u8)>,
 	}
 
 	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
 		fn default() -> Self {
-			Self { balances: Default::default() }
+			Self {
+				balances: Default::default(),
+				members: Default::default(),
+				blacklist: Default::default(),
+			}
 		}
 	}
 
@@ -561,11 +696,35 @@ pub mod pallet {
 				assert!(T::AccountStore::insert(who, AccountData { free, ..Default::default() })
 					.is_ok());
 			}
-		}
-
-		
 
+			let mut members = BoundedVec::<T::AccountId, T::MaxMembers>::default();
+			for (who, mint_limit, burn_limit) in &self.members {
+				assert!(!members.contains(who), "duplicate members in genesis.");
+				assert!(members.try_push(who.clone()).is_ok(), "too many members in genesis.");
+				Allowances::<T, I>::insert(
+					who,
+					MemberAllowance {
+						mint_limit: *mint_limit,
+						burn_limit: *burn_limit,
+						..Default::default()
+					},
+				);
+			}
+			if !members.is_empty() {
+				MemberShip::<T, I>::put(members);
+			}
 
+			for (who, reason) in &self.blacklist {
+				assert!(
+					!BlackList::<T, I>::contains_key(who),
+					"duplicate blacklisted accounts in genesis."
+				);
+				BlackList::<T, I>::insert(
+					who,
+					BlacklistInfo { reason: *reason, set_by: None, expiry: None },
+				);
+			}
+		}
 	}
 
 	#[pallet::hooks]
@@ -825,6 +984,8 @@ pub mod pallet {
 
 		/// Transfers balance along with a message
 		/// 
+		/// The memo is at most `MaxMemoLen` bytes long.
+		///
 		/// The dispatch origin for this call is signed.
 		#[pallet::call_index(9)]
 		#[pallet::weight(T::WeightInfo::transfer_with_memo())]
@@ -832,31 +993,52 @@ pub mod pallet {
 		   origin: OriginFor<T>,
 		   dest: <T::Lookup as StaticLookup>::Source,
 		   #[pallet::compact] value: T::Balance,
-		   memo: Vec<u8>,
+		   memo: BoundedVec<u8, T::MaxMemoLen>,
 	   ) -> DispatchResultWithPostInfo {
 		   let transactor = ensure_signed(origin)?;
 		   let dest = T::Lookup::lookup(dest)?;
 
-		   <Self as Currency<_>>::transfer_with_memo(&transactor, &dest, value, memo, KeepAlive)?;
+		   <Self as Currency<_>>::transfer_with_memo(&transactor, &dest, value, memo.into_inner(), KeepAlive)?;
 		   Ok(().into())
 	   }
 
 	   /// Mint new tokens
 	   /// 
-	   /// The dispatch origin for this call is signed
+	   /// The amount is taken from the mint allowance of the member and may not take the total
+	   /// issuance beyond the cap of the mint policy. Mints above the approval threshold of the
+	   /// policy wait until they have the required approvals of the policy. The proposer counts as
+	   /// the first approval, the others `approve_mint` them. A member may have at most
+	   /// `MaxPendingMints` mints waiting at once.
+	   ///
+	   /// The dispatch origin for this call is signed by a member
 	   #[pallet::call_index(10)]
-	   #[pallet::weight(Weight::zero())]
+	   #[pallet::weight(T::WeightInfo::mint())]
 	   pub fn mint(
 		   origin: OriginFor<T>,
 		   amount:T::Balance,
 		   account: <T::Lookup as StaticLookup>::Source,
 	   ) -> DispatchResult {
 		   let who = ensure_signed(origin)?;
-		   let members = MemberShip::<T,I>::get().unwrap_or_else(Vec::new);
-		   ensure!(members.contains(&who),Error::<T,I>::NotAMember);
+		   Self::ensure_member(&who)?;
 		   let dest = T::Lookup::lookup(account)?;
-		   let _ = crate::Pallet::<T,I>::deposit_creating(&dest, amount);
-		   Self::deposit_event(Event::AmountMinted { who, amount: amount, account: dest });
+		   let policy = CurrentMintPolicy::<T, I>::get();
+		   let needs_approval = policy.required_approvals > 1 &&
+			   policy.approval_threshold.map_or(false, |threshold| amount > threshold);
+		   if !needs_approval {
+			   return Self::do_mint(who, dest, amount)
+		   }
+
+		   let id = NextMintId::<T, I>::get();
+		   PendingMintsOf::<T, I>::try_mutate(&who, |ids| {
+			   ids.try_push(id).map_err(|_| Error::<T, I>::TooManyPendingMints)
+		   })?;
+		   NextMintId::<T, I>::put(id.wrapping_add(1));
+		   PendingMints::<T, I>::insert(
+			   id,
+			   PendingMint { proposer: who.clone(), beneficiary: dest.clone(), amount },
+		   );
+		   MintApprovals::<T, I>::insert(id, &who, ());
+		   Self::deposit_event(Event::MintProposed { id, proposer: who, account: dest, amount });
 		   Ok(())
 	   }
 
@@ -864,18 +1046,25 @@ pub mod pallet {
 	   /// 
 	   /// The dispatch origin for this call is signed
 	   #[pallet::call_index(11)]
-	   #[pallet::weight(Weight::zero())]
+	   #[pallet::weight(T::WeightInfo::burn())]
 	   pub fn burn(
 		   origin: OriginFor<T>,
 		   amount: T::Balance,
 		   account: <T::Lookup as StaticLookup>::Source,
 	   ) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let members = MemberShip::<T,I>::get().unwrap_or_else(Vec::new);
-			ensure!(members.contains(&who),Error::<T,I>::NotAMember);
+			Self::ensure_member(&who)?;
 		  	let dest = T::Lookup::lookup(account)?;
-		   	let _ =  crate::Pallet::<T,I>::slash(&dest, amount);
-			Self::deposit_event(Event::AmountBurned { who, amount: amount, account: dest });
+			let period = CurrentMintPolicy::<T, I>::get().period;
+			let now = frame_system::Pallet::<T>::block_number();
+			let (amount, remaining_allowance) = Allowances::<T, I>::try_mutate(&who, |allowance| {
+				allowance.refresh(now, period);
+				ensure!(amount <= allowance.remaining_burn(), Error::<T, I>::BurnAllowanceExceeded);
+				let (imbalance, _) = crate::Pallet::<T, I>::slash(&dest, amount);
+				allowance.burned = allowance.burned.saturating_add(imbalance.peek());
+				Ok::<_, DispatchError>((imbalance.peek(), allowance.remaining_burn()))
+			})?;
+			Self::deposit_event(Event::AmountBurned { who, amount, account: dest, remaining_allowance });
 		 	Ok(())
 	   }	
 
@@ -883,73 +1072,235 @@ pub mod pallet {
 	   /// 
 	   /// The disptach origin for this call is root
 	   #[pallet::call_index(12)]
-	   #[pallet::weight(Weight::zero())]
+	   #[pallet::weight(T::WeightInfo::set_member())]
 	   pub fn set_member(
 		   origin: OriginFor<T>,
 		   account: <T::Lookup as StaticLookup>::Source,
 	   ) -> DispatchResult {
 			ensure_root(origin)?;
 		  	let who = T::Lookup::lookup(account)?;
-		  	let mut members = MemberShip::<T,I>::get().unwrap_or_else(Vec::new);
+		  	let mut members = MemberShip::<T,I>::get().unwrap_or_default();
 			ensure!(!members.contains(&who),Error::<T,I>::AlreadyAMember);
-			members.push(who.clone());
+			members.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyMembers)?;
 			MemberShip::<T,I>::put(members);
 			Self::deposit_event(Event::MemberSet{ account: who });
 		 	Ok(())
 	   }
 
 	   /// Blacklist the users from performing any transactions
-	   /// 
-	   /// The disptach origin for this call is root
+	   ///
+	   /// A blacklisted account can neither pay fees nor send or receive balances or assets.
+	   /// `reason` is a code recorded for audits, `expiry` the block from which on the account
+	   /// is no longer blacklisted.
+	   ///
+	   /// The dispatch origin for this call is `BlacklistOrigin`
 	   #[pallet::call_index(13)]
-	   #[pallet::weight(Weight::zero())]
+	   #[pallet::weight(T::WeightInfo::blacklist())]
 	   pub fn blacklist(
 		   origin: OriginFor<T>,
 		   account: <T::Lookup as StaticLookup>::Source,
+		   reason: u8,
+		   expiry: Option<BlockNumberFor<T>>,
 	   ) -> DispatchResult {
-			ensure_root(origin)?;
-		  	let who = T::Lookup::lookup(account)?;
-		  	let mut members = BlackList::<T,I>::get().unwrap_or_else(Vec::new);
-			ensure!(!members.contains(&who),Error::<T,I>::AlreadyAMember);
-			members.push(who.clone());
-			BlackList::<T,I>::put(members);
-			Self::deposit_event(Event::AccountBlacklisted{ account: who });
-		 	Ok(())
+			let set_by = ensure_signed(origin.clone()).ok();
+			T::BlacklistOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(account)?;
+			ensure!(!Self::is_blacklisted(&who), Error::<T, I>::AlreadyBlacklisted);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expiry.map_or(true, |expiry| expiry > now), Error::<T, I>::InvalidExpiry);
+			BlackList::<T, I>::insert(
+				&who,
+				BlacklistInfo { reason, set_by: set_by.clone(), expiry },
+			);
+			Self::deposit_event(Event::AccountBlacklisted { account: who, reason, set_by, expiry });
+			Ok(())
 	   }
 
        /// Whitelist the Blacklisted users
 	   /// 
-	   /// The disptach origin for this call is root
+	   /// Also clears entries whose expiry has passed.
+	   ///
+	   /// The dispatch origin for this call is `BlacklistOrigin`
 	   #[pallet::call_index(14)]
-	   #[pallet::weight(Weight::zero())]
+	   #[pallet::weight(T::WeightInfo::whitelist())]
 	   pub fn whitelist(
 		   origin: OriginFor<T>,
 		   account: <T::Lookup as StaticLookup>::Source,
 	   ) -> DispatchResult {
-			ensure_root(origin)?;
-		  	let who = T::Lookup::lookup(account)?;
-		  	let mut members = BlackList::<T,I>::get().unwrap_or_else(Vec::new);
-			if let Some(index) = members.iter().position(|a| a == &who.clone()) {
-				members.remove(index);
-			}
-			BlackList::<T,I>::put(members);
+			T::BlacklistOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(account)?;
+			ensure!(BlackList::<T, I>::contains_key(&who), Error::<T, I>::NotBlacklisted);
+			BlackList::<T, I>::remove(&who);
 			Self::deposit_event(Event::AccountWhitelisted{ account: who });
 		 	Ok(())
 	   }
 
+	   /// Removes a member, together with its mint and burn allowance
+	   ///
+	   /// The mints the member proposed that still wait for approvals are cancelled.
+	   ///
+	   /// The dispatch origin for this call is root
+	   #[pallet::call_index(15)]
+	   #[pallet::weight(T::WeightInfo::remove_member(T::MaxPendingMints::get()))]
+	   pub fn remove_member(
+		   origin: OriginFor<T>,
+		   account: <T::Lookup as StaticLookup>::Source,
+	   ) -> DispatchResult {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(account)?;
+			let mut members = MemberShip::<T, I>::get().unwrap_or_default();
+			let position = members.iter().position(|member| member == &who);
+			members.remove(position.ok_or(Error::<T, I>::NotAMember)?);
+			MemberShip::<T, I>::put(members);
+			Allowances::<T, I>::remove(&who);
+			for id in PendingMintsOf::<T, I>::take(&who) {
+				PendingMints::<T, I>::remove(id);
+				let _ = MintApprovals::<T, I>::clear_prefix(id, T::MaxMembers::get(), None);
+				Self::deposit_event(Event::MintCancelled { id });
+			}
+			Self::deposit_event(Event::MemberRemoved { account: who });
+			Ok(())
+	   }
+
+	   /// Sets how much a member may mint and burn per period of the mint policy
+	   ///
+	   /// What the member used up in the current period stays used up.
+	   ///
+	   /// The dispatch origin for this call is root
+	   #[pallet::call_index(16)]
+	   #[pallet::weight(T::WeightInfo::set_allowance())]
+	   pub fn set_allowance(
+		   origin: OriginFor<T>,
+		   member: <T::Lookup as StaticLookup>::Source,
+		   mint_limit: T::Balance,
+		   burn_limit: T::Balance,
+	   ) -> DispatchResult {
+			ensure_root(origin)?;
+			let member = T::Lookup::lookup(member)?;
+			Self::ensure_member(&member)?;
+			Allowances::<T, I>::mutate(&member, |allowance| {
+				allowance.mint_limit = mint_limit;
+				allowance.burn_limit = burn_limit;
+			});
+			Self::deposit_event(Event::AllowanceSet { member, mint_limit, burn_limit });
+			Ok(())
+	   }
+
+	   /// Sets the rules every mint of a member is subject to
+	   ///
+	   /// The dispatch origin for this call is root
+	   #[pallet::call_index(17)]
+	   #[pallet::weight(T::WeightInfo::set_mint_policy())]
+	   pub fn set_mint_policy(
+		   origin: OriginFor<T>,
+		   policy: MintPolicy<T::Balance, BlockNumberFor<T>>,
+	   ) -> DispatchResult {
+			ensure_root(origin)?;
+			CurrentMintPolicy::<T, I>::put(&policy);
+			Self::deposit_event(Event::MintPolicySet { policy });
+			Ok(())
+	   }
+
+	   /// Approves a mint above the approval threshold
+	   ///
+	   /// The mint happens with the approval that brings it to the required approvals of the
+	   /// mint policy, the approval of the proposer included. Approvals of accounts that are no
+	   /// longer members don't count.
+	   ///
+	   /// The dispatch origin for this call is signed by a member
+	   #[pallet::call_index(18)]
+	   #[pallet::weight(T::WeightInfo::approve_mint())]
+	   pub fn approve_mint(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let members = MemberShip::<T, I>::get().unwrap_or_default();
+			ensure!(members.contains(&who), Error::<T, I>::NotAMember);
+			let pending = PendingMints::<T, I>::get(id).ok_or(Error::<T, I>::UnknownMint)?;
+			ensure!(!MintApprovals::<T, I>::contains_key(id, &who), Error::<T, I>::AlreadyApproved);
+			MintApprovals::<T, I>::insert(id, &who, ());
+
+			let approvals = MintApprovals::<T, I>::iter_key_prefix(id)
+				.filter(|approver| members.contains(approver))
+				.count() as u32;
+			Self::deposit_event(Event::MintApproved { id, approver: who, approvals });
+			if approvals >= CurrentMintPolicy::<T, I>::get().required_approvals {
+				Self::remove_pending_mint(id, &pending.proposer);
+				Self::do_mint(pending.proposer, pending.beneficiary, pending.amount)?;
+			}
+			Ok(())
+	   }
+
+	   /// Cancels a mint still waiting for approvals
+	   ///
+	   /// The dispatch origin for this call is the proposer of the mint or root
+	   #[pallet::call_index(19)]
+	   #[pallet::weight(T::WeightInfo::cancel_mint())]
+	   pub fn cancel_mint(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let who = ensure_signed_or_root(origin)?;
+			let pending = PendingMints::<T, I>::get(id).ok_or(Error::<T, I>::UnknownMint)?;
+			ensure!(who.map_or(true, |who| who == pending.proposer), Error::<T, I>::NotProposer);
+			Self::remove_pending_mint(id, &pending.proposer);
+			Self::deposit_event(Event::MintCancelled { id });
+			Ok(())
+	   }
+
 
 	}
 
 	impl<T: Config<I>, I: 'static> BlackListAccounts<T::AccountId> for Pallet<T, I> {
-		fn blacklisted_accounts() -> Vec<T::AccountId> {
-			BlackList::<T,I>::get().unwrap_or_else(Vec::new)
+		fn is_blacklisted(who: &T::AccountId) -> bool {
+			BlackList::<T, I>::get(who)
+				.map_or(false, |info| info.is_active(frame_system::Pallet::<T>::block_number()))
 		}
-	 }
+	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		fn ed() -> T::Balance {
 			T::ExistentialDeposit::get()
 		}
+
+		fn ensure_member(who: &T::AccountId) -> DispatchResult {
+			let members = MemberShip::<T, I>::get().unwrap_or_default();
+			ensure!(members.contains(who), Error::<T, I>::NotAMember);
+			Ok(())
+		}
+
+		/// Mint `amount` into `beneficiary` out of the allowance of the member `who`.
+		fn do_mint(who: T::AccountId, beneficiary: T::AccountId, amount: T::Balance) -> DispatchResult {
+			let policy = CurrentMintPolicy::<T, I>::get();
+			if let Some(cap) = policy.issuance_cap {
+				ensure!(
+					TotalIssuance::<T, I>::get().saturating_add(amount) <= cap,
+					Error::<T, I>::IssuanceCapExceeded
+				);
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let remaining_allowance = Allowances::<T, I>::try_mutate(&who, |allowance| {
+				allowance.refresh(now, policy.period);
+				ensure!(amount <= allowance.remaining_mint(), Error::<T, I>::MintAllowanceExceeded);
+				allowance.minted = allowance.minted.saturating_add(amount);
+				Ok::<_, DispatchError>(allowance.remaining_mint())
+			})?;
+			<Self as fungible::Mutate<_>>::mint_into(&beneficiary, amount)?;
+			Self::deposit_event(Event::AmountMinted {
+				who,
+				amount,
+				account: beneficiary,
+				remaining_allowance,
+			});
+			Ok(())
+		}
+
+		fn remove_pending_mint(id: u32, proposer: &T::AccountId) {
+			PendingMints::<T, I>::remove(id);
+			let mut ids = PendingMintsOf::<T, I>::get(proposer);
+			ids.retain(|pending| *pending != id);
+			if ids.is_empty() {
+				PendingMintsOf::<T, I>::remove(proposer);
+			} else {
+				PendingMintsOf::<T, I>::insert(proposer, ids);
+			}
+			let _ = MintApprovals::<T, I>::clear_prefix(id, T::MaxMembers::get(), None);
+		}
 		/// Ensure the account `who` is using the new logic.
 		///
 		/// Returns `true` if the account did get upgraded, `false` if it didn't need upgrading.
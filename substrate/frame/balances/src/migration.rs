@@ -85,3 +85,135 @@ impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for ResetInactive<T, I> {
 		}
 	}
 }
+
+pub mod v2 {
+	use super::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// The blacklist as it was stored before version 2: a single list of accounts.
+	#[frame_support::storage_alias]
+	type BlackList<T: Config<I>, I: 'static> = StorageValue<
+		Pallet<T, I>,
+		Vec<<T as frame_system::Config>::AccountId>,
+		OptionQuery,
+	>;
+
+	/// Move the blacklisted accounts from the old list into the `BlackList` map. They keep no
+	/// reason code, setter or expiry.
+	pub struct MigrateBlacklistToMap<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateBlacklistToMap<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+
+			if onchain_version == 1 {
+				let accounts = BlackList::<T, I>::take().unwrap_or_default();
+				let migrated = accounts.len() as u64;
+				for account in accounts {
+					crate::BlackList::<T, I>::insert(
+						account,
+						BlacklistInfo::<_, BlockNumberFor<T>> {
+							reason: 0,
+							set_by: None,
+							expiry: None,
+						},
+					);
+				}
+				StorageVersion::new(2).put::<Pallet<T, I>>();
+
+				log::info!(target: LOG_TARGET, "Migrated {} blacklisted accounts", migrated);
+				T::DbWeight::get().reads_writes(2, migrated + 2)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let accounts = BlackList::<T, I>::get().unwrap_or_default();
+			Ok(accounts.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let accounts = Vec::<T::AccountId>::decode(&mut &state[..])
+				.map_err(|_| "the pre-upgrade state does not decode")?;
+			for account in accounts {
+				ensure!(
+					crate::BlackList::<T, I>::contains_key(&account),
+					"a blacklisted account was not migrated"
+				);
+			}
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 2,
+				"the storage version was not bumped"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// The members as they were stored before version 3: an unbounded list of accounts.
+	#[frame_support::storage_alias]
+	type MemberShip<T: Config<I>, I: 'static> = StorageValue<
+		Pallet<T, I>,
+		Vec<<T as frame_system::Config>::AccountId>,
+		OptionQuery,
+	>;
+
+	/// Bound the members by `MaxMembers`. Members beyond that are removed together with their
+	/// allowances, the earliest ones are kept.
+	pub struct BoundMembers<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for BoundMembers<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+
+			if onchain_version == 2 {
+				let mut members = MemberShip::<T, I>::take().unwrap_or_default();
+				let removed = members.split_off(members.len().min(T::MaxMembers::get() as usize));
+				for member in &removed {
+					Allowances::<T, I>::remove(member);
+				}
+				if !members.is_empty() {
+					crate::MemberShip::<T, I>::put(BoundedVec::truncate_from(members));
+				}
+				StorageVersion::new(3).put::<Pallet<T, I>>();
+
+				log::info!(target: LOG_TARGET, "Bound the members, removed {}", removed.len());
+				T::DbWeight::get().reads_writes(2, removed.len() as u64 + 2)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let members = MemberShip::<T, I>::get().unwrap_or_default();
+			Ok(members.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let members = Vec::<T::AccountId>::decode(&mut &state[..])
+				.map_err(|_| "the pre-upgrade state does not decode")?;
+			let bounded = crate::MemberShip::<T, I>::get().unwrap_or_default();
+			let kept = members.len().min(T::MaxMembers::get() as usize);
+			ensure!(bounded[..] == members[..kept], "the earliest members were not kept");
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 3,
+				"the storage version was not bumped"
+			);
+			Ok(())
+		}
+	}
+}
@@ -18,7 +18,7 @@
 //! Tests regarding the functionality of the dispatchables/extrinsics.
 
 use super::*;
-use frame_support::traits::tokens::Preservation::Expendable;
+use frame_support::{traits::tokens::Preservation::Expendable, BoundedVec};
 use fungible::{hold::Mutate as HoldMutate, Inspect, Mutate};
 
 #[test]
@@ -222,3 +222,86 @@ fn upgrade_accounts_should_work() {
 			assert_eq!(System::consumers(&7), 0);
 		});
 }
+
+#[test]
+fn blacklisted_accounts_can_neither_send_nor_receive() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		assert_ok!(Balances::blacklist(RuntimeOrigin::root(), 2, 1, None));
+		assert_eq!(
+			Balances::blacklist_info(2),
+			Some(crate::BlacklistInfo { reason: 1, set_by: None, expiry: None })
+		);
+		assert_noop!(Balances::transfer_allow_death(Some(2).into(), 1, 10), TokenError::Frozen);
+		assert_noop!(Balances::transfer_allow_death(Some(1).into(), 2, 10), TokenError::Blocked);
+
+		assert_ok!(Balances::whitelist(RuntimeOrigin::root(), 2));
+		assert_ok!(Balances::transfer_allow_death(Some(2).into(), 1, 10));
+		assert_ok!(Balances::transfer_allow_death(Some(1).into(), 2, 10));
+	});
+}
+
+#[test]
+fn blacklisting_ends_at_its_expiry() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			Balances::blacklist(RuntimeOrigin::root(), 2, 0, Some(5)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(Balances::blacklist(RuntimeOrigin::root(), 2, 0, Some(10)));
+		assert_noop!(
+			Balances::blacklist(RuntimeOrigin::root(), 2, 0, None),
+			Error::<Test>::AlreadyBlacklisted
+		);
+		assert_noop!(Balances::transfer_allow_death(Some(2).into(), 1, 10), TokenError::Frozen);
+
+		System::set_block_number(10);
+		assert_ok!(Balances::transfer_allow_death(Some(2).into(), 1, 10));
+		// The lapsed entry may be replaced or cleared.
+		assert_ok!(Balances::blacklist(RuntimeOrigin::root(), 2, 1, Some(20)));
+		let info = Balances::blacklist_info(2).unwrap();
+		assert_eq!((info.reason, info.expiry), (1, Some(20)));
+		assert_noop!(Balances::transfer_allow_death(Some(2).into(), 1, 10), TokenError::Frozen);
+		assert_ok!(Balances::whitelist(RuntimeOrigin::root(), 2));
+		assert_noop!(Balances::whitelist(RuntimeOrigin::root(), 2), Error::<Test>::NotBlacklisted);
+	});
+}
+
+#[test]
+fn blacklist_requires_blacklist_origin() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		assert_noop!(Balances::blacklist(Some(1).into(), 2, 0, None), BadOrigin);
+		assert_noop!(Balances::whitelist(Some(1).into(), 2), BadOrigin);
+	});
+}
+
+#[test]
+fn transfer_with_memo_works() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		let memo: BoundedVec<u8, ConstU32<16>> = b"invoice 42".to_vec().try_into().unwrap();
+		assert_ok!(Balances::transfer_with_memo(Some(1).into(), 2, 5, memo.clone()));
+		assert_eq!(Balances::free_balance(2), 25);
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::TransferWithMemo {
+			from: 1,
+			to: 2,
+			amount: 5,
+			memo,
+		}));
+	});
+}
+
+#[test]
+fn transfer_with_memo_is_bounded_by_max_memo_len() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		assert_noop!(
+			<Balances as frame_support::traits::Currency<_>>::transfer_with_memo(
+				&1,
+				&2,
+				5,
+				vec![0; 17],
+				frame_support::traits::ExistenceRequirement::KeepAlive,
+			),
+			Error::<Test>::MemoTooLong
+		);
+	});
+}
@@ -0,0 +1,274 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests regarding the minting and burning of the members.
+
+use super::*;
+use crate::{
+	Allowances, CurrentMintPolicy, MintApprovals, MintPolicy, PendingMints, PendingMintsOf,
+};
+
+/// Make `who` a member that may mint and burn `allowance` per period.
+fn member(who: u64, allowance: u64) {
+	assert_ok!(Balances::set_member(RuntimeOrigin::root(), who));
+	assert_ok!(Balances::set_allowance(RuntimeOrigin::root(), who, allowance, allowance));
+}
+
+fn set_policy(period: u64, issuance_cap: Option<u64>, approval_threshold: Option<u64>, approvals: u32) {
+	assert_ok!(Balances::set_mint_policy(
+		RuntimeOrigin::root(),
+		MintPolicy { period, issuance_cap, approval_threshold, required_approvals: approvals },
+	));
+}
+
+#[test]
+fn mint_is_limited_by_the_allowance_of_the_period() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		member(1, 100);
+		set_policy(10, None, None, 0);
+
+		assert_ok!(Balances::mint(Some(1).into(), 60, 5));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::AmountMinted {
+			who: 1,
+			amount: 60,
+			account: 5,
+			remaining_allowance: 40,
+		}));
+		assert_noop!(Balances::mint(Some(1).into(), 41, 5), Error::<Test>::MintAllowanceExceeded);
+		assert_ok!(Balances::mint(Some(1).into(), 40, 5));
+		assert_eq!(Balances::free_balance(5), 100);
+
+		// The allowance refills once the period is over.
+		System::set_block_number(11);
+		assert_ok!(Balances::mint(Some(1).into(), 100, 5));
+		assert_eq!(Balances::total_issuance(), 200);
+	});
+}
+
+#[test]
+fn only_members_mint_and_burn() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		assert_noop!(Balances::mint(Some(1).into(), 1, 5), Error::<Test>::NotAMember);
+		assert_noop!(Balances::burn(Some(1).into(), 1, 2), Error::<Test>::NotAMember);
+
+		// Members without an allowance can't do either.
+		assert_ok!(Balances::set_member(RuntimeOrigin::root(), 1));
+		assert_noop!(Balances::mint(Some(1).into(), 1, 5), Error::<Test>::MintAllowanceExceeded);
+		assert_noop!(Balances::burn(Some(1).into(), 1, 2), Error::<Test>::BurnAllowanceExceeded);
+	});
+}
+
+#[test]
+fn mint_may_not_exceed_the_issuance_cap() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		member(1, 100);
+		set_policy(0, Some(150), None, 0);
+
+		assert_noop!(Balances::mint(Some(1).into(), 41, 5), Error::<Test>::IssuanceCapExceeded);
+		assert_ok!(Balances::mint(Some(1).into(), 40, 5));
+		assert_eq!(Balances::total_issuance(), 150);
+	});
+}
+
+#[test]
+fn burn_is_limited_by_the_allowance() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		member(1, 25);
+
+		assert_ok!(Balances::burn(Some(1).into(), 15, 3));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::AmountBurned {
+			who: 1,
+			amount: 15,
+			account: 3,
+			remaining_allowance: 10,
+		}));
+		assert_noop!(Balances::burn(Some(1).into(), 11, 3), Error::<Test>::BurnAllowanceExceeded);
+		assert_eq!(Balances::free_balance(3), 15);
+		assert_eq!(Balances::total_issuance(), 95);
+	});
+}
+
+#[test]
+fn large_mints_need_the_required_approvals_the_proposer_included() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		member(1, 1_000);
+		member(2, 0);
+		member(3, 0);
+		set_policy(0, None, Some(100), 3);
+
+		// Small mints go through right away.
+		assert_ok!(Balances::mint(Some(1).into(), 100, 5));
+		assert_eq!(Balances::free_balance(5), 100);
+
+		assert_ok!(Balances::mint(Some(1).into(), 500, 5));
+		assert!(PendingMints::<Test>::contains_key(0));
+		assert_eq!(Balances::free_balance(5), 100);
+
+		// The proposer counts as the first approval.
+		assert_noop!(Balances::approve_mint(Some(1).into(), 0), Error::<Test>::AlreadyApproved);
+		assert_noop!(Balances::approve_mint(Some(4).into(), 0), Error::<Test>::NotAMember);
+		assert_ok!(Balances::approve_mint(Some(2).into(), 0));
+		assert_eq!(Balances::free_balance(5), 100);
+
+		// The third approval executes the mint out of the allowance of the proposer.
+		assert_ok!(Balances::approve_mint(Some(3).into(), 0));
+		assert_eq!(Balances::free_balance(5), 600);
+		assert_eq!(Allowances::<Test>::get(1).minted, 600);
+		assert!(PendingMints::<Test>::get(0).is_none());
+		assert_noop!(Balances::approve_mint(Some(2).into(), 0), Error::<Test>::UnknownMint);
+	});
+}
+
+#[test]
+fn pending_mints_can_be_cancelled_by_the_proposer_or_root() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		member(1, 1_000);
+		member(2, 0);
+		set_policy(0, None, Some(0), 2);
+
+		assert_ok!(Balances::mint(Some(1).into(), 10, 5));
+		assert_ok!(Balances::mint(Some(1).into(), 20, 5));
+		assert_noop!(Balances::cancel_mint(Some(2).into(), 0), Error::<Test>::NotProposer);
+		assert_ok!(Balances::cancel_mint(Some(1).into(), 0));
+		assert_ok!(Balances::cancel_mint(RuntimeOrigin::root(), 1));
+		assert_noop!(Balances::approve_mint(Some(2).into(), 0), Error::<Test>::UnknownMint);
+		assert_eq!(Balances::free_balance(5), 0);
+	});
+}
+
+#[test]
+fn pending_mints_are_bounded_per_member() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		member(1, 1_000);
+		member(2, 1_000);
+		set_policy(0, None, Some(0), 2);
+
+		assert_ok!(Balances::mint(Some(1).into(), 10, 5));
+		assert_ok!(Balances::mint(Some(1).into(), 20, 5));
+		assert_eq!(PendingMintsOf::<Test>::get(1).into_inner(), vec![0, 1]);
+		assert_noop!(Balances::mint(Some(1).into(), 30, 5), Error::<Test>::TooManyPendingMints);
+		// The limit is per member.
+		assert_ok!(Balances::mint(Some(2).into(), 30, 5));
+
+		// Cancelled and executed mints make room again.
+		assert_ok!(Balances::cancel_mint(Some(1).into(), 0));
+		assert_ok!(Balances::mint(Some(1).into(), 40, 5));
+		assert_noop!(Balances::mint(Some(1).into(), 50, 5), Error::<Test>::TooManyPendingMints);
+		assert_ok!(Balances::approve_mint(Some(2).into(), 1));
+		assert_eq!(Balances::free_balance(5), 20);
+		assert_eq!(PendingMintsOf::<Test>::get(1).into_inner(), vec![3]);
+		assert_ok!(Balances::mint(Some(1).into(), 50, 5));
+
+		assert_ok!(Balances::cancel_mint(RuntimeOrigin::root(), 2));
+		assert!(!PendingMintsOf::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn removed_members_lose_their_allowance_and_approvals() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		member(1, 1_000);
+		member(2, 0);
+		member(3, 0);
+		set_policy(0, None, Some(0), 3);
+
+		assert_ok!(Balances::mint(Some(1).into(), 10, 5));
+		assert_ok!(Balances::approve_mint(Some(2).into(), 0));
+		assert_ok!(Balances::remove_member(RuntimeOrigin::root(), 2));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::MemberRemoved {
+			account: 2,
+		}));
+		assert_noop!(
+			Balances::remove_member(RuntimeOrigin::root(), 2),
+			Error::<Test>::NotAMember
+		);
+		assert!(!Allowances::<Test>::contains_key(2));
+
+		// The approval of the removed member doesn't count anymore.
+		assert_ok!(Balances::approve_mint(Some(3).into(), 0));
+		assert_eq!(Balances::free_balance(5), 0);
+		assert_eq!(CurrentMintPolicy::<Test>::get().required_approvals, 3);
+	});
+}
+
+#[test]
+fn removed_members_pending_mints_are_cancelled() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		member(1, 1_000);
+		member(2, 1_000);
+		member(3, 0);
+		set_policy(0, None, Some(0), 2);
+
+		assert_ok!(Balances::mint(Some(1).into(), 10, 5));
+		assert_ok!(Balances::mint(Some(2).into(), 20, 5));
+		assert_ok!(Balances::mint(Some(1).into(), 30, 5));
+		assert_ok!(Balances::remove_member(RuntimeOrigin::root(), 1));
+		for id in [0, 2] {
+			System::assert_has_event(RuntimeEvent::Balances(crate::Event::MintCancelled { id }));
+			assert!(PendingMints::<Test>::get(id).is_none());
+			assert_eq!(MintApprovals::<Test>::iter_key_prefix(id).count(), 0);
+		}
+		assert!(!PendingMintsOf::<Test>::contains_key(1));
+		assert_noop!(Balances::approve_mint(Some(3).into(), 0), Error::<Test>::UnknownMint);
+
+		// Adding the member again doesn't bring its mints back, the mint of 2 is untouched.
+		assert_ok!(Balances::set_member(RuntimeOrigin::root(), 1));
+		assert_noop!(Balances::approve_mint(Some(1).into(), 2), Error::<Test>::UnknownMint);
+		assert_ok!(Balances::approve_mint(Some(3).into(), 1));
+		assert_eq!(Balances::free_balance(5), 20);
+	});
+}
+
+#[test]
+fn members_are_bounded_by_max_members() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		for who in 1..=3 {
+			assert_ok!(Balances::set_member(RuntimeOrigin::root(), who));
+		}
+		assert_noop!(Balances::set_member(RuntimeOrigin::root(), 4), Error::<Test>::TooManyMembers);
+
+		// Removing a member makes room again.
+		assert_ok!(Balances::remove_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Balances::set_member(RuntimeOrigin::root(), 4));
+	});
+}
+
+#[test]
+fn members_and_blacklist_can_be_set_in_genesis() {
+	ExtBuilder::default().set_associated_consts();
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> {
+		balances: vec![(2, 100), (3, 100)],
+		members: vec![(1, 50, 20)],
+		blacklist: vec![(3, 7)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Balances::membership().unwrap().into_inner(), vec![1]);
+		assert_eq!(Allowances::<Test>::get(1).burn_limit, 20);
+		assert_noop!(Balances::mint(Some(1).into(), 51, 2), Error::<Test>::MintAllowanceExceeded);
+		assert_ok!(Balances::mint(Some(1).into(), 50, 2));
+
+		assert_eq!(
+			Balances::blacklist_info(3),
+			Some(crate::BlacklistInfo { reason: 7, set_by: None, expiry: None })
+		);
+		assert_noop!(Balances::transfer_allow_death(Some(3).into(), 2, 10), TokenError::Frozen);
+	});
+}
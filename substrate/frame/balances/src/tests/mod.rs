@@ -47,6 +47,7 @@ mod currency_tests;
 mod dispatchable_tests;
 mod fungible_conformance_tests;
 mod fungible_tests;
+mod issuance_tests;
 mod reentrancy_tests;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -119,6 +120,9 @@ impl pallet_transaction_payment::Config for Test {
 	type WeightToFee = IdentityFee<u64>;
 	type LengthToFee = IdentityFee<u64>;
 	type FeeMultiplierUpdate = ();
+	type BlackListAccounts = ();
+	type FeeExemption = ();
+	type FreeTransactionsPerBlock = frame_support::traits::ConstU32<0>;
 }
 
 impl Config for Test {
@@ -135,6 +139,10 @@ impl Config for Test {
 	type FreezeIdentifier = TestId;
 	type MaxFreezes = ConstU32<2>;
 	type MaxHolds = ConstU32<2>;
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = ConstU32<16>;
+	type MaxMembers = ConstU32<3>;
+	type MaxPendingMints = ConstU32<2>;
 }
 
 #[derive(Clone)]
@@ -183,6 +191,7 @@ impl ExtBuilder {
 			} else {
 				vec![]
 			},
+			..Default::default()
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
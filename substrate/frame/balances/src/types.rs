@@ -22,7 +22,95 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use core::ops::BitOr;
 use frame_support::traits::{Imbalance, LockIdentifier, OnUnbalanced, WithdrawReasons};
 use scale_info::TypeInfo;
-use sp_runtime::{RuntimeDebug, Saturating};
+use sp_runtime::{traits::Zero, RuntimeDebug, Saturating};
+
+/// Why, by whom and until when an account is blacklisted.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct BlacklistInfo<AccountId, BlockNumber> {
+	/// The reason code given when the account was blacklisted.
+	pub reason: u8,
+	/// The account which blacklisted it, `None` if it was a non-signed origin such as root.
+	pub set_by: Option<AccountId>,
+	/// The block from which on the account is no longer blacklisted, if any.
+	pub expiry: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber: PartialOrd> BlacklistInfo<AccountId, BlockNumber> {
+	/// Whether the account is still blacklisted at block `now`.
+	pub fn is_active(&self, now: BlockNumber) -> bool {
+		self.expiry.as_ref().map_or(true, |expiry| &now < expiry)
+	}
+}
+
+/// What a `MemberShip` member may mint and burn per allowance period, and how much of it is
+/// used up.
+#[derive(
+	Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct MemberAllowance<Balance, BlockNumber> {
+	/// The amount the member may mint per period.
+	pub mint_limit: Balance,
+	/// The amount the member may burn per period.
+	pub burn_limit: Balance,
+	/// The amount minted in the current period.
+	pub minted: Balance,
+	/// The amount burned in the current period.
+	pub burned: Balance,
+	/// The block the current period started at.
+	pub period_start: BlockNumber,
+}
+
+impl<Balance: Saturating + Copy + Default, BlockNumber: Saturating + Copy + PartialOrd + Zero>
+	MemberAllowance<Balance, BlockNumber>
+{
+	/// Start a new period if the current one, `period` blocks long, is over at block `now`. A
+	/// zero `period` never ends, so the allowance never refills.
+	pub fn refresh(&mut self, now: BlockNumber, period: BlockNumber) {
+		if !period.is_zero() && now >= self.period_start.saturating_add(period) {
+			self.minted = Default::default();
+			self.burned = Default::default();
+			self.period_start = now;
+		}
+	}
+
+	/// The amount that may still be minted in the current period.
+	pub fn remaining_mint(&self) -> Balance {
+		self.mint_limit.saturating_sub(self.minted)
+	}
+
+	/// The amount that may still be burned in the current period.
+	pub fn remaining_burn(&self) -> Balance {
+		self.burn_limit.saturating_sub(self.burned)
+	}
+}
+
+/// The rules every `mint` of a `MemberShip` member is subject to.
+#[derive(
+	Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct MintPolicy<Balance, BlockNumber> {
+	/// The number of blocks after which the allowances of the members refill. Zero means they
+	/// never refill.
+	pub period: BlockNumber,
+	/// The total issuance that mints may not take the chain beyond, if any.
+	pub issuance_cap: Option<Balance>,
+	/// The amount above which a mint has to be approved by `required_approvals` members, if any.
+	pub approval_threshold: Option<Balance>,
+	/// The number of members, the proposer included, that have to approve a mint above
+	/// `approval_threshold`.
+	pub required_approvals: u32,
+}
+
+/// A mint above the approval threshold, waiting for the approvals of the members.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PendingMint<AccountId, Balance> {
+	/// The member that proposed the mint. The mint is taken from its allowance.
+	pub proposer: AccountId,
+	/// The account the minted amount goes to.
+	pub beneficiary: AccountId,
+	/// The amount to mint.
+	pub amount: Balance,
+}
 
 /// Simplified reasons for withdrawing balance.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -0,0 +1,132 @@
+//! Currency adapter pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as CurrencyAdapter;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use frame_support::{
+    sp_std::vec,
+    traits::{
+        fungible::{InspectFreeze, Mutate as _, MutateFreeze},
+        LockableCurrency,
+    },
+};
+use sp_runtime::traits::TrailingZeroInput;
+
+const SEED: u32 = 0;
+
+fn funded_account<T: Config>() -> T::AccountId {
+    let who: T::AccountId = account("locked", 0, SEED);
+    T::Balances::set_balance(&who, T::Balances::minimum_balance().saturating_mul(1_000));
+    who
+}
+
+fn freeze_id<T: Config>() -> T::FreezeIdentifier {
+    T::FreezeIdentifier::decode(&mut TrailingZeroInput::zeroes()).unwrap()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn force_set_lock() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>();
+        let amount = T::Balances::minimum_balance().saturating_mul(100);
+        let origin =
+            T::LockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, T::GetNativeCurrencyId::get(), who.clone(), amount);
+
+        assert_eq!(
+            <CurrencyAdapter<T> as Inspects<T::AccountId>>::reducible_balance(
+                T::GetNativeCurrencyId::get(),
+                &who,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            ),
+            T::Balances::minimum_balance().saturating_mul(900),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_remove_lock() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>();
+        T::Balances::set_lock(
+            CURRENCY_ADAPTER_ID,
+            &who,
+            T::Balances::minimum_balance().saturating_mul(100),
+            WithdrawReasons::all(),
+        );
+        let origin =
+            T::LockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, T::GetNativeCurrencyId::get(), who);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_with_memo() {
+        let who = funded_account::<T>();
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let amount = T::Balances::minimum_balance().saturating_mul(100);
+        let memo: BoundedVec<u8, T::MaxMemoLen> =
+            vec![0u8; T::MaxMemoLen::get() as usize].try_into().unwrap();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(who),
+            T::GetNativeCurrencyId::get(),
+            T::Lookup::unlookup(dest.clone()),
+            amount,
+            memo,
+        );
+
+        assert_eq!(T::Balances::balance(&dest), amount);
+    }
+
+    #[benchmark]
+    fn force_set_freeze() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>();
+        let amount = T::Balances::minimum_balance().saturating_mul(100);
+        let origin =
+            T::LockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            T::GetNativeCurrencyId::get(),
+            freeze_id::<T>(),
+            who.clone(),
+            amount,
+        );
+
+        assert_eq!(T::Balances::balance_frozen(&freeze_id::<T>(), &who), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_thaw() -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>();
+        T::Balances::set_freeze(
+            &freeze_id::<T>(),
+            &who,
+            T::Balances::minimum_balance().saturating_mul(100),
+        )?;
+        let origin =
+            T::LockOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, T::GetNativeCurrencyId::get(), freeze_id::<T>(), who.clone());
+
+        assert!(T::Balances::balance_frozen(&freeze_id::<T>(), &who).is_zero());
+        Ok(())
+    }
+}
@@ -3,19 +3,50 @@
 //! ## Overview
 //!
 //! This pallet works like a bridge between pallet-balances & pallet-assets
+//!
+//! `transfer_with_memo` moves the native currency or any asset through the same bridge and
+//! records the memo in a single `TransferredWithMemo` event, whatever the currency.
+//!
+//! Besides `Inspect` and `Mutate`, the pallet implements `Unbalanced`, `Balanced`, the hold and
+//! freeze traits and `Create`/`Destroy` of `fungibles`, so that it can stand in for
+//! pallet-assets wherever a single `fungibles` implementation over every currency is needed:
+//!
+//! - Holds and freezes of the native currency are the ones of pallet-balances, with the same
+//!   reasons and identifiers.
+//! - Assets on hold are taken out of the asset account and only recorded per reason in
+//!   [`Holds`]. They stay part of the supply of the asset, so no other account has to exist
+//!   to keep them.
+//! - Asset freezes are recorded per identifier in [`Freezes`] and enforced by pallet-assets,
+//!   for which the pallet is the `Freezer`.
+//! - Only assets other than the native currency can be created or destroyed. Destroying an
+//!   asset also destroys its holds and freezes, in `destroy_accounts` and within the same
+//!   `max_items`, and `finish_destroy` fails while any are left.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 use frame_system::pallet_prelude::BlockNumberFor;
 use frame_support::{
-    defensive,
     dispatch::DispatchResult,
     pallet_prelude::*,
     traits::{
         tokens::{
-            fungible::{Inspect, Mutate},
-            fungibles::{Dust, Inspect as Inspects, Mutate as Mutates, Unbalanced as Unbalanceds},
+            fungible::{self, Inspect, Mutate},
+            fungibles::{
+                self, Dust, Inspect as Inspects, Mutate as Mutates, Unbalanced as Unbalanceds,
+            },
             DepositConsequence, Fortitude, Precision, Preservation, Provenance,
             WithdrawConsequence,
         },
@@ -23,50 +54,158 @@ use frame_support::{
     },
 };
 use liquid_staking_primitives::{Balance, CurrencyId};
-use sp_runtime::DispatchError;
+use scale_info::prelude::vec::Vec;
+use sp_runtime::{
+    traits::{Saturating, StaticLookup, Zero},
+    DispatchError, RuntimeDebug,
+};
 
 type AssetIdOf<T> =
     <<T as Config>::Assets as Inspects<<T as frame_system::Config>::AccountId>>::AssetId;
 type BalanceOf<T> =
     <<T as Config>::Assets as Inspects<<T as frame_system::Config>::AccountId>>::Balance;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type HoldsOf<T> = BoundedVec<
+    IdAmount<<T as Config>::RuntimeHoldReason, BalanceOf<T>>,
+    <T as Config>::MaxHolds,
+>;
+type FreezesOf<T> = BoundedVec<
+    IdAmount<<T as Config>::FreezeIdentifier, BalanceOf<T>>,
+    <T as Config>::MaxFreezes,
+>;
 
 const CURRENCY_ADAPTER_ID: LockIdentifier = *b"cadapter";
 
+/// An amount of an asset held for a reason or frozen under an identifier.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+    /// The reason of the hold or the identifier of the freeze.
+    pub id: Id,
+    /// The amount held or frozen.
+    pub amount: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::traits::LockableCurrency;
-    use frame_system::pallet_prelude::OriginFor;
+    use frame_system::pallet_prelude::{ensure_signed, OriginFor};
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
         type Assets: Inspects<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
-            + Mutates<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+            + Mutates<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + fungibles::Create<Self::AccountId>
+            + fungibles::Destroy<Self::AccountId>;
 
         type Balances: Inspect<Self::AccountId, Balance = Balance>
             + Mutate<Self::AccountId, Balance = Balance>
+            + fungible::InspectHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + fungible::MutateHold<Self::AccountId>
+            + fungible::InspectFreeze<Self::AccountId, Id = Self::FreezeIdentifier>
+            + fungible::MutateFreeze<Self::AccountId>
             + LockableCurrency<Self::AccountId, Balance = Balance, Moment = BlockNumberFor<Self>>;
 
+        /// The reasons funds can be held for, the ones of `Balances`.
+        type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+        /// The identifiers funds can be frozen under, the ones of `Balances`.
+        type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+        /// The maximum number of holds an account can have on a single asset.
+        #[pallet::constant]
+        type MaxHolds: Get<u32>;
+
+        /// The maximum number of freezes an account can have on a single asset.
+        #[pallet::constant]
+        type MaxFreezes: Get<u32>;
+
         #[pallet::constant]
         type GetNativeCurrencyId: Get<AssetIdOf<Self>>;
 
         // Origin which can lock asset balance
         type LockOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The maximum length of the memo attached to a transfer.
+        #[pallet::constant]
+        type MaxMemoLen: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    /// The holds on assets other than the native currency, per asset and account.
+    #[pallet::storage]
+    pub type Holds<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        HoldsOf<T>,
+        ValueQuery,
+    >;
+
+    /// The freezes on assets other than the native currency, per asset and account.
+    #[pallet::storage]
+    pub type Freezes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        FreezesOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Some currency was transferred along with a memo.
+        TransferredWithMemo {
+            asset: AssetIdOf<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            memo: BoundedVec<u8, T::MaxMemoLen>,
+        },
+        /// A freeze was set on some currency of an account.
+        FreezeSet {
+            asset: AssetIdOf<T>,
+            id: T::FreezeIdentifier,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A freeze was removed from some currency of an account.
+        Thawed { asset: AssetIdOf<T>, id: T::FreezeIdentifier, who: T::AccountId },
+        /// The assets an account had on hold were destroyed together with the asset.
+        HoldsDestroyed { asset: AssetIdOf<T>, who: T::AccountId, amount: BalanceOf<T> },
+    }
+
     #[pallet::error]
     pub enum Error<T> {
         /// Not a native token
         NotANativeToken,
+        /// The native token can't be created or destroyed
+        CannotAlterNativeToken,
+        /// The account has too many holds on the asset
+        TooManyHolds,
+        /// The account has too many freezes on the asset
+        TooManyFreezes,
+        /// The asset being destroyed still has holds or freezes
+        HoldsOrFreezesLeft,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::zero())]
+        #[pallet::weight(T::WeightInfo::force_set_lock())]
         pub fn force_set_lock(
             origin: OriginFor<T>,
             asset: AssetIdOf<T>,
@@ -83,7 +222,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::zero())]
+        #[pallet::weight(T::WeightInfo::force_remove_lock())]
         pub fn force_remove_lock(
             origin: OriginFor<T>,
             asset: AssetIdOf<T>,
@@ -97,6 +236,67 @@ pub mod pallet {
             T::Balances::remove_lock(CURRENCY_ADAPTER_ID, &who);
             Ok(())
         }
+
+        /// Transfer the native currency or an asset along with a memo.
+        ///
+        /// The dispatch origin for this call is signed.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::transfer_with_memo())]
+        pub fn transfer_with_memo(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            dest: AccountIdLookupOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            memo: BoundedVec<u8, T::MaxMemoLen>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            let to = T::Lookup::lookup(dest)?;
+            let amount = <Self as Mutates<_>>::transfer(
+                asset,
+                &from,
+                &to,
+                amount,
+                Preservation::Expendable,
+            )?;
+            Self::deposit_event(Event::TransferredWithMemo { asset, from, to, amount, memo });
+            Ok(())
+        }
+
+        /// Freeze `amount` of the native currency or an asset of `who` under `id`, replacing
+        /// any freeze already under `id`.
+        ///
+        /// The dispatch origin for this call must be `LockOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::force_set_freeze())]
+        pub fn force_set_freeze(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            id: T::FreezeIdentifier,
+            who: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::LockOrigin::ensure_origin(origin)?;
+            <Self as fungibles::MutateFreeze<_>>::set_freeze(asset, &id, &who, amount)?;
+            Self::deposit_event(Event::FreezeSet { asset, id, who, amount });
+            Ok(())
+        }
+
+        /// Remove the freeze under `id` from the native currency or an asset of `who`.
+        ///
+        /// The dispatch origin for this call must be `LockOrigin`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::force_thaw())]
+        pub fn force_thaw(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            id: T::FreezeIdentifier,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            T::LockOrigin::ensure_origin(origin)?;
+            <Self as fungibles::MutateFreeze<_>>::thaw(asset, &id, &who)?;
+            Self::deposit_event(Event::Thawed { asset, id, who });
+            Ok(())
+        }
     }
 }
 
@@ -124,7 +324,9 @@ impl<T: Config> Inspects<T::AccountId> for Pallet<T> {
         if asset == T::GetNativeCurrencyId::get() {
             T::Balances::total_balance(who)
         } else {
+            // Assets on hold are no longer in the asset account.
             T::Assets::total_balance(asset, who)
+                .saturating_add(<Self as fungibles::InspectHold<_>>::total_balance_on_hold(asset, who))
         }
     }
 
@@ -225,18 +427,339 @@ impl<T: Config> Mutates<T::AccountId> for Pallet<T> {
 }
 
 impl<T: Config> Unbalanceds<T::AccountId> for Pallet<T> {
-    fn handle_dust(_: Dust<T::AccountId, Self>) {
-        defensive!("`decrease_balance` and `increase_balance` have non-default impls; nothing else calls this; qed");
+    fn handle_dust(dust: Dust<T::AccountId, Self>) {
+        let Dust(asset, amount) = dust;
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::handle_dust(fungible::Dust(amount))
+        } else {
+            <T::Assets as Unbalanceds<_>>::handle_dust(Dust(asset, amount))
+        }
     }
+
     fn write_balance(
-        _: Self::AssetId,
-        _: &T::AccountId,
-        _: Self::Balance,
+        asset: Self::AssetId,
+        who: &T::AccountId,
+        amount: Self::Balance,
     ) -> Result<Option<Self::Balance>, DispatchError> {
-        defensive!("write_balance is not used");
-        Err(DispatchError::Unavailable)
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::write_balance(who, amount)
+        } else {
+            <T::Assets as Unbalanceds<_>>::write_balance(asset, who, amount)
+        }
+    }
+
+    fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::set_total_issuance(amount)
+        } else {
+            <T::Assets as Unbalanceds<_>>::set_total_issuance(asset, amount)
+        }
     }
-    fn set_total_issuance(_: AssetIdOf<T>, _: Self::Balance) {
-        defensive!("set_total_issuance is not used");
+
+    fn decrease_balance(
+        asset: Self::AssetId,
+        who: &T::AccountId,
+        amount: Self::Balance,
+        precision: Precision,
+        preservation: Preservation,
+        force: Fortitude,
+    ) -> Result<Self::Balance, DispatchError> {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::decrease_balance(
+                who,
+                amount,
+                precision,
+                preservation,
+                force,
+            )
+        } else {
+            <T::Assets as Unbalanceds<_>>::decrease_balance(
+                asset,
+                who,
+                amount,
+                precision,
+                preservation,
+                force,
+            )
+        }
+    }
+
+    fn increase_balance(
+        asset: Self::AssetId,
+        who: &T::AccountId,
+        amount: Self::Balance,
+        precision: Precision,
+    ) -> Result<Self::Balance, DispatchError> {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::increase_balance(who, amount, precision)
+        } else {
+            <T::Assets as Unbalanceds<_>>::increase_balance(asset, who, amount, precision)
+        }
+    }
+
+    fn deactivate(asset: Self::AssetId, amount: Self::Balance) {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::deactivate(amount)
+        } else {
+            <T::Assets as Unbalanceds<_>>::deactivate(asset, amount)
+        }
+    }
+
+    fn reactivate(asset: Self::AssetId, amount: Self::Balance) {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::Unbalanced<_>>::reactivate(amount)
+        } else {
+            <T::Assets as Unbalanceds<_>>::reactivate(asset, amount)
+        }
+    }
+}
+
+impl<T: Config> fungibles::Balanced<T::AccountId> for Pallet<T> {
+    type OnDropDebt = fungibles::IncreaseIssuance<T::AccountId, Self>;
+    type OnDropCredit = fungibles::DecreaseIssuance<T::AccountId, Self>;
+}
+
+impl<T: Config> fungibles::InspectHold<T::AccountId> for Pallet<T> {
+    type Reason = T::RuntimeHoldReason;
+
+    fn total_balance_on_hold(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::InspectHold<_>>::total_balance_on_hold(who)
+        } else {
+            Holds::<T>::get(asset, who)
+                .iter()
+                .fold(Zero::zero(), |total, hold| total.saturating_add(hold.amount))
+        }
+    }
+
+    fn reducible_total_balance_on_hold(
+        asset: Self::AssetId,
+        who: &T::AccountId,
+        force: Fortitude,
+    ) -> Self::Balance {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::InspectHold<_>>::reducible_total_balance_on_hold(who, force)
+        } else {
+            // Freezes only apply to the funds left in the account.
+            <Self as fungibles::InspectHold<_>>::total_balance_on_hold(asset, who)
+        }
+    }
+
+    fn balance_on_hold(
+        asset: Self::AssetId,
+        reason: &Self::Reason,
+        who: &T::AccountId,
+    ) -> Self::Balance {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::InspectHold<_>>::balance_on_hold(reason, who)
+        } else {
+            Holds::<T>::get(asset, who)
+                .iter()
+                .find(|hold| &hold.id == reason)
+                .map_or_else(Zero::zero, |hold| hold.amount)
+        }
+    }
+
+    fn hold_available(asset: Self::AssetId, reason: &Self::Reason, who: &T::AccountId) -> bool {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::InspectHold<_>>::hold_available(reason, who)
+        } else {
+            let holds = Holds::<T>::get(asset, who);
+            holds.len() < T::MaxHolds::get() as usize || holds.iter().any(|hold| &hold.id == reason)
+        }
+    }
+}
+
+impl<T: Config> fungibles::UnbalancedHold<T::AccountId> for Pallet<T> {
+    fn set_balance_on_hold(
+        asset: Self::AssetId,
+        reason: &Self::Reason,
+        who: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if asset == T::GetNativeCurrencyId::get() {
+            return <T::Balances as fungible::UnbalancedHold<_>>::set_balance_on_hold(
+                reason, who, amount,
+            )
+        }
+        Holds::<T>::try_mutate_exists(asset, who, |maybe_holds| -> DispatchResult {
+            let mut holds = maybe_holds.take().unwrap_or_default();
+            match holds.iter().position(|hold| &hold.id == reason) {
+                Some(index) if amount.is_zero() => {
+                    holds.remove(index);
+                },
+                Some(index) => holds[index].amount = amount,
+                None if amount.is_zero() => {},
+                None => holds
+                    .try_push(IdAmount { id: *reason, amount })
+                    .map_err(|_| Error::<T>::TooManyHolds)?,
+            }
+            if !holds.is_empty() {
+                *maybe_holds = Some(holds);
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> fungibles::BalancedHold<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> fungibles::InspectFreeze<T::AccountId> for Pallet<T> {
+    type Id = T::FreezeIdentifier;
+
+    fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> Self::Balance {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::InspectFreeze<_>>::balance_frozen(id, who)
+        } else {
+            Freezes::<T>::get(asset, who)
+                .iter()
+                .find(|freeze| &freeze.id == id)
+                .map_or_else(Zero::zero, |freeze| freeze.amount)
+        }
+    }
+
+    fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+        if asset == T::GetNativeCurrencyId::get() {
+            <T::Balances as fungible::InspectFreeze<_>>::can_freeze(id, who)
+        } else {
+            let freezes = Freezes::<T>::get(asset, who);
+            freezes.len() < T::MaxFreezes::get() as usize ||
+                freezes.iter().any(|freeze| &freeze.id == id)
+        }
+    }
+}
+
+impl<T: Config> fungibles::MutateFreeze<T::AccountId> for Pallet<T> {
+    fn set_freeze(
+        asset: Self::AssetId,
+        id: &Self::Id,
+        who: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if asset == T::GetNativeCurrencyId::get() {
+            return <T::Balances as fungible::MutateFreeze<_>>::set_freeze(id, who, amount)
+        }
+        if amount.is_zero() {
+            return <Self as fungibles::MutateFreeze<_>>::thaw(asset, id, who)
+        }
+        Freezes::<T>::try_mutate(asset, who, |freezes| -> DispatchResult {
+            match freezes.iter_mut().find(|freeze| &freeze.id == id) {
+                Some(freeze) => freeze.amount = amount,
+                None => freezes
+                    .try_push(IdAmount { id: *id, amount })
+                    .map_err(|_| Error::<T>::TooManyFreezes)?,
+            }
+            Ok(())
+        })
+    }
+
+    fn extend_freeze(
+        asset: Self::AssetId,
+        id: &Self::Id,
+        who: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if asset == T::GetNativeCurrencyId::get() {
+            return <T::Balances as fungible::MutateFreeze<_>>::extend_freeze(id, who, amount)
+        }
+        if amount.is_zero() {
+            return Ok(())
+        }
+        Freezes::<T>::try_mutate(asset, who, |freezes| -> DispatchResult {
+            match freezes.iter_mut().find(|freeze| &freeze.id == id) {
+                Some(freeze) => freeze.amount = freeze.amount.max(amount),
+                None => freezes
+                    .try_push(IdAmount { id: *id, amount })
+                    .map_err(|_| Error::<T>::TooManyFreezes)?,
+            }
+            Ok(())
+        })
+    }
+
+    fn thaw(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+        if asset == T::GetNativeCurrencyId::get() {
+            return <T::Balances as fungible::MutateFreeze<_>>::thaw(id, who)
+        }
+        Freezes::<T>::mutate_exists(asset, who, |maybe_freezes| {
+            if let Some(freezes) = maybe_freezes {
+                freezes.retain(|freeze| &freeze.id != id);
+                if freezes.is_empty() {
+                    *maybe_freezes = None;
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+/// pallet-assets keeps the largest freeze of an account on an asset out of its reach.
+impl<T: Config> pallet_assets::FrozenBalance<AssetIdOf<T>, T::AccountId, BalanceOf<T>>
+    for Pallet<T>
+{
+    fn frozen_balance(asset: AssetIdOf<T>, who: &T::AccountId) -> Option<BalanceOf<T>> {
+        Freezes::<T>::get(asset, who).iter().map(|freeze| freeze.amount).max()
+    }
+
+    fn died(asset: AssetIdOf<T>, who: &T::AccountId) {
+        Freezes::<T>::remove(asset, who);
+    }
+}
+
+impl<T: Config> fungibles::Create<T::AccountId> for Pallet<T> {
+    fn create(
+        id: Self::AssetId,
+        admin: T::AccountId,
+        is_sufficient: bool,
+        min_balance: Self::Balance,
+    ) -> DispatchResult {
+        ensure!(id != T::GetNativeCurrencyId::get(), Error::<T>::CannotAlterNativeToken);
+        <T::Assets as fungibles::Create<_>>::create(id, admin, is_sufficient, min_balance)
+    }
+}
+
+impl<T: Config> fungibles::Destroy<T::AccountId> for Pallet<T> {
+    fn start_destroy(id: Self::AssetId, maybe_check_owner: Option<T::AccountId>) -> DispatchResult {
+        ensure!(id != T::GetNativeCurrencyId::get(), Error::<T>::CannotAlterNativeToken);
+        <T::Assets as fungibles::Destroy<_>>::start_destroy(id, maybe_check_owner)
+    }
+
+    /// Destroy up to `max_items` accounts of the asset, then its holds and freezes with what
+    /// is left of `max_items`.
+    fn destroy_accounts(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError> {
+        ensure!(id != T::GetNativeCurrencyId::get(), Error::<T>::CannotAlterNativeToken);
+        let accounts = <T::Assets as fungibles::Destroy<_>>::destroy_accounts(id, max_items)?;
+        // The keys are collected before anything is removed, so that several calls in the same
+        // block make progress.
+        let left = max_items.saturating_sub(accounts) as usize;
+        let holders: Vec<T::AccountId> = Holds::<T>::iter_key_prefix(id).take(left).collect();
+        for who in &holders {
+            let amount = Holds::<T>::take(id, who)
+                .iter()
+                .fold(Zero::zero(), |acc: BalanceOf<T>, hold| acc.saturating_add(hold.amount));
+            Self::deposit_event(Event::HoldsDestroyed { asset: id, who: who.clone(), amount });
+        }
+        let left = left.saturating_sub(holders.len());
+        let frozen: Vec<T::AccountId> = Freezes::<T>::iter_key_prefix(id).take(left).collect();
+        for who in &frozen {
+            Freezes::<T>::remove(id, who);
+        }
+        Ok(accounts.saturating_add((holders.len() + frozen.len()) as u32))
+    }
+
+    fn destroy_approvals(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError> {
+        ensure!(id != T::GetNativeCurrencyId::get(), Error::<T>::CannotAlterNativeToken);
+        <T::Assets as fungibles::Destroy<_>>::destroy_approvals(id, max_items)
+    }
+
+    fn finish_destroy(id: Self::AssetId) -> DispatchResult {
+        ensure!(id != T::GetNativeCurrencyId::get(), Error::<T>::CannotAlterNativeToken);
+        ensure!(
+            Holds::<T>::iter_key_prefix(id).next().is_none() &&
+                Freezes::<T>::iter_key_prefix(id).next().is_none(),
+            Error::<T>::HoldsOrFreezesLeft
+        );
+        <T::Assets as fungibles::Destroy<_>>::finish_destroy(id)
     }
 }
@@ -0,0 +1,163 @@
+//! Test environment for the currency adapter pallet.
+
+#![cfg(test)]
+
+use crate as pallet_currency_adapter;
+use frame_support::{
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64},
+};
+use liquid_staking_primitives::{Balance, CurrencyId, SAITA};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+/// An asset that is not sufficient and has a minimum balance above the holds of the tests.
+pub const ASSET: CurrencyId = 3;
+pub const MIN_BALANCE: Balance = 10;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        CurrencyAdapter: pallet_currency_adapter,
+    }
+);
+
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub enum HoldReason {
+    Staking,
+    Governance,
+    Other,
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type FreezeIdentifier = [u8; 8];
+    type MaxFreezes = ConstU32<2>;
+    type RuntimeHoldReason = HoldReason;
+    type MaxHolds = ConstU32<2>;
+    type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type MaxMemoLen = ConstU32<256>;
+    type MaxMembers = ConstU32<100>;
+    type MaxPendingMints = ConstU32<10>;
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = CurrencyId;
+    type AssetIdParameter = CurrencyId;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<1>;
+    type AssetAccountDeposit = ConstU128<1>;
+    type MetadataDepositBase = ConstU128<1>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type ApprovalDeposit = ConstU128<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = CurrencyAdapter;
+    type WeightInfo = ();
+    type CallbackHandle = ();
+    type Extra = ();
+    type RemoveItemsLimit = ConstU32<5>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+    type Blacklist = ();
+    type MaxMemoLen = ConstU32<256>;
+}
+
+parameter_types! {
+    pub const NativeCurrencyId: CurrencyId = SAITA;
+}
+
+impl pallet_currency_adapter::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = Assets;
+    type Balances = Balances;
+    type RuntimeHoldReason = HoldReason;
+    type FreezeIdentifier = [u8; 8];
+    type MaxHolds = ConstU32<2>;
+    type MaxFreezes = ConstU32<2>;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type LockOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxMemoLen = ConstU32<256>;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 1_000), (BOB, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(ASSET, ALICE, false, MIN_BALANCE)],
+        metadata: vec![],
+        accounts: vec![(ASSET, ALICE, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
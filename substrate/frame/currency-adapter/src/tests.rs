@@ -0,0 +1,229 @@
+//! Tests for the currency adapter pallet.
+
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, Freezes, Holds};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::tokens::{
+        fungibles::{
+            Balanced, Create, Destroy, Inspect, InspectFreeze, InspectHold, Mutate, MutateHold,
+        },
+        Fortitude, Precision, Preservation,
+    },
+};
+use liquid_staking_primitives::SAITA;
+use sp_runtime::TokenError;
+
+const FREEZE: [u8; 8] = *b"freeze01";
+
+#[test]
+fn assets_on_hold_stay_in_the_supply_without_another_account() {
+    new_test_ext().execute_with(|| {
+        // Holds need no other account of the non-sufficient asset, so less than its minimum
+        // balance may be held.
+        assert_ok!(CurrencyAdapter::hold(ASSET, &HoldReason::Staking, &ALICE, 5));
+        assert_ok!(CurrencyAdapter::hold(ASSET, &HoldReason::Governance, &ALICE, 20));
+        assert_eq!(CurrencyAdapter::balance(ASSET, &ALICE), 75);
+        assert_eq!(CurrencyAdapter::balance_on_hold(ASSET, &HoldReason::Staking, &ALICE), 5);
+        assert_eq!(CurrencyAdapter::total_balance_on_hold(ASSET, &ALICE), 25);
+        assert_eq!(CurrencyAdapter::total_balance(ASSET, &ALICE), 100);
+        assert_eq!(CurrencyAdapter::total_issuance(ASSET), 100);
+
+        assert_noop!(
+            CurrencyAdapter::hold(ASSET, &HoldReason::Other, &ALICE, 5),
+            TokenError::CannotCreateHold
+        );
+        // The account has to keep the minimum balance.
+        assert_noop!(
+            CurrencyAdapter::hold(ASSET, &HoldReason::Staking, &ALICE, 70),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn assets_on_hold_can_be_released_and_burned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CurrencyAdapter::hold(ASSET, &HoldReason::Staking, &ALICE, 30));
+
+        assert_eq!(
+            CurrencyAdapter::release(ASSET, &HoldReason::Staking, &ALICE, 10, Precision::Exact),
+            Ok(10)
+        );
+        assert_eq!(CurrencyAdapter::balance(ASSET, &ALICE), 80);
+        assert_eq!(CurrencyAdapter::balance_on_hold(ASSET, &HoldReason::Staking, &ALICE), 20);
+
+        assert_eq!(
+            CurrencyAdapter::burn_held(
+                ASSET,
+                &HoldReason::Staking,
+                &ALICE,
+                20,
+                Precision::Exact,
+                Fortitude::Polite,
+            ),
+            Ok(20)
+        );
+        assert_eq!(CurrencyAdapter::total_balance(ASSET, &ALICE), 80);
+        assert_eq!(CurrencyAdapter::total_issuance(ASSET), 80);
+        assert!(!Holds::<Test>::contains_key(ASSET, ALICE));
+    });
+}
+
+#[test]
+fn native_holds_are_the_ones_of_balances() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CurrencyAdapter::hold(SAITA, &HoldReason::Staking, &ALICE, 100));
+        assert_eq!(Balances::free_balance(ALICE), 900);
+        assert_eq!(Balances::reserved_balance(ALICE), 100);
+        assert_eq!(CurrencyAdapter::balance_on_hold(SAITA, &HoldReason::Staking, &ALICE), 100);
+        assert!(!Holds::<Test>::contains_key(SAITA, ALICE));
+    });
+}
+
+#[test]
+fn asset_freezes_are_enforced_by_the_assets_pallet() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CurrencyAdapter::force_set_freeze(
+            RuntimeOrigin::root(),
+            ASSET,
+            FREEZE,
+            ALICE,
+            60,
+        ));
+        System::assert_last_event(RuntimeEvent::CurrencyAdapter(Event::FreezeSet {
+            asset: ASSET,
+            id: FREEZE,
+            who: ALICE,
+            amount: 60,
+        }));
+        assert_eq!(CurrencyAdapter::balance_frozen(ASSET, &FREEZE, &ALICE), 60);
+
+        // The frozen amount and the minimum balance stay in the account.
+        assert_noop!(
+            Assets::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, 31),
+            pallet_assets::Error::<Test>::BalanceLow
+        );
+        assert_noop!(
+            CurrencyAdapter::transfer(ASSET, &ALICE, &BOB, 31, Preservation::Expendable),
+            TokenError::Frozen
+        );
+        assert_ok!(Assets::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, 30));
+
+        assert_ok!(CurrencyAdapter::force_thaw(RuntimeOrigin::root(), ASSET, FREEZE, ALICE));
+        assert!(!Freezes::<Test>::contains_key(ASSET, ALICE));
+        assert_ok!(Assets::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, 60));
+        assert_eq!(CurrencyAdapter::balance(ASSET, &BOB), 90);
+    });
+}
+
+#[test]
+fn freezes_need_the_lock_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CurrencyAdapter::force_set_freeze(RuntimeOrigin::signed(ALICE), ASSET, FREEZE, BOB, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CurrencyAdapter::force_thaw(RuntimeOrigin::signed(ALICE), ASSET, FREEZE, BOB),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn balanced_deposits_and_withdrawals_settle_the_issuance() {
+    new_test_ext().execute_with(|| {
+        let debt = CurrencyAdapter::deposit(ASSET, &BOB, 50, Precision::Exact).unwrap();
+        assert_eq!(debt.peek(), 50);
+        assert_eq!(CurrencyAdapter::total_issuance(ASSET), 100);
+        // Dropping the debt mints it.
+        drop(debt);
+        assert_eq!(CurrencyAdapter::total_issuance(ASSET), 150);
+        assert_eq!(CurrencyAdapter::balance(ASSET, &BOB), 50);
+
+        let credit = CurrencyAdapter::withdraw(
+            ASSET,
+            &ALICE,
+            40,
+            Precision::Exact,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .unwrap();
+        assert_eq!(credit.peek(), 40);
+        // Dropping the credit burns it.
+        drop(credit);
+        assert_eq!(CurrencyAdapter::total_issuance(ASSET), 110);
+        assert_eq!(CurrencyAdapter::balance(ASSET, &ALICE), 60);
+
+        // The native currency goes through the balances pallet.
+        drop(CurrencyAdapter::deposit(SAITA, &BOB, 100, Precision::Exact).unwrap());
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+        assert_eq!(CurrencyAdapter::total_issuance(SAITA), 2_100);
+    });
+}
+
+#[test]
+fn only_assets_other_than_the_native_currency_can_be_created_or_destroyed() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            <CurrencyAdapter as Create<_>>::create(SAITA, ALICE, true, 1),
+            Error::<Test>::CannotAlterNativeToken
+        );
+        assert_noop!(
+            <CurrencyAdapter as Destroy<_>>::start_destroy(SAITA, None),
+            Error::<Test>::CannotAlterNativeToken
+        );
+        assert_noop!(
+            <CurrencyAdapter as Destroy<_>>::finish_destroy(SAITA),
+            Error::<Test>::CannotAlterNativeToken
+        );
+
+        assert_ok!(<CurrencyAdapter as Create<_>>::create(4, ALICE, true, 1));
+        assert!(CurrencyAdapter::asset_exists(4));
+        assert_ok!(CurrencyAdapter::mint_into(4, &BOB, 10));
+        assert_ok!(<CurrencyAdapter as Destroy<_>>::start_destroy(4, None));
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_accounts(4, 10), Ok(1));
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_approvals(4, 10), Ok(0));
+        assert_ok!(<CurrencyAdapter as Destroy<_>>::finish_destroy(4));
+        assert!(!CurrencyAdapter::asset_exists(4));
+    });
+}
+
+#[test]
+fn destroying_an_asset_destroys_its_holds_and_freezes_within_max_items() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<CurrencyAdapter as Create<_>>::create(4, ALICE, true, 1));
+        assert_ok!(CurrencyAdapter::mint_into(4, &BOB, 10));
+        assert_ok!(CurrencyAdapter::hold(4, &HoldReason::Staking, &BOB, 4));
+        assert_ok!(CurrencyAdapter::force_set_freeze(RuntimeOrigin::root(), 4, FREEZE, ALICE, 1));
+        assert_ok!(<CurrencyAdapter as Destroy<_>>::start_destroy(4, None));
+
+        // The account of BOB first, then its holds and the freeze of ALICE.
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_accounts(4, 1), Ok(1));
+        assert_noop!(
+            <CurrencyAdapter as Destroy<_>>::finish_destroy(4),
+            Error::<Test>::HoldsOrFreezesLeft
+        );
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_accounts(4, 1), Ok(1));
+        System::assert_last_event(RuntimeEvent::CurrencyAdapter(Event::HoldsDestroyed {
+            asset: 4,
+            who: BOB,
+            amount: 4,
+        }));
+        assert!(!Holds::<Test>::contains_key(4, BOB));
+        assert!(Freezes::<Test>::contains_key(4, ALICE));
+        assert_noop!(
+            <CurrencyAdapter as Destroy<_>>::finish_destroy(4),
+            Error::<Test>::HoldsOrFreezesLeft
+        );
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_accounts(4, 1), Ok(1));
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_accounts(4, 1), Ok(0));
+
+        assert_eq!(<CurrencyAdapter as Destroy<_>>::destroy_approvals(4, 10), Ok(0));
+        assert_ok!(<CurrencyAdapter as Destroy<_>>::finish_destroy(4));
+        assert!(!CurrencyAdapter::asset_exists(4));
+    });
+}
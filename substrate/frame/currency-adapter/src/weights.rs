@@ -0,0 +1,143 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_currency_adapter
+//!
+//! THESE WEIGHTS WERE NOT GENERATED BY THE SUBSTRATE BENCHMARK CLI. The benchmarks of this
+//! pallet have not been run on reference hardware yet, so every function charges a
+//! deliberately pessimistic 500 µs and 16 KiB of proof, plus 50 µs and 4 KiB per item of its
+//! components, on top of the storage it reads and writes. Replace this file with the output
+//! of the command below before a release.
+
+// Command to generate the weights:
+// target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_currency_adapter
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/currency-adapter/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_currency_adapter.
+pub trait WeightInfo {
+	fn force_set_lock() -> Weight;
+	fn force_remove_lock() -> Weight;
+	fn transfer_with_memo() -> Weight;
+	fn force_set_freeze() -> Weight;
+	fn force_thaw() -> Weight;
+}
+
+/// Placeholder weights for pallet_currency_adapter, see the module documentation.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_set_lock() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_remove_lock() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::BlackList` (r:2 w:0)
+	fn transfer_with_memo() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_set_freeze() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_thaw() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_set_lock() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_remove_lock() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::BlackList` (r:2 w:0)
+	fn transfer_with_memo() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_set_freeze() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_thaw() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
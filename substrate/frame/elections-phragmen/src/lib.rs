@@ -1358,6 +1358,10 @@ mod tests {
 		type MaxFreezes = ();
 		type RuntimeHoldReason = ();
 		type MaxHolds = ();
+		type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+		type MaxMemoLen = frame_support::traits::ConstU32<256>;
+		type MaxMembers = frame_support::traits::ConstU32<100>;
+		type MaxPendingMints = frame_support::traits::ConstU32<10>;
 	}
 
 	frame_support::parameter_types! {
@@ -1511,6 +1515,7 @@ mod tests {
 						(5, 50 * self.balance_factor),
 						(6, 60 * self.balance_factor),
 					],
+					..Default::default()
 				},
 				elections: elections_phragmen::GenesisConfig::<Test> {
 					members: self.genesis_members,
@@ -905,6 +905,10 @@ mod tests {
 		type MaxFreezes = ConstU32<1>;
 		type RuntimeHoldReason = ();
 		type MaxHolds = ConstU32<1>;
+		type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+		type MaxMemoLen = frame_support::traits::ConstU32<256>;
+		type MaxMembers = frame_support::traits::ConstU32<100>;
+		type MaxPendingMints = frame_support::traits::ConstU32<10>;
 	}
 
 	parameter_types! {
@@ -917,6 +921,9 @@ mod tests {
 		type WeightToFee = IdentityFee<Balance>;
 		type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 		type FeeMultiplierUpdate = ();
+		type BlackListAccounts = ();
+		type FeeExemption = ();
+		type FreeTransactionsPerBlock = ConstU32<0>;
 	}
 	impl custom::Config for Runtime {}
 
@@ -983,7 +990,7 @@ mod tests {
 	#[test]
 	fn balance_transfer_dispatch_works() {
 		let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
-		pallet_balances::GenesisConfig::<Runtime> { balances: vec![(1, 211)] }
+		pallet_balances::GenesisConfig::<Runtime> { balances: vec![(1, 211)], ..Default::default() }
 			.assimilate_storage(&mut t)
 			.unwrap();
 		let xt = TestXt::new(call_transfer(2, 69), sign_extra(1, 0, 0));
@@ -1011,17 +1018,23 @@ mod tests {
 
 	fn new_test_ext(balance_factor: Balance) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
-		pallet_balances::GenesisConfig::<Runtime> { balances: vec![(1, 111 * balance_factor)] }
-			.assimilate_storage(&mut t)
-			.unwrap();
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(1, 111 * balance_factor)],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
 		t.into()
 	}
 
 	fn new_test_ext_v0(balance_factor: Balance) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
-		pallet_balances::GenesisConfig::<Runtime> { balances: vec![(1, 111 * balance_factor)] }
-			.assimilate_storage(&mut t)
-			.unwrap();
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(1, 111 * balance_factor)],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
 		(t, sp_runtime::StateVersion::V0).into()
 	}
 
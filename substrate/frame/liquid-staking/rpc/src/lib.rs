@@ -0,0 +1,117 @@
+//! RPC interface for the liquid staking pallet.
+
+use std::{convert::TryInto, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use pallet_liquid_staking_runtime_api::{Rate, StakingPosition, UnlockingInfo};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+pub use pallet_liquid_staking_runtime_api::LiquidStakingApi as LiquidStakingRuntimeApi;
+
+#[rpc(client, server)]
+pub trait LiquidStakingApi<BlockHash, AccountId> {
+	#[method(name = "liquidStaking_exchangeRate")]
+	fn exchange_rate(&self, at: Option<BlockHash>) -> RpcResult<Rate>;
+
+	#[method(name = "liquidStaking_stakingPosition")]
+	fn staking_position(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<StakingPosition<NumberOrHex>>;
+}
+
+/// Provides RPC methods to query the liquid staking position of an account.
+pub struct LiquidStaking<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> LiquidStaking<C, P> {
+	/// Creates a new instance of the LiquidStaking Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string())))
+}
+
+impl<C, Block, AccountId, Balance> LiquidStakingApiServer<<Block as BlockT>::Hash, AccountId>
+	for LiquidStaking<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: LiquidStakingRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn exchange_rate(&self, at: Option<Block::Hash>) -> RpcResult<Rate> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.exchange_rate(at_hash)
+			.map_err(|e| map_err(e, "Unable to query the exchange rate.").into())
+	}
+
+	fn staking_position(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<StakingPosition<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let position = api
+			.staking_position(at_hash, who)
+			.map_err(|e| map_err(e, "Unable to query the staking position."))?;
+
+		let try_into_rpc_balance = |value: Balance| {
+			value.try_into().map_err(|_| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					ErrorCode::InvalidParams.code(),
+					format!("{} doesn't fit in NumberOrHex representation", value),
+					None::<()>,
+				)))
+			})
+		};
+
+		Ok(StakingPosition {
+			liquid_balance: try_into_rpc_balance(position.liquid_balance)?,
+			staked_amount: try_into_rpc_balance(position.staked_amount)?,
+			unlocking: position
+				.unlocking
+				.into_iter()
+				.map(|chunk| {
+					Ok(UnlockingInfo { value: try_into_rpc_balance(chunk.value)?, era: chunk.era })
+				})
+				.collect::<Result<_, JsonRpseeError>>()?,
+			claimable: try_into_rpc_balance(position.claimable)?,
+			estimated_era_reward: try_into_rpc_balance(position.estimated_era_reward)?,
+		})
+	}
+}
@@ -0,0 +1,21 @@
+//! Runtime API definition for the liquid staking pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_liquid_staking::types::{ Rate, StakingPosition, UnlockingInfo };
+
+sp_api::decl_runtime_apis! {
+	pub trait LiquidStakingApi<AccountId, Balance>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+	{
+		/// Returns how much of the staking currency one unit of the liquid currency is worth.
+		fn exchange_rate() -> Rate;
+
+		/// Returns the liquid staking position of `who`.
+		fn staking_position(who: AccountId) -> StakingPosition<Balance>;
+	}
+}
@@ -0,0 +1,331 @@
+//! Liquid staking pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as LiquidStaking;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::{ fungible::Mutate as _, Get, Hooks };
+use frame_system::RawOrigin;
+use frame_support::BoundedVec;
+use pallet_staking::{ Ledger, MinNominatorBond, ValidatorPrefs, Validators };
+
+const SEED: u32 = 0;
+
+/// An amount comfortably above every minimum the liquid staking and staking pallets enforce.
+fn stake_amount<T: Config>() -> BalanceOf<T> {
+	let min_bond: u128 = MinNominatorBond::<T>::get().into();
+	T::MinStake::get()
+		.max(<T::Balances as Inspect<T::AccountId>>::minimum_balance())
+		.max(min_bond)
+		.saturating_mul(1_000)
+}
+
+fn funded_staker<T: Config>(name: &'static str) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, SEED);
+	T::Balances::set_balance(&who, stake_amount::<T>().saturating_mul(10));
+	who
+}
+
+/// Stake into the pool on behalf of a new account and return it with its liquid balance.
+fn staked<T: Config>() -> Result<(T::AccountId, BalanceOf<T>), BenchmarkError> {
+	T::BenchmarkHelper::create_liquid_currency();
+	let who = funded_staker::<T>("staker");
+	LiquidStaking::<T>::stake(RawOrigin::Signed(who.clone()).into(), stake_amount::<T>())?;
+	let liquid = T::Assets::balance(LiquidStaking::<T>::liquid_currency()?, &who);
+	Ok((who, liquid))
+}
+
+fn strategy_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::StrategyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+fn fee_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::FeeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+/// Set up the collection unlock tickets get minted in.
+fn ticket_collection<T: Config>() -> Result<(), BenchmarkError> {
+	let collection = T::BenchmarkHelper::create_ticket_collection();
+	LiquidStaking::<T>::set_ticket_collection(strategy_origin::<T>()?, collection)?;
+	Ok(())
+}
+
+/// Bond the funds staked into the vault, the way the era rebalance does. Returns the vault and
+/// the staker.
+fn bonded_vault<T: Config>() -> Result<(T::AccountId, T::AccountId), BenchmarkError> {
+	let (who, _) = staked::<T>()?;
+	let vault = LiquidStaking::<T>::account_id();
+	pallet_staking::Pallet::<T>::bond(
+		RawOrigin::Signed(vault.clone()).into(),
+		stake_amount::<T>().into(),
+		RewardDestination::Account(vault.clone())
+	)?;
+	Ok((vault, who))
+}
+
+fn validators<T: Config>(n: u32) -> Vec<T::AccountId> {
+	(0..n)
+		.map(|i| {
+			let validator: T::AccountId = account("validator", i, SEED);
+			Validators::<T>::insert(&validator, ValidatorPrefs::default());
+			validator
+		})
+		.collect()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn stake() {
+		T::BenchmarkHelper::create_liquid_currency();
+		let who = funded_staker::<T>("staker");
+		let amount = stake_amount::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), amount);
+
+		assert_eq!(MatchingPool::<T>::get().total_active(), Ok(amount));
+	}
+
+	#[benchmark]
+	fn unstake() -> Result<(), BenchmarkError> {
+		let (who, liquid) = staked::<T>()?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), liquid);
+
+		assert!(Unlockings::<T>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_for() -> Result<(), BenchmarkError> {
+		let (who, liquid) = staked::<T>()?;
+		LiquidStaking::<T>::unstake(RawOrigin::Signed(who.clone()).into(), liquid)?;
+		CurrentEra::<T>::put(LiquidStaking::<T>::target_era());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), T::Lookup::unlookup(who.clone()));
+
+		assert!(!Unlockings::<T>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bond() -> Result<(), BenchmarkError> {
+		staked::<T>()?;
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, stake_amount::<T>());
+
+		assert!(Ledger::<T>::contains_key(LiquidStaking::<T>::account_id()));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unbond() -> Result<(), BenchmarkError> {
+		let (vault, _) = bonded_vault::<T>()?;
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, stake_amount::<T>() / 2);
+
+		assert!(!Ledger::<T>::get(&vault).unwrap().unlocking.is_empty());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded() -> Result<(), BenchmarkError> {
+		let (vault, _) = bonded_vault::<T>()?;
+		LiquidStaking::<T>::unbond(strategy_origin::<T>()?, stake_amount::<T>() / 2)?;
+		CurrentEra::<T>::put(LiquidStaking::<T>::target_era());
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(Ledger::<T>::get(&vault).unwrap().unlocking.is_empty());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn nominate(n: Linear<1, { T::MaxTargets::get() }>) -> Result<(), BenchmarkError> {
+		let (vault, _) = bonded_vault::<T>()?;
+		let targets = validators::<T>(n)
+			.into_iter()
+			.map(|validator| (T::Lookup::unlookup(validator), 1))
+			.collect::<Vec<_>>();
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, targets);
+
+		assert!(pallet_staking::Nominators::<T>::contains_key(&vault));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_reward(n: Linear<1, { T::MaxTargets::get() }>) -> Result<(), BenchmarkError> {
+		let (_, who) = bonded_vault::<T>()?;
+		let targets = validators::<T>(n);
+		for validator in &targets {
+			ValidatorRewardAccounts::<T>::insert(
+				validator,
+				<T as pallet_reward::Config>::Balance::from(1u128)
+			);
+		}
+		LiquidStaking::<T>::nominate(
+			strategy_origin::<T>()?,
+			targets
+				.iter()
+				.map(|validator| (T::Lookup::unlookup(validator.clone()), 1))
+				.collect()
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who));
+
+		assert_eq!(EraRewardsVault::<T>::get().unwrap_or_default().len(), n as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bond_extra() -> Result<(), BenchmarkError> {
+		let (vault, _) = bonded_vault::<T>()?;
+		T::Balances::set_balance(&vault, stake_amount::<T>().saturating_mul(20));
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, stake_amount::<T>());
+
+		let active: u128 = Ledger::<T>::get(&vault).unwrap().active.into();
+		assert_eq!(active, stake_amount::<T>().saturating_mul(2));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rebond(l: Linear<1, { T::MaxUnlockingChunks::get() }>) -> Result<(), BenchmarkError> {
+		let (vault, _) = bonded_vault::<T>()?;
+		let chunk = stake_amount::<T>() / 100;
+		for era in 0..l {
+			CurrentEra::<T>::put(era);
+			LiquidStaking::<T>::unbond(strategy_origin::<T>()?, chunk)?;
+		}
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, chunk.saturating_mul(l.into()));
+
+		assert!(Ledger::<T>::get(&vault).unwrap().unlocking.is_empty());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn instant_unstake() -> Result<(), BenchmarkError> {
+		// The stake is not settled yet, so it covers its own redemption.
+		let (who, liquid) = staked::<T>()?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), liquid);
+
+		assert!(T::Assets::balance(LiquidStaking::<T>::liquid_currency()?, &who).is_zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_ticket_collection() -> Result<(), BenchmarkError> {
+		let collection = T::BenchmarkHelper::create_ticket_collection();
+		let origin = strategy_origin::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, collection.clone());
+
+		assert_eq!(TicketCollection::<T>::get(), Some(collection));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unstake_to_ticket() -> Result<(), BenchmarkError> {
+		ticket_collection::<T>()?;
+		let (who, liquid) = staked::<T>()?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), liquid);
+
+		assert!(UnlockTickets::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn redeem_ticket() -> Result<(), BenchmarkError> {
+		ticket_collection::<T>()?;
+		let (who, liquid) = staked::<T>()?;
+		LiquidStaking::<T>::unstake_to_ticket(RawOrigin::Signed(who.clone()).into(), liquid)?;
+		CurrentEra::<T>::put(LiquidStaking::<T>::target_era());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), 0);
+
+		assert!(!UnlockTickets::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rebalance() -> Result<(), BenchmarkError> {
+		// Worst case: a matured unlocking chunk gets withdrawn, fresh stakes get bonded and the
+		// vault starts nominating every target.
+		let (vault, _) = bonded_vault::<T>()?;
+		LiquidStaking::<T>::unbond(strategy_origin::<T>()?, stake_amount::<T>() / 2)?;
+		let current_era = LiquidStaking::<T>::target_era();
+		CurrentEra::<T>::put(current_era);
+		staked::<T>()?;
+		let targets: BoundedVec<_, T::MaxTargets> = validators::<T>(T::MaxTargets::get())
+			.into_iter()
+			.map(|validator| (validator, 1))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		ValidatorTargets::<T>::put(targets);
+		// The fees of the last era get reported too.
+		LastRebalancedEra::<T>::put(0);
+		EraFees::<T>::mutate(|fees| fees.stake_fees = One::one());
+
+		#[block]
+		{
+			LiquidStaking::<T>::on_initialize(Zero::zero());
+		}
+
+		assert_eq!(LastRebalancedEra::<T>::get(), Some(current_era));
+		assert!(pallet_staking::Nominators::<T>::contains_key(&vault));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_commission() -> Result<(), BenchmarkError> {
+		let origin = fee_origin::<T>()?;
+		let commission = T::MaxCommission::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, commission);
+
+		assert_eq!(Commission::<T>::get(), commission);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fees() -> Result<(), BenchmarkError> {
+		let origin = fee_origin::<T>()?;
+		let fee = T::MaxFee::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, fee, fee);
+
+		assert_eq!((StakeFee::<T>::get(), UnstakeFee::<T>::get()), (fee, fee));
+		Ok(())
+	}
+}
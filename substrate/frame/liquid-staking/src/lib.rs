@@ -1,30 +1,98 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
+	ensure,
 	pallet_prelude::DispatchResult,
+	BoundedVec,
 	PalletId,
 	traits::{
 		fungible::{ Inspect, Mutate },
 		fungibles::{ Inspect as Inspects, Mutate as Mutates },
+		nonfungibles_v2::{ Inspect as NftInspect, Mutate as NftMutate },
 		liquid_staking::{ StakingAccount, DerivativeRewardAccount },
 		LockableCurrency,
-		tokens::{ Precision::BestEffort, Preservation::Expendable, Fortitude::Polite },
+		EnsureOrigin,
+		tokens::{
+			DepositConsequence,
+			Precision::Exact,
+			Preservation::Expendable,
+			Fortitude::Polite,
+			Provenance,
+		},
 	},
 };
 use liquid_staking_primitives::{ EraIndex, CurrencyId, Balance };
 use scale_info::prelude::vec::Vec;
-use pallet_staking::{ CurrentEra, UnlockChunk };
-use crate::types::{ MatchingLedger, LiquidStakingCurrenciesProvider, DecimalProvider };
-use sp_runtime::traits::{ StaticLookup, Zero, AccountIdConversion };
-use pallet_reward::{NominatorRewardAccounts,EraReward};
+use sp_std::collections::btree_map::BTreeMap;
+use pallet_staking::{ CurrentEra, UnlockChunk, RewardDestination };
+use sp_staking::OnStakingUpdate;
+use crate::types::{
+	CollectedFees,
+	MatchingLedger,
+	LiquidStakingCurrenciesProvider,
+	DecimalProvider,
+	Rate,
+	StakingPosition,
+	TicketId,
+	UnlockingInfo,
+};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{ StaticLookup, Zero, One, AccountIdConversion },
+	ArithmeticError,
+	PerThing,
+	Perbill,
+	Permill,
+	Perquintill,
+	FixedPointNumber,
+	Rounding,
+};
+#[cfg(any(feature = "try-runtime", test))]
+use sp_runtime::TryRuntimeError;
+use pallet_reward::{ NominatorRewardAccounts, EraReward, EraRewardsVault, ValidatorRewardAccounts };
 pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 pub type BalanceOf<T> =
 	<<T as Config>::Assets as Inspects<<T as frame_system::Config>::AccountId>>::Balance;
 pub type AssetIdOf<T> =
 	<<T as Config>::Assets as Inspects<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type StakingBalanceOf<T> = pallet_staking::BalanceOf<T>;
+pub type UnlockingsOf<T> = BoundedVec<UnlockChunk<BalanceOf<T>>, <T as Config>::MaxUnlockings>;
+
+/// The attribute of an unlock ticket holding the amount it redeemed for when it was minted.
+/// Slashes of the vault after that reduce what it redeems for.
+pub const TICKET_AMOUNT_KEY: &[u8] = b"amount";
+/// The attribute of an unlock ticket holding the era it can be redeemed from.
+pub const TICKET_ERA_KEY: &[u8] = b"era";
 
 pub use pallet::*;
+pub mod migration;
 pub mod types;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Prepares the runtime for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<CollectionId> {
+	/// Make sure the liquid currency exists, so it can be minted.
+	fn create_liquid_currency();
+	/// Create a collection owned by the pallet account to mint unlock tickets in.
+	fn create_ticket_collection() -> CollectionId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<CollectionId: Default> BenchmarkHelper<CollectionId> for () {
+	fn create_liquid_currency() {}
+	fn create_ticket_collection() -> CollectionId {
+		Default::default()
+	}
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -32,6 +100,9 @@ pub mod pallet {
 	use frame_support::pallet_prelude::{ ValueQuery, * };
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_staking::Config + pallet_reward::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -46,62 +117,244 @@ pub mod pallet {
 		type PalletId: Get<PalletId>;
 		#[pallet::constant]
 		type MinStake: Get<BalanceOf<Self>>;
+		/// The origin allowed to operate the vault: bond, unbond and pick its validators.
+		type StrategyOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The maximum number of validators the vault can target.
+		#[pallet::constant]
+		type MaxTargets: Get<u32>;
+		/// The maximum number of pending unlock chunks of an account. Unstakes made in the same
+		/// era share a chunk, so this caps the eras an account can be waiting on at once.
+		#[pallet::constant]
+		type MaxUnlockings: Get<u32>;
+		/// The fee charged on instant unstakes. It stays in the pool, so it accrues to the
+		/// remaining holders of the liquid currency.
+		#[pallet::constant]
+		type InstantUnstakeFee: Get<Permill>;
+		/// The origin allowed to set the protocol commission and the stake and unstake fees.
+		type FeeOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The account the protocol fees are paid to, in the liquid currency.
+		type FeeCollector: Get<Self::AccountId>;
+		/// The highest commission that can be taken on the vault's rewards.
+		#[pallet::constant]
+		type MaxCommission: Get<Perbill>;
+		/// The highest fee that can be charged on stakes and on unstakes.
+		#[pallet::constant]
+		type MaxFee: Get<Permill>;
+		/// The NFTs pending redemptions can be turned into, so they can change hands.
+		///
+		/// Tickets are minted without charging the collection owner, so the ticket collection is
+		/// best created without deposits.
+		type Nfts: NftInspect<
+			Self::AccountId,
+			ItemId = TicketId,
+			CollectionId = Self::TicketCollectionId
+		> +
+			NftMutate<Self::AccountId, Self::TicketItemConfig>;
+		/// Identifies the collection unlock tickets are minted in.
+		type TicketCollectionId: Member + Parameter + MaxEncodedLen;
+		/// The settings unlock tickets are minted with.
+		type TicketItemConfig: Default;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Helper to set up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::TicketCollectionId>;
 	}
 
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// The pending redemptions of every account. The chunks hold shares of the pending
+	/// redemptions, see `UnlockingSlashFactor`.
 	#[pallet::storage]
 	#[pallet::getter(fn unlockings)]
 	pub type Unlockings<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		Vec<UnlockChunk<BalanceOf<T>>>,
+		UnlockingsOf<T>,
 		OptionQuery
 	>;
 
+	#[pallet::type_value]
+	pub fn DefaultExchangeRate() -> Rate {
+		Rate::one()
+	}
+
+	/// The exchange rate between the staking currency and the liquid currency, i.e. how much
+	/// SAITA one unit of sSAITA can be redeemed for.
+	#[pallet::storage]
+	#[pallet::getter(fn exchange_rate)]
+	pub type ExchangeRate<T: Config> = StorageValue<_, Rate, ValueQuery, DefaultExchangeRate>;
+
+	/// The pool backing the liquid currency. `total_stake_amount - total_unstake_amount` is the
+	/// amount of SAITA the outstanding sSAITA can be redeemed for.
+	///
+	/// The `reserved` parts are the amounts already settled on `pallet_staking`, the free parts
+	/// are the stakes and unstakes of the current era, which get netted against each other.
+	#[pallet::storage]
+	#[pallet::getter(fn matching_pool)]
+	pub type MatchingPool<T: Config> = StorageValue<_, MatchingLedger<BalanceOf<T>>, ValueQuery>;
+
+	/// The total of the shares waiting in `Unlockings` and `UnlockTickets` to be claimed. The
+	/// vault keeps the staking currency they are worth out of the bond.
+	#[pallet::storage]
+	pub type TotalUnlocking<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The part of the pending redemptions left after every slash of the vault so far.
+	///
+	/// Pending redemptions are kept as shares, their amount divided by this factor when they
+	/// were made, and are worth their share times the current factor. A slash only lowers the
+	/// factor, so it reaches every pending redemption without touching any of them.
 	#[pallet::storage]
-	#[pallet::getter(fn account_list)]
-	pub type AccountStake<T: Config> = StorageMap<
+	#[pallet::getter(fn unlocking_slash_factor)]
+	pub type UnlockingSlashFactor<T: Config> = StorageValue<
 		_,
-		Blake2_128Concat,
-		T::AccountId,
-		u128,
+		Rate,
+		ValueQuery,
+		DefaultExchangeRate
+	>;
+
+	/// The validators the vault nominates, with their target weight. Validators with a zero
+	/// weight are kept but not nominated.
+	#[pallet::storage]
+	#[pallet::getter(fn validator_targets)]
+	pub type ValidatorTargets<T: Config> = StorageValue<
+		_,
+		BoundedVec<(T::AccountId, u32), T::MaxTargets>,
+		ValueQuery
+	>;
+
+	/// The last era in which the vault's bond was rebalanced.
+	#[pallet::storage]
+	pub type LastRebalancedEra<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
+	/// The collection unlock tickets are minted in, owned by the pallet account. No tickets
+	/// can be minted while it is unset.
+	#[pallet::storage]
+	#[pallet::getter(fn ticket_collection)]
+	pub type TicketCollection<T: Config> = StorageValue<_, T::TicketCollectionId, OptionQuery>;
+
+	/// The redemption every outstanding unlock ticket entitles its holder to, as a share of the
+	/// pending redemptions. Their total is part of `TotalUnlocking`.
+	#[pallet::storage]
+	#[pallet::getter(fn unlock_tickets)]
+	pub type UnlockTickets<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		TicketId,
+		UnlockChunk<BalanceOf<T>>,
 		OptionQuery
 	>;
 
+	/// The id of the next unlock ticket.
 	#[pallet::storage]
-	#[pallet::getter(fn matching_pool)]
-	pub type MatchingPool<T: Config> = StorageValue<_, MatchingLedger<BalanceOf<T>>, ValueQuery>;
+	pub type NextTicketId<T: Config> = StorageValue<_, TicketId, ValueQuery>;
+
+	/// The share of the vault's rewards the protocol takes as its commission.
+	#[pallet::storage]
+	#[pallet::getter(fn commission)]
+	pub type Commission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+	/// The fee charged on stakes, out of the liquid currency they mint.
 	#[pallet::storage]
-	#[pallet::getter(fn bonded_accounts)]
-	pub type StakedAccounts<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+	#[pallet::getter(fn stake_fee)]
+	pub type StakeFee<T: Config> = StorageValue<_, Permill, ValueQuery>;
 
+	/// The fee charged on unstakes, out of the liquid currency they redeem.
 	#[pallet::storage]
-	#[pallet::getter(fn era_reward_account)]
-	pub type EraDerivativeReward<T: Config> = StorageValue<_, Vec<T::AccountId>, OptionQuery>;
+	#[pallet::getter(fn unstake_fee)]
+	pub type UnstakeFee<T: Config> = StorageValue<_, Permill, ValueQuery>;
 
-    #[pallet::storage]
-    pub type Bonds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+	/// The protocol fees collected so far in the current era. They are reported and reset
+	/// once the era is over.
+	#[pallet::storage]
+	#[pallet::getter(fn era_fees)]
+	pub type EraFees<T: Config> = StorageValue<_, CollectedFees<BalanceOf<T>>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The commission taken on the vault's rewards.
+		pub commission: Perbill,
+		/// The fee charged on stakes.
+		pub stake_fee: Permill,
+		/// The fee charged on unstakes.
+		pub unstake_fee: Permill,
+		/// The staking currency minted into the vault account, so the vault exists before the
+		/// first stake.
+		pub vault_funds: BalanceOf<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			assert!(
+				self.commission <= T::MaxCommission::get(),
+				"the commission can't be above `MaxCommission`."
+			);
+			assert!(
+				self.stake_fee <= T::MaxFee::get() && self.unstake_fee <= T::MaxFee::get(),
+				"the stake and unstake fees can't be above `MaxFee`."
+			);
+			Commission::<T>::put(self.commission);
+			StakeFee::<T>::put(self.stake_fee);
+			UnstakeFee::<T>::put(self.unstake_fee);
+			if !self.vault_funds.is_zero() {
+				assert!(
+					T::Balances::mint_into(&Pallet::<T>::account_id(), self.vault_funds).is_ok(),
+					"the vault funds must be at least the existential deposit."
+				);
+			}
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// The assets get staked successfully
-		Staked(T::AccountId, BalanceOf<T>),
-		/// The assets got unstaked successfully
-		UnStaked(T::AccountId, BalanceOf<T>),
+		/// The assets get staked successfully. \[who, staked_amount, liquid_amount\]
+		Staked(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// The assets got unstaked successfully. \[who, liquid_amount, staked_amount\]
+		UnStaked(T::AccountId, BalanceOf<T>, BalanceOf<T>),
 		/// The amount will be unlocked at target era
 		Unlocked(BalanceOf<T>, EraIndex),
 		/// Claim user's unbonded staking assets
 		ClaimedFor(T::AccountId, BalanceOf<T>),
 		/// the reward will be distributed after era
 		Rewarded(T::AccountId),
-		/// the reward has been successfully received
-		DerivativeReceived(T::AccountId),
+		/// The vault's era reward was added to the pool backing the liquid currency
+		RewardCompounded(BalanceOf<T>),
+		/// The exchange rate between the staking and the liquid currency was updated
+		ExchangeRateUpdated(Rate),
+		/// The vault bonded the amount
+		VaultBonded(BalanceOf<T>),
+		/// The vault unbonded the amount
+		VaultUnbonded(BalanceOf<T>),
+		/// The validators targeted by the vault were updated. \[(validator, weight)\]
+		ValidatorTargetsUpdated(Vec<(T::AccountId, u32)>),
+		/// Rebalancing the vault failed at the start of the era
+		RebalanceFailed(EraIndex, DispatchError),
+		/// The liquid currency got redeemed out of the matching pool. The fee is the instant
+		/// unstake fee, the unstake fee is part of the collected fees of the era.
+		/// \[who, liquid_amount, received_amount, fee\]
+		InstantUnstaked(T::AccountId, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// A slash of the vault was taken out of the pool backing the liquid currency and the
+		/// pending redemptions. \[active_slashed, unlocking_slashed\]
+		Slashed(BalanceOf<T>, BalanceOf<T>),
+		/// The collection unlock tickets are minted in was set.
+		TicketCollectionSet(T::TicketCollectionId),
+		/// A pending redemption was minted as an unlock ticket. \[who, ticket, amount, era\]
+		TicketMinted(T::AccountId, TicketId, BalanceOf<T>, EraIndex),
+		/// An unlock ticket got redeemed by its holder. \[who, ticket, amount\]
+		TicketRedeemed(T::AccountId, TicketId, BalanceOf<T>),
+		/// The protocol commission on the vault's rewards was set.
+		CommissionSet(Perbill),
+		/// The stake and unstake fees were set. \[stake_fee, unstake_fee\]
+		FeesSet(Permill, Permill),
+		/// The protocol fees collected in an era were paid to the fee collector, in the liquid
+		/// currency. \[era, commission, stake_fees, unstake_fees\]
+		FeesCollected(EraIndex, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -127,13 +380,86 @@ pub mod pallet {
 		/// No account in era reward accounts
 		AccountNotInDerivativeReward,
 		/// Not enough stake to nominate
-    	CannotNominate,
+		CannotNominate,
+		/// The exchange rate is invalid
+		InvalidExchangeRate,
+		/// More validators than `MaxTargets` were given
+		TooManyTargets,
+		/// None of the validator targets has a weight
+		NoTargets,
+		/// The stakes of this era can't cover the instant unstake
+		NotEnoughMatchable,
+		/// Too many pending unlock chunks, the matured ones have to be claimed first
+		TooManyUnlockings,
+		/// No collection was set to mint unlock tickets in
+		NoTicketCollection,
+		/// The ticket collection is not owned by the pallet account
+		TicketCollectionNotOwned,
+		/// The ticket collection can't change while tickets are outstanding
+		TicketsOutstanding,
+		/// There is no such unlock ticket
+		UnknownTicket,
+		/// Only the holder of an unlock ticket can redeem it
+		NotTicketOwner,
+		/// The commission is above `MaxCommission`
+		CommissionTooHigh,
+		/// The fee is above `MaxFee`
+		FeeTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Bond newly staked and unbond unstaked funds once per era.
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let current_era = CurrentEra::<T>::get().unwrap_or(0);
+			let last_era = LastRebalancedEra::<T>::get();
+			if last_era == Some(current_era) {
+				return T::DbWeight::get().reads(2);
+			}
+			LastRebalancedEra::<T>::put(current_era);
+			if let Some(last_era) = last_era {
+				let fees = EraFees::<T>::take();
+				if !fees.is_zero() {
+					Self::deposit_event(
+						Event::<T>::FeesCollected(
+							last_era,
+							fees.commission,
+							fees.stake_fees,
+							fees.unstake_fees
+						)
+					);
+				}
+			}
+			if let Err(e) = MatchingPool::<T>::try_mutate(|p| p.settle()) {
+				log::error!(
+					target: "runtime::liquid-staking",
+					"failed to settle the matching pool in era {}: {:?}",
+					current_era,
+					e
+				);
+			}
+			if let Err(e) = Self::do_rebalance(current_era) {
+				log::error!(
+					target: "runtime::liquid-staking",
+					"failed to rebalance the vault in era {}: {:?}",
+					current_era,
+					e
+				);
+				Self::deposit_event(Event::<T>::RebalanceFailed(current_era, e));
+			}
+			<T as Config>::WeightInfo::rebalance()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(1)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::stake())]
 		pub fn stake(
 			origin: OriginFor<T>,
 			#[pallet::compact] amount: BalanceOf<T>
@@ -141,60 +467,58 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 			ensure!(amount >= T::MinStake::get(), Error::<T>::StakeTooSmall);
 			let liquid_currency = Self::liquid_currency()?;
-			T::Assets::mint_into(liquid_currency, &who, amount)?;
-			Self::bonded_account(who.clone())?;
+			let minted = Self::staking_to_liquid(amount).ok_or(Error::<T>::InvalidExchangeRate)?;
+			let fee = Self::chargeable_fee(
+				liquid_currency,
+				Self::stake_fee().mul_ceil(minted),
+				Provenance::Minted
+			);
+			let liquid_amount = minted.saturating_sub(fee);
+			ensure!(!liquid_amount.is_zero(), Error::<T>::StakeTooSmall);
 			T::Balances::transfer(&who, &Self::account_id(), amount, Expendable)?;
-			Self::update_share(who.clone(), amount)?;
+			T::Assets::mint_into(liquid_currency, &who, liquid_amount)?;
+			if !fee.is_zero() {
+				T::Assets::mint_into(liquid_currency, &T::FeeCollector::get(), fee)?;
+				EraFees::<T>::mutate(|fees| fees.stake_fees = fees.stake_fees.saturating_add(fee));
+			}
 			MatchingPool::<T>::try_mutate(|p| -> DispatchResult { p.add_stake_amount(amount) })?;
-			Self::deposit_event(Event::<T>::Staked(who, amount.into()));
+			Self::do_update_exchange_rate()?;
+			Self::deposit_event(Event::<T>::Staked(who, amount, liquid_amount));
 			Ok(().into())
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake())]
 		pub fn unstake(
 			origin: OriginFor<T>,
-			#[pallet::compact] amount: BalanceOf<T>
+			#[pallet::compact] liquid_amount: BalanceOf<T>
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin.clone())?;
-			let staked_accounts = StakedAccounts::<T>::get();
-			ensure!(staked_accounts.contains(&who), Error::<T>::NotStaked);
-			T::Assets::burn_from(Self::liquid_currency()?, &who, amount, BestEffort, Polite)?;
-			let staked_amount = AccountStake::<T>::get(who.clone()).unwrap_or(0);
-			ensure!(amount <= staked_amount, Error::<T>::InsufficientBalance);
-			let new_amount = staked_amount - amount;
-			if new_amount.is_zero() {
-				let mut account = StakedAccounts::<T>::get();
-				if let Some(index) = account.iter().position(|x| *x == who) {
-					account.remove(index);
-					StakedAccounts::<T>::put(&account);
-				}
-				AccountStake::<T>::remove(who.clone());
-			} else {
-				AccountStake::<T>::insert(who.clone(), new_amount);
-			}
+			let (amount, share) = Self::do_unstake(&who, liquid_amount)?;
 			Unlockings::<T>::try_mutate(&who, |b| -> DispatchResult {
 				let mut chunks = b.take().unwrap_or_default();
 				let target_era = Self::target_era();
 				if let Some(chunk) = chunks.last_mut().filter(|chunk| chunk.era == target_era) {
-					chunk.value = chunk.value.saturating_add(amount);
+					chunk.value = chunk.value.saturating_add(share);
 				} else {
-					chunks.push(UnlockChunk {
-						value: amount,
-						era: target_era,
-					});
+					chunks
+						.try_push(UnlockChunk {
+							value: share,
+							era: target_era,
+						})
+						.map_err(|_| Error::<T>::TooManyUnlockings)?;
 				}
 				*b = Some(chunks);
 				Self::deposit_event(Event::<T>::Unlocked(amount, target_era));
 				Ok(())
 			})?;
-			MatchingPool::<T>::try_mutate(|p| p.add_unstake_amount(amount))?;
-			Self::deposit_event(Event::<T>::UnStaked(who, amount));
+			Self::do_update_exchange_rate()?;
+			Self::deposit_event(Event::<T>::UnStaked(who, liquid_amount, amount));
 			Ok(().into())
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_for())]
 		pub fn claim_for(
 			origin: OriginFor<T>,
 			dest: <T::Lookup as StaticLookup>::Source
@@ -204,20 +528,20 @@ pub mod pallet {
 			let current_era = CurrentEra::<T>::get().unwrap_or(0);
 
 			Unlockings::<T>::try_mutate_exists(&who, |b| -> DispatchResult {
-				let mut amount: BalanceOf<T> = Zero::zero();
+				let mut share: BalanceOf<T> = Zero::zero();
 				let chunks = b.as_mut().ok_or(Error::<T>::NoUnlockings)?;
 				chunks.retain(|chunk| {
 					if chunk.era > current_era {
 						true
 					} else {
-						amount += chunk.value;
+						share += chunk.value;
 						false
 					}
 				});
-				if amount.is_zero() {
+				if share.is_zero() {
 					return Err(Error::<T>::NothingToClaim.into());
 				}
-				Self::do_claim_for(&who, amount)?;
+				let amount = Self::do_claim_for(&who, share)?;
 
 				if chunks.is_empty() {
 					*b = None;
@@ -230,102 +554,85 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::bond())]
 		pub fn bond(origin: OriginFor<T>, balance: BalanceOf<T>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			<Bonds<T>>::insert(&sender, balance);
+			T::StrategyOrigin::ensure_origin(origin)?;
 			pallet_staking::Pallet::<T>::bond(
-				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id().clone())),
+				Self::vault_origin(),
 				balance.into(),
-				pallet_staking::RewardDestination::Account(Self::account_id())
+				RewardDestination::Account(Self::account_id())
 			)?;
+			Self::deposit_event(Event::<T>::VaultBonded(balance));
 			Ok(())
 		}
 
 		#[pallet::call_index(5)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::unbond())]
 		pub fn unbond(origin: OriginFor<T>, balance: BalanceOf<T>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			let mut bonded_amount = <Bonds<T>>::get(&sender).ok_or(Error::<T>::NotBonded)?;
-            ensure!(bonded_amount >= balance, Error::<T>::InsufficientBonded);
-			pallet_staking::Pallet::<T>::unbond(
-				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id().clone())),
-				balance.into()
-			);
-			bonded_amount -= balance;
-            if bonded_amount.is_zero() {
-            // If the entire bond is unbonded, remove the record from storage
-            <Bonds<T>>::remove(&sender);
-           } else {
-            // Update the bond amount in storage
-			<Bonds<T>>::mutate(&sender, |bond_amount| *bond_amount = Some(bonded_amount));
-           }
-           Ok(())
-		}
-
-		
+			T::StrategyOrigin::ensure_origin(origin)?;
+			pallet_staking::Pallet::<T>
+				::unbond(Self::vault_origin(), balance.into())
+				.map_err(|e| e.error)?;
+			Self::deposit_event(Event::<T>::VaultUnbonded(balance));
+			Ok(())
+		}
+
 		#[pallet::call_index(6)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn withdraw_unbonded(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-			pallet_staking::Pallet::<T>::withdraw_unbonded(
-				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id())),
-				0
-			)
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::StrategyOrigin::ensure_origin(origin)?;
+			pallet_staking::Pallet::<T>::withdraw_unbonded(Self::vault_origin(), 0)
 		}
 
+		/// Set the validators the vault nominates together with their target weight, and
+		/// nominate them right away if the vault is bonded.
 		#[pallet::call_index(7)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::nominate(targets.len() as u32))]
 		pub fn nominate(
 			origin: OriginFor<T>,
-			targets: Vec<AccountIdLookupOf<T>>
+			targets: Vec<(AccountIdLookupOf<T>, u32)>
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-			let staked_accounts = StakedAccounts::<T>::get();
-			ensure!(staked_accounts.contains(&who), Error::<T>::NotStaked);
-			// Access the `AccountStake` storage
-			let account_stake_storage = <AccountStake<T>>::iter();
-			// Iterate through all entries and sum up the stake amounts
-			let mut total_staked_amount = 0;
-			for (_, stake) in account_stake_storage {
-				if let stake_amount = stake {
-					total_staked_amount += stake_amount;
-				}
+			T::StrategyOrigin::ensure_origin(origin)?;
+			let targets = targets
+				.into_iter()
+				.map(|(target, weight)| Ok((T::Lookup::lookup(target)?, weight)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			ensure!(
+				targets.iter().any(|(_, weight)| !weight.is_zero()),
+				Error::<T>::NoTargets
+			);
+			let bounded: BoundedVec<_, T::MaxTargets> = targets
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyTargets)?;
+			ValidatorTargets::<T>::put(bounded);
+			Self::deposit_event(Event::<T>::ValidatorTargetsUpdated(targets));
+			if pallet_staking::Ledger::<T>::contains_key(Self::account_id()) {
+				Self::do_nominate()?;
 			}
-			let min_stake = pallet_staking::MinNominatorBond::<T>::get();
-			ensure!(total_staked_amount >= min_stake.into() , Error::<T>:: CannotNominate);
-			pallet_staking::Pallet::<T>::nominate(
-				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id())),
-				targets
-			)?;
 			Ok(())
 		}
 
 		#[pallet::call_index(8)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_reward(T::MaxTargets::get()))]
 		pub fn claim_reward(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let staked_accounts = StakedAccounts::<T>::get();
-			ensure!(staked_accounts.contains(&who), Error::<T>::NotStaked);
-			let mut era_reward_accounts = EraDerivativeReward::<T>::get().unwrap_or_else(Vec::new);
-			if era_reward_accounts.is_empty() {
-				era_reward_accounts.push(who.clone());
-				EraDerivativeReward::<T>::put(era_reward_accounts.clone());
-				if let Some(nominator) = pallet_staking::Nominators::<T>::get(Self::account_id()) {
-					for target in &nominator.targets {
-						pallet_reward::Pallet::<T>::get_rewards(
-							T::RuntimeOrigin::from(
-								frame_system::RawOrigin::Signed(Self::account_id())
-							),
-							target.clone()
-						)?;
-					}
-				} else {
-					return Ok(());
+			Self::ensure_staked(&who)?;
+			let nominator = pallet_staking::Nominators::<T>
+				::get(Self::account_id())
+				.ok_or(Error::<T>::NotBonded)?;
+			let queued_validators = EraRewardsVault::<T>::get().unwrap_or_default();
+			for target in &nominator.targets {
+				if
+					queued_validators.contains(target) ||
+					ValidatorRewardAccounts::<T>::get(target).is_zero()
+				{
+					continue;
 				}
-			} else {
-				ensure!(!era_reward_accounts.contains(&who), Error::<T>::WaitTheEraToComplete);
-				era_reward_accounts.push(who.clone());
-				EraDerivativeReward::<T>::put(era_reward_accounts.clone());
+				pallet_reward::Pallet::<T>::get_rewards(
+					T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id())),
+					target.clone()
+				)?;
 			}
 			Self::deposit_event(Event::<T>::Rewarded(who));
 
@@ -333,25 +640,174 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(9)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn bond_extra(_origin: OriginFor<T>, extra_balance: BalanceOf<T>) -> DispatchResult {
-			pallet_staking::Pallet::<T>::bond_extra(
-				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id().clone())),
-				extra_balance.into()
-			)?;
+		#[pallet::weight(<T as Config>::WeightInfo::bond_extra())]
+		pub fn bond_extra(origin: OriginFor<T>, extra_balance: BalanceOf<T>) -> DispatchResult {
+			T::StrategyOrigin::ensure_origin(origin)?;
+			pallet_staking::Pallet::<T>::bond_extra(Self::vault_origin(), extra_balance.into())?;
+			Self::deposit_event(Event::<T>::VaultBonded(extra_balance));
 			Ok(())
 		}
 
 		#[pallet::call_index(10)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::rebond(T::MaxUnlockingChunks::get()))]
 		pub fn rebond(origin: OriginFor<T>, balance: BalanceOf<T>) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
-			let staked_accounts = StakedAccounts::<T>::get();
-			ensure!(staked_accounts.contains(&who), Error::<T>::NotStaked);
-			pallet_staking::Pallet::<T>::rebond(
-				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id().clone())),
-				balance.into()
-			)
+			T::StrategyOrigin::ensure_origin(origin)?;
+			pallet_staking::Pallet::<T>::rebond(Self::vault_origin(), balance.into())
+		}
+
+		/// Redeem liquid currency right away against the stakes of the current era, paying
+		/// `InstantUnstakeFee` instead of waiting for the bonding duration.
+		///
+		/// The unstake fee is paid to the fee collector first, like `unstake` does. The instant
+		/// unstake fee is then taken from what the rest is worth and stays in the pool.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::instant_unstake())]
+		pub fn instant_unstake(
+			origin: OriginFor<T>,
+			#[pallet::compact] liquid_amount: BalanceOf<T>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let liquid_currency = Self::liquid_currency()?;
+			ensure!(!liquid_amount.is_zero(), Error::<T>::NothingToClaim);
+			ensure!(
+				T::Assets::balance(liquid_currency, &who) >= liquid_amount,
+				Error::<T>::InsufficientBalance
+			);
+			let protocol_fee = Self::chargeable_fee(
+				liquid_currency,
+				Self::unstake_fee().mul_ceil(liquid_amount),
+				Provenance::Extant
+			);
+			let redeemed = liquid_amount.saturating_sub(protocol_fee);
+			let amount = Self::liquid_to_staking(redeemed).ok_or(
+				Error::<T>::InvalidExchangeRate
+			)?;
+			let fee = T::InstantUnstakeFee::get().mul_ceil(amount);
+			let received = amount.saturating_sub(fee);
+			ensure!(!received.is_zero(), Error::<T>::NothingToClaim);
+			ensure!(
+				MatchingPool::<T>::get().matchable()? >= received,
+				Error::<T>::NotEnoughMatchable
+			);
+
+			if !protocol_fee.is_zero() {
+				T::Assets::transfer(
+					liquid_currency,
+					&who,
+					&T::FeeCollector::get(),
+					protocol_fee,
+					Expendable
+				)?;
+				EraFees::<T>::mutate(|fees| {
+					fees.unstake_fees = fees.unstake_fees.saturating_add(protocol_fee)
+				});
+			}
+			T::Assets::burn_from(liquid_currency, &who, redeemed, Exact, Polite)?;
+			T::Balances::transfer(&Self::account_id(), &who, received, Expendable)?;
+			MatchingPool::<T>::try_mutate(|p| p.add_unstake_amount(received))?;
+			Self::do_update_exchange_rate()?;
+			Self::deposit_event(Event::<T>::InstantUnstaked(who, liquid_amount, received, fee));
+			Ok(())
+		}
+
+		/// Set the collection unlock tickets get minted in. It has to be owned by the pallet
+		/// account, and can't change while tickets are outstanding.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_ticket_collection())]
+		pub fn set_ticket_collection(
+			origin: OriginFor<T>,
+			collection: T::TicketCollectionId
+		) -> DispatchResult {
+			T::StrategyOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::Nfts::collection_owner(&collection) == Some(Self::account_id()),
+				Error::<T>::TicketCollectionNotOwned
+			);
+			ensure!(
+				UnlockTickets::<T>::iter_keys().next().is_none(),
+				Error::<T>::TicketsOutstanding
+			);
+			TicketCollection::<T>::put(collection.clone());
+			Self::deposit_event(Event::<T>::TicketCollectionSet(collection));
+			Ok(())
+		}
+
+		/// Unstake like `unstake` does, but hand the pending redemption out as an unlock ticket
+		/// NFT. Whoever holds the ticket once the target era is reached can redeem it with
+		/// `redeem_ticket`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake_to_ticket())]
+		pub fn unstake_to_ticket(
+			origin: OriginFor<T>,
+			#[pallet::compact] liquid_amount: BalanceOf<T>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = Self::ticket_collection().ok_or(Error::<T>::NoTicketCollection)?;
+			let (amount, share) = Self::do_unstake(&who, liquid_amount)?;
+			let era = Self::target_era();
+			let ticket = NextTicketId::<T>::get();
+			NextTicketId::<T>::put(ticket.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			T::Nfts::mint_into(&collection, &ticket, &who, &Default::default(), false)?;
+			T::Nfts::set_attribute(&collection, &ticket, TICKET_AMOUNT_KEY, &amount.encode())?;
+			T::Nfts::set_attribute(&collection, &ticket, TICKET_ERA_KEY, &era.encode())?;
+			UnlockTickets::<T>::insert(ticket, UnlockChunk { value: share, era });
+			Self::do_update_exchange_rate()?;
+			Self::deposit_event(Event::<T>::TicketMinted(who.clone(), ticket, amount, era));
+			Self::deposit_event(Event::<T>::UnStaked(who, liquid_amount, amount));
+			Ok(())
+		}
+
+		/// Redeem a matured unlock ticket, burning it and paying its amount out to its holder.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_ticket())]
+		pub fn redeem_ticket(origin: OriginFor<T>, ticket: TicketId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = Self::ticket_collection().ok_or(Error::<T>::NoTicketCollection)?;
+			let chunk = UnlockTickets::<T>::get(ticket).ok_or(Error::<T>::UnknownTicket)?;
+			ensure!(
+				T::Nfts::owner(&collection, &ticket) == Some(who.clone()),
+				Error::<T>::NotTicketOwner
+			);
+			ensure!(
+				chunk.era <= CurrentEra::<T>::get().unwrap_or(0),
+				Error::<T>::WaitTheEraToComplete
+			);
+			Self::burn_ticket(&collection, ticket)?;
+			UnlockTickets::<T>::remove(ticket);
+			let amount = Self::do_claim_for(&who, chunk.value)?;
+			Self::deposit_event(Event::<T>::TicketRedeemed(who, ticket, amount));
+			Ok(())
+		}
+
+		/// Set the share of the vault's rewards the protocol takes as its commission, at most
+		/// `MaxCommission`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_commission())]
+		pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			T::FeeOrigin::ensure_origin(origin)?;
+			ensure!(commission <= T::MaxCommission::get(), Error::<T>::CommissionTooHigh);
+			Commission::<T>::put(commission);
+			Self::deposit_event(Event::<T>::CommissionSet(commission));
+			Ok(())
+		}
+
+		/// Set the fees charged on stakes and on unstakes, each at most `MaxFee`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fees())]
+		pub fn set_fees(
+			origin: OriginFor<T>,
+			stake_fee: Permill,
+			unstake_fee: Permill
+		) -> DispatchResult {
+			T::FeeOrigin::ensure_origin(origin)?;
+			ensure!(
+				stake_fee <= T::MaxFee::get() && unstake_fee <= T::MaxFee::get(),
+				Error::<T>::FeeTooHigh
+			);
+			StakeFee::<T>::put(stake_fee);
+			UnstakeFee::<T>::put(unstake_fee);
+			Self::deposit_event(Event::<T>::FeesSet(stake_fee, unstake_fee));
+			Ok(())
 		}
 	}
 
@@ -365,100 +821,479 @@ pub mod pallet {
 		pub fn target_era() -> EraIndex {
 			pallet_staking::Pallet::<T>::current_era().unwrap_or(0) + T::BondingDuration::get() + 1
 		}
-		pub fn update_share(account: T::AccountId, amount: u128) -> DispatchResult {
-			let already_present_amount = AccountStake::<T>::get(account.clone()).unwrap_or(0);
-			let new_amount = already_present_amount + amount;
-			AccountStake::<T>::insert(account.clone(), new_amount);
+		/// Ensure `who` holds some of the liquid currency.
+		fn ensure_staked(who: &T::AccountId) -> DispatchResult {
+			let liquid_currency = Self::liquid_currency()?;
+			ensure!(!T::Assets::balance(liquid_currency, who).is_zero(), Error::<T>::NotStaked);
+			Ok(())
+		}
+		/// `fee` in the liquid currency if the fee collector can receive it, nothing otherwise:
+		/// fees too small to open an account of the liquid currency are waived.
+		fn chargeable_fee(
+			liquid_currency: AssetIdOf<T>,
+			fee: BalanceOf<T>,
+			provenance: Provenance
+		) -> BalanceOf<T> {
+			let collector = T::FeeCollector::get();
+			if
+				fee.is_zero() ||
+				T::Assets::can_deposit(liquid_currency, &collector, fee, provenance) !=
+					DepositConsequence::Success
+			{
+				return Zero::zero();
+			}
+			fee
+		}
+		/// Burn `liquid_amount` of the liquid currency of `who`, less the unstake fee paid to the
+		/// fee collector, and set the staking currency it is worth aside for a redemption.
+		/// Returns that amount and the share of the pending redemptions it is kept as.
+		fn do_unstake(
+			who: &T::AccountId,
+			liquid_amount: BalanceOf<T>
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let liquid_currency = Self::liquid_currency()?;
+			ensure!(!liquid_amount.is_zero(), Error::<T>::NothingToClaim);
+			ensure!(
+				T::Assets::balance(liquid_currency, who) >= liquid_amount,
+				Error::<T>::InsufficientBalance
+			);
+			let fee = Self::chargeable_fee(
+				liquid_currency,
+				Self::unstake_fee().mul_ceil(liquid_amount),
+				Provenance::Extant
+			);
+			let redeemed = liquid_amount.saturating_sub(fee);
+			let amount = Self::liquid_to_staking(redeemed).ok_or(
+				Error::<T>::InvalidExchangeRate
+			)?;
+			let share = Self::unlocking_share(amount).ok_or(Error::<T>::InvalidExchangeRate)?;
+			if !fee.is_zero() {
+				T::Assets::transfer(liquid_currency, who, &T::FeeCollector::get(), fee, Expendable)?;
+				EraFees::<T>::mutate(|fees| {
+					fees.unstake_fees = fees.unstake_fees.saturating_add(fee)
+				});
+			}
+			T::Assets::burn_from(liquid_currency, who, redeemed, Exact, Polite)?;
+			MatchingPool::<T>::try_mutate(|p| p.add_unstake_amount(amount))?;
+			TotalUnlocking::<T>::mutate(|total| *total = total.saturating_add(share));
+			Ok((amount, share))
+		}
+		/// Clear the attributes of an unlock ticket and burn it.
+		fn burn_ticket(collection: &T::TicketCollectionId, ticket: TicketId) -> DispatchResult {
+			T::Nfts::clear_attribute(collection, &ticket, TICKET_AMOUNT_KEY)?;
+			T::Nfts::clear_attribute(collection, &ticket, TICKET_ERA_KEY)?;
+			T::Nfts::burn(collection, &ticket, None)
+		}
+		/// Pay `who` out what `share` of the pending redemptions is worth. Returns that amount.
+		fn do_claim_for(
+			who: &T::AccountId,
+			share: BalanceOf<T>
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let amount = Self::unlocking_value(share);
+			T::Balances::transfer(&Self::account_id(), who, amount, Expendable)?;
+			TotalUnlocking::<T>::mutate(|total| *total = total.saturating_sub(share));
+			Ok(amount)
+		}
+		/// The share of the pending redemptions `amount` is kept as, rounding down.
+		fn unlocking_share(amount: BalanceOf<T>) -> Option<BalanceOf<T>> {
+			multiply_by_rational_with_rounding(
+				amount,
+				Rate::DIV,
+				Self::unlocking_slash_factor().into_inner(),
+				Rounding::Down
+			)
+		}
+		/// The amount of the staking currency `share` of the pending redemptions is worth,
+		/// rounding down.
+		pub fn unlocking_value(share: BalanceOf<T>) -> BalanceOf<T> {
+			Self::unlocking_slash_factor().saturating_mul_int(share)
+		}
+		/// The amount of the staking currency waiting in `Unlockings` and `UnlockTickets` to be
+		/// claimed.
+		pub fn total_unlocking() -> BalanceOf<T> {
+			Self::unlocking_value(TotalUnlocking::<T>::get())
+		}
+		fn vault_origin() -> T::RuntimeOrigin {
+			T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(Self::account_id()))
+		}
+
+		/// Nominate the validator targets with a non-zero weight, heaviest first.
+		fn do_nominate() -> DispatchResult {
+			let mut targets = ValidatorTargets::<T>
+				::get()
+				.into_iter()
+				.filter(|(_, weight)| !weight.is_zero())
+				.collect::<Vec<_>>();
+			ensure!(!targets.is_empty(), Error::<T>::NoTargets);
+			targets.sort_by(|a, b| b.1.cmp(&a.1));
+			pallet_staking::Pallet::<T>::nominate(
+				Self::vault_origin(),
+				targets
+					.into_iter()
+					.map(|(target, _)| T::Lookup::unlookup(target))
+					.collect()
+			)
+		}
+
+		/// Bring the vault's active bond in line with the matching pool.
+		///
+		/// Funds the vault owes to unstakers, and which are not already unlocking in
+		/// `pallet_staking`, are never bonded, so `claim_for` can always be paid out.
+		fn do_rebalance(current_era: EraIndex) -> DispatchResult {
+			let vault = Self::account_id();
+			let target: BalanceOf<T> = MatchingPool::<T>::get().total_active()?;
+			let ledger = pallet_staking::Ledger::<T>::get(&vault);
+
+			if let Some(ledger) = &ledger {
+				if ledger.unlocking.iter().any(|chunk| chunk.era <= current_era) {
+					pallet_staking::Pallet::<T>
+						::withdraw_unbonded(Self::vault_origin(), 0)
+						.map_err(|e| e.error)?;
+				}
+			}
+			let ledger = pallet_staking::Ledger::<T>::get(&vault);
+			let (active, total, unlocking): (BalanceOf<T>, BalanceOf<T>, BalanceOf<T>) = ledger
+				.as_ref()
+				.map(|l| {
+					let unlocking = l.unlocking
+						.iter()
+						.fold(0u128, |acc, chunk| acc.saturating_add(chunk.value.into()));
+					(l.active.into(), l.total.into(), unlocking)
+				})
+				.unwrap_or_default();
+			let reserved = Self::total_unlocking().saturating_sub(unlocking);
+			let bondable = <T::Balances as Inspect<T::AccountId>>
+				::balance(&vault)
+				.saturating_sub(total)
+				.saturating_sub(reserved);
+
+			if active < target {
+				let amount = target.saturating_sub(active).min(bondable);
+				if ledger.is_none() {
+					if amount < <T::Balances as Inspect<T::AccountId>>::minimum_balance() {
+						return Ok(());
+					}
+					pallet_staking::Pallet::<T>::bond(
+						Self::vault_origin(),
+						amount.into(),
+						RewardDestination::Account(vault.clone())
+					)?;
+					Self::deposit_event(Event::<T>::VaultBonded(amount));
+				} else if !amount.is_zero() {
+					pallet_staking::Pallet::<T>::bond_extra(Self::vault_origin(), amount.into())?;
+					Self::deposit_event(Event::<T>::VaultBonded(amount));
+				}
+			} else if active > target {
+				let amount = active.saturating_sub(target);
+				pallet_staking::Pallet::<T>
+					::unbond(Self::vault_origin(), amount.into())
+					.map_err(|e| e.error)?;
+				Self::deposit_event(Event::<T>::VaultUnbonded(amount));
+			}
+
+			if
+				!pallet_staking::Nominators::<T>::contains_key(&vault) &&
+				pallet_staking::Ledger::<T>::contains_key(&vault) &&
+				!Self::validator_targets().is_empty()
+			{
+				Self::do_nominate()?;
+			}
+			Ok(())
+		}
+
+		/// Spread a slash of the vault over the pool backing the liquid currency and the pending
+		/// redemptions, in proportion to their size.
+		///
+		/// The vault can't tell which of its bonded funds back which holder, so every holder and
+		/// every unstaker waiting for a redemption loses the same share. The pending redemptions
+		/// are slashed through `UnlockingSlashFactor`, so this takes constant time however many
+		/// there are.
+		pub(crate) fn do_slash(loss: BalanceOf<T>) -> DispatchResult {
+			let total_active = MatchingPool::<T>::get().total_active()?;
+			let total_unlocking = Self::total_unlocking();
+			let backing = total_active.saturating_add(total_unlocking);
+			if backing.is_zero() {
+				return Ok(());
+			}
+			let ratio = Perquintill::from_rational(loss.min(backing), backing);
+			let active_slashed = ratio.mul_ceil(total_active).min(loss);
+			if !total_unlocking.is_zero() {
+				let remaining = Rate::saturating_from_rational(
+					ratio.left_from_one().deconstruct(),
+					Perquintill::ACCURACY
+				);
+				UnlockingSlashFactor::<T>::mutate(|factor| {
+					*factor = factor.saturating_mul(remaining)
+				});
+			}
+			let unlocking_slashed = total_unlocking.saturating_sub(Self::total_unlocking());
+			MatchingPool::<T>::try_mutate(|p| p.slash(active_slashed))?;
+			Self::do_update_exchange_rate()?;
+			Self::deposit_event(Event::<T>::Slashed(active_slashed, unlocking_slashed));
 			Ok(())
 		}
-		pub fn bonded_account(who: T::AccountId) -> DispatchResult {
-			let mut bonded_accounts = StakedAccounts::<T>::get();
-			if bonded_accounts.contains(&who) {
+
+		/// Convert an amount of the staking currency into the liquid currency at the current
+		/// exchange rate, rounding down.
+		///
+		/// The amount is divided by the rate in a single step, so it is rounded once only.
+		pub fn staking_to_liquid(amount: BalanceOf<T>) -> Option<BalanceOf<T>> {
+			multiply_by_rational_with_rounding(
+				amount,
+				Rate::DIV,
+				Self::exchange_rate().into_inner(),
+				Rounding::Down
+			)
+		}
+
+		/// Convert an amount of the liquid currency into the staking currency at the current
+		/// exchange rate, rounding down.
+		pub fn liquid_to_staking(liquid_amount: BalanceOf<T>) -> Option<BalanceOf<T>> {
+			Self::exchange_rate().checked_mul_int(liquid_amount)
+		}
+
+		/// Recompute the exchange rate from the matching pool and the liquid currency issuance.
+		///
+		/// The rate is left untouched while no liquid currency is in circulation, so the first
+		/// staker after the pool got drained stakes at the last known rate.
+		pub fn do_update_exchange_rate() -> DispatchResult {
+			let issuance = T::Assets::total_issuance(Self::liquid_currency()?);
+			if issuance.is_zero() {
+				return Ok(());
+			}
+			let total_active = MatchingPool::<T>::get().total_active()?;
+			let rate = Rate::checked_from_rational(total_active, issuance).ok_or(
+				ArithmeticError::Overflow
+			)?;
+			if rate.is_zero() || rate == Self::exchange_rate() {
 				return Ok(());
 			}
-			bonded_accounts.push(who.clone());
-			StakedAccounts::<T>::put(bonded_accounts);
+			ExchangeRate::<T>::put(rate);
+			Self::deposit_event(Event::<T>::ExchangeRateUpdated(rate));
 			Ok(())
 		}
-		fn do_claim_for(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
-			T::Balances::transfer(&Self::account_id(), who, amount, Expendable)?;
+
+		/// The current exchange rate, for the runtime API.
+		pub fn api_exchange_rate() -> Rate {
+			Self::exchange_rate()
+		}
+
+		/// The liquid staking position of `who`, for the runtime API.
+		pub fn api_staking_position(who: T::AccountId) -> StakingPosition<BalanceOf<T>> {
+			let liquid_balance = Self::liquid_currency()
+				.map(|liquid_currency| T::Assets::balance(liquid_currency, &who))
+				.unwrap_or_default();
+			let staked_amount = Self::liquid_to_staking(liquid_balance).unwrap_or_default();
+			let current_era = CurrentEra::<T>::get().unwrap_or(0);
+			let mut claimable: BalanceOf<T> = Zero::zero();
+			let unlocking = Unlockings::<T>
+				::get(&who)
+				.unwrap_or_default()
+				.into_iter()
+				.map(|chunk| {
+					let value = Self::unlocking_value(chunk.value);
+					if chunk.era <= current_era {
+						claimable = claimable.saturating_add(value);
+					}
+					UnlockingInfo { value, era: chunk.era }
+				})
+				.collect();
+			StakingPosition {
+				liquid_balance,
+				staked_amount,
+				unlocking,
+				claimable,
+				estimated_era_reward: pallet_reward::Pallet::<T>::estimate_era_reward(
+					staked_amount
+				),
+			}
+		}
+
+		/// Check the solvency of the pool.
+		///
+		/// * `TotalUnlocking` is the sum of the pending `Unlockings`, none of which is empty,
+		///   and of the outstanding `UnlockTickets`, each of which has its NFT.
+		/// * Slashes never raise `UnlockingSlashFactor` above one.
+		/// * The matching pool never reserves more than it holds.
+		/// * The exchange rate is the pool's active stake per unit of the liquid currency.
+		/// * The vault holds, bonded or not, at least the active stake plus the pending
+		///   unlockings, and its `pallet_staking` ledger is backed by its balance.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), TryRuntimeError> {
+			let mut total_unlocking: BalanceOf<T> = Zero::zero();
+			for chunks in Unlockings::<T>::iter_values() {
+				ensure!(!chunks.is_empty(), "an account has an empty list of unlockings");
+				total_unlocking = chunks
+					.iter()
+					.fold(total_unlocking, |acc, chunk| acc.saturating_add(chunk.value));
+			}
+			for (ticket, chunk) in UnlockTickets::<T>::iter() {
+				let collection = Self::ticket_collection().ok_or(
+					"unlock tickets are outstanding without a ticket collection"
+				)?;
+				ensure!(
+					T::Nfts::owner(&collection, &ticket).is_some(),
+					"an unlock ticket has no NFT"
+				);
+				total_unlocking = total_unlocking.saturating_add(chunk.value);
+			}
+			ensure!(
+				TotalUnlocking::<T>::get() == total_unlocking,
+				"TotalUnlocking does not match the pending unlockings"
+			);
+			ensure!(
+				Self::unlocking_slash_factor() <= Rate::one(),
+				"the unlocking slash factor is above one"
+			);
+			let total_unlocking = Self::total_unlocking();
+
+			let pool = MatchingPool::<T>::get();
+			ensure!(
+				pool.total_stake_amount.reserved <= pool.total_stake_amount.total &&
+					pool.total_unstake_amount.reserved <= pool.total_unstake_amount.total,
+				"the matching pool reserves more than it holds"
+			);
+			let total_active = pool.total_active()?;
+
+			let issuance = T::Assets::total_issuance(Self::liquid_currency()?);
+			if !issuance.is_zero() && !total_active.is_zero() {
+				ensure!(
+					Rate::checked_from_rational(total_active, issuance) ==
+						Some(Self::exchange_rate()),
+					"the exchange rate does not match the pool"
+				);
+			}
+
+			let vault = Self::account_id();
+			let vault_balance = <T::Balances as Inspect<_>>::total_balance(&vault);
+			ensure!(
+				vault_balance >= total_active.saturating_add(total_unlocking),
+				"the vault does not hold the active stake and the pending unlockings"
+			);
+			if let Some(ledger) = pallet_staking::Ledger::<T>::get(&vault) {
+				let bonded: BalanceOf<T> = ledger.total.into();
+				ensure!(bonded <= vault_balance, "the vault's bond exceeds its balance");
+			}
 			Ok(())
 		}
 
-		pub fn convert_f64_to_u128(value: f64) -> u128 {
-			let precision = 18;
-			let multiplier = (10u128).pow(precision);
-			let number = (value * (multiplier as f64)) as u128;
-			number
+		/// Add the vault's era reward to the pool, less the protocol commission.
+		///
+		/// The commission stays in the pool too, but backs liquid currency minted to the fee
+		/// collector at the rate the rest of the reward leads to, so it is the collector and not
+		/// the holders who earn it.
+		pub(crate) fn compound_reward(reward: BalanceOf<T>) -> DispatchResult {
+			let liquid_currency = Self::liquid_currency()?;
+			let commission = Self::commission().mul_floor(reward);
+			MatchingPool::<T>::try_mutate(|p| p.add_stake_amount(reward.saturating_sub(commission)))?;
+			Self::do_update_exchange_rate()?;
+			let fee = Self::staking_to_liquid(commission)
+				.map(|fee| Self::chargeable_fee(liquid_currency, fee, Provenance::Minted))
+				.unwrap_or_default();
+			if !fee.is_zero() {
+				T::Assets::mint_into(liquid_currency, &T::FeeCollector::get(), fee)?;
+				EraFees::<T>::mutate(|fees| fees.commission = fees.commission.saturating_add(fee));
+			}
+			MatchingPool::<T>::try_mutate(|p| p.add_stake_amount(commission))?;
+			Self::do_update_exchange_rate()?;
+			Self::deposit_event(Event::<T>::RewardCompounded(reward));
+			Ok(())
 		}
 
-		pub fn calculate_reward(share: u128, total_stake: u128, reward: u128) -> f64 {
-			let precision = 18;
-			let scaled_share = share / (10u128).pow(precision);
-			let scaled_total_stake: u64 = (total_stake / (10u128).pow(precision)) as u64;
-			let division: f64 = ((scaled_share as f64) / (scaled_total_stake as f64)) as f64;
-			let scaled_reward: f64 = ((reward as f64) / ((10u128).pow(precision) as f64)) as f64;
-			let total_reward = division * scaled_reward;
-			total_reward
+		/// The part of the vault's nominator reward that was actually paid out this era.
+		///
+		/// `pallet_reward` drops the `EraReward` entry of every validator it paid, so a target
+		/// still listing the vault as a nominator has not been paid.
+		pub(crate) fn paid_vault_reward() -> BalanceOf<T> {
+			let vault = Self::account_id();
+			let mut reward: BalanceOf<T> = Zero::zero();
+			if let Some(nominator) = pallet_staking::Nominators::<T>::get(&vault) {
+				for validator in &nominator.targets {
+					if EraReward::<T>::get(validator).contains(&vault) {
+						continue;
+					}
+					let validator_reward: u128 = NominatorRewardAccounts::<T>
+						::get(validator, &vault)
+						.into();
+					reward = reward.saturating_add(validator_reward);
+				}
+			}
+			reward
 		}
 	}
 }
 
 impl<T: Config> DerivativeRewardAccount<T::AccountId> for Pallet<T> {
 	fn derivative_reward_accounts() -> Vec<T::AccountId> {
-		EraDerivativeReward::<T>::get().unwrap_or_else(Vec::new)
+		let vault = Self::account_id();
+		if pallet_staking::Nominators::<T>::contains_key(&vault) {
+			Vec::from([vault])
+		} else {
+			Vec::new()
+		}
 	}
 
+	/// Compound the vault's era reward into the pool, raising the exchange rate for every
+	/// holder of the liquid currency.
 	fn claim_derivative(account: T::AccountId) -> DispatchResult {
-		let individual_stake = AccountStake::<T>::get(account.clone()).unwrap_or(0);
-		let pool = MatchingPool::<T>::get();
-		let total_stake = pool.total_stake_amount.total;
-		let mut nominator_reward :T::Balance = 0u128.into();
-		if let Some(nominator) = pallet_staking::Nominators::<T>::get(Self::account_id()) {
-			for validator in &nominator.targets {
-				let reward = NominatorRewardAccounts::<T>::get(validator,Self::account_id());
-				nominator_reward += reward;
-				}
+		ensure!(account == Self::account_id(), Error::<T>::AccountNotInDerivativeReward);
+		let reward = Self::paid_vault_reward();
+		if reward.is_zero() {
+			return Ok(());
 		}
-		let individual_reward = Self::calculate_reward(
-			individual_stake,
-			total_stake,
-			nominator_reward.into()
-		);
-		let liquid_currency = Self::liquid_currency()?;
-		let mut era_reward_accounts = EraDerivativeReward::<T>::get().unwrap_or_else(Vec::new);
-		if !era_reward_accounts.contains(&account) {
-			return Err(Error::<T>::AccountNotInDerivativeReward.into());
-		}
-		if let Some(index) = era_reward_accounts.iter().position(|a| a == &account.clone()) {
-			era_reward_accounts.remove(index);
-		}
-		let converted_reward = Self::convert_f64_to_u128(individual_reward);
-		EraDerivativeReward::<T>::put(era_reward_accounts);
-		T::Assets::mint_into(liquid_currency, &account, converted_reward)?;
-		Self::update_share(account.clone(), converted_reward)?;
-		Self::deposit_event(Event::<T>::DerivativeReceived(account));
-		Ok(())
+		Self::compound_reward(reward)
 	}
+	/// Drop the vault's rewards that were paid out and compounded. Rewards still listed in
+	/// `EraReward` were not paid yet, so they are kept for the next payout of the validator.
 	fn reset_reward() -> DispatchResult {
-		if let Some(nominator) = pallet_staking::Nominators::<T>::get(Self::account_id()) {
-		for validator in nominator.targets{
-		let mut nominators = EraReward::<T>::get(validator.clone());
-			if
-				let Some(index) = nominators
-					.iter()
-					.position(|nominator_account| nominator_account == &Self::account_id().clone())
-			{
-				nominators.remove(index);
+		let vault = Self::account_id();
+		if let Some(nominator) = pallet_staking::Nominators::<T>::get(&vault) {
+			for validator in nominator.targets {
+				if !EraReward::<T>::get(&validator).contains(&vault) {
+					pallet_reward::Pallet::<T>::remove_nominator_reward(&validator, &vault);
+				}
 			}
-			EraReward::<T>::insert(validator.clone(),nominators.clone());
-			NominatorRewardAccounts::<T>::remove(validator,Self::account_id());
 		}
-	}
 		Ok(())
 	}
 }
 
+impl<T: Config> OnStakingUpdate<T::AccountId, StakingBalanceOf<T>> for Pallet<T> {
+	/// Pass a slash of the vault on to the holders of the liquid currency.
+	///
+	/// `pallet_staking` calls this before it stores the slashed ledger, so the loss is the
+	/// difference between the stored ledger and the slashed amounts.
+	fn on_slash(
+		stash: &T::AccountId,
+		slashed_active: StakingBalanceOf<T>,
+		slashed_unlocking: &BTreeMap<EraIndex, StakingBalanceOf<T>>
+	) {
+		if stash != &Self::account_id() {
+			return;
+		}
+		let Some(ledger) = pallet_staking::Ledger::<T>::get(stash) else {
+			return;
+		};
+		let remaining = ledger.unlocking
+			.iter()
+			.fold(slashed_active, |acc, chunk| {
+				acc.saturating_add(*slashed_unlocking.get(&chunk.era).unwrap_or(&chunk.value))
+			});
+		let loss: BalanceOf<T> = ledger.total.saturating_sub(remaining).into();
+		if loss.is_zero() {
+			return;
+		}
+		if let Err(e) = Pallet::<T>::do_slash(loss) {
+			log::error!(
+				target: "runtime::liquid-staking",
+				"failed to pass a slash of {:?} on to the pool: {:?}",
+				loss,
+				e
+			);
+		}
+	}
+}
+
 impl<T: Config> StakingAccount<T::AccountId> for Pallet<T> {
 	fn staking_account() -> T::AccountId {
 		Self::account_id()
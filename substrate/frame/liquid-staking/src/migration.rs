@@ -0,0 +1,437 @@
+//! Storage migrations for the liquid staking pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{ GetStorageVersion, OnRuntimeUpgrade, StorageVersion },
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	#[storage_alias]
+	pub(crate) type AccountStake<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u128
+	>;
+
+	#[storage_alias]
+	pub(crate) type StakedAccounts<T: Config> = StorageValue<
+		Pallet<T>,
+		Vec<<T as frame_system::Config>::AccountId>
+	>;
+
+	#[storage_alias]
+	type EraDerivativeReward<T: Config> = StorageValue<
+		Pallet<T>,
+		Vec<<T as frame_system::Config>::AccountId>
+	>;
+
+	#[storage_alias]
+	pub(crate) type Unlockings<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Vec<UnlockChunk<BalanceOf<T>>>
+	>;
+
+	/// What the vault holds for the holders of the liquid currency: its bond, as recorded in its
+	/// `pallet_staking` ledger, and the rest of its balance, less the pending redemptions it owes
+	/// to unstakers and the existential deposit it has to keep.
+	pub(crate) fn vault_backing<T: Config>() -> BalanceOf<T> {
+		let vault = Pallet::<T>::account_id();
+		let bonded: BalanceOf<T> = pallet_staking::Ledger::<T>
+			::get(&vault)
+			.map_or_else(Zero::zero, |ledger| ledger.total.into());
+		// The bond stays in the balance of the vault, locked by `pallet_staking`.
+		let unbonded = <T::Balances as Inspect<_>>::total_balance(&vault).saturating_sub(bonded);
+		let pending = Unlockings::<T>
+			::iter_values()
+			.flatten()
+			.fold(BalanceOf::<T>::zero(), |acc, chunk| acc.saturating_add(chunk.value));
+		bonded
+			.saturating_add(unbonded)
+			.saturating_sub(pending)
+			.saturating_sub(<T::Balances as Inspect<_>>::minimum_balance())
+	}
+
+	/// Move from per-account stake bookkeeping to exchange-rate based share accounting.
+	///
+	/// The old `unstake` burned the liquid currency best-effort and paid out of `AccountStake`,
+	/// so it is `AccountStake` and not the liquid balance that recorded the claim of a holder.
+	/// Holders with less of the liquid currency than their stake get the difference minted, so
+	/// everyone keeps at least their recorded claim in shares. The matching pool is then set to
+	/// what the vault actually holds, and the exchange rate spreads that over the liquid
+	/// currency, so it never promises more than the vault has.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 1 {
+				log::info!(
+					target: "runtime::liquid-staking",
+					"MigrateToV1 should be removed, on-chain version is {:?}",
+					onchain
+				);
+				return T::DbWeight::get().reads(1);
+			}
+			let liquid_currency = match Pallet::<T>::liquid_currency() {
+				Ok(liquid_currency) => liquid_currency,
+				Err(e) => {
+					log::error!(
+						target: "runtime::liquid-staking",
+						"MigrateToV1 needs the liquid currency: {:?}",
+						e
+					);
+					return T::DbWeight::get().reads(1);
+				},
+			};
+
+			let mut reads = 4u64;
+			let mut writes = 4u64;
+			let mut accounts = 0u32;
+			let mut minted: BalanceOf<T> = Zero::zero();
+			for (who, staked) in AccountStake::<T>::drain() {
+				accounts += 1;
+				reads += 2;
+				writes += 1;
+				let shortfall = staked.saturating_sub(T::Assets::balance(liquid_currency, &who));
+				if shortfall.is_zero() {
+					continue;
+				}
+				match T::Assets::mint_into(liquid_currency, &who, shortfall) {
+					Ok(_) => {
+						minted = minted.saturating_add(shortfall);
+						writes += 2;
+					},
+					Err(e) =>
+						log::error!(
+							target: "runtime::liquid-staking",
+							"failed to mint the missing {:?} of the stake of {:?}: {:?}",
+							shortfall,
+							who,
+							e
+						),
+				}
+			}
+			StakedAccounts::<T>::kill();
+			EraDerivativeReward::<T>::kill();
+
+			let backing = vault_backing::<T>();
+			reads += Unlockings::<T>::iter_keys().count() as u64;
+			MatchingPool::<T>::mutate(|pool| {
+				pool.total_stake_amount.total = pool.total_unstake_amount.total.saturating_add(
+					backing
+				);
+			});
+			if let Err(e) = Pallet::<T>::do_update_exchange_rate() {
+				log::error!(
+					target: "runtime::liquid-staking",
+					"failed to derive the exchange rate: {:?}",
+					e
+				);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::liquid-staking",
+				"migrated {} staked accounts, minted {:?} missing shares, vault backing {:?}",
+				accounts,
+				minted,
+				backing
+			);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let stakes: Vec<(T::AccountId, u128)> = AccountStake::<T>::iter().collect();
+			Ok(stakes.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let stakes = <Vec<(T::AccountId, u128)> as Decode>
+				::decode(&mut &state[..])
+				.map_err(|_| "cannot decode the pre-upgrade state")?;
+			ensure!(AccountStake::<T>::iter().next().is_none(), "AccountStake was not drained");
+			let liquid_currency = Pallet::<T>::liquid_currency()?;
+			for (who, staked) in stakes {
+				ensure!(
+					T::Assets::balance(liquid_currency, &who) >= staked,
+					"a holder has less of the liquid currency than their stake"
+				);
+			}
+			let backing = vault_backing::<T>();
+			ensure!(
+				MatchingPool::<T>::get().total_active()? == backing,
+				"the matching pool does not match the vault backing"
+			);
+			let issuance = T::Assets::total_issuance(liquid_currency);
+			ensure!(
+				Pallet::<T>::exchange_rate().saturating_mul_int(issuance) <= backing,
+				"the exchange rate promises more than the vault holds"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"storage version was not bumped"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	#[storage_alias]
+	type Bonds<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u128
+	>;
+
+	/// The unlock chunks as they are stored before v3 bounds them by `MaxUnlockings`.
+	#[storage_alias]
+	pub(crate) type Unlockings<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Vec<UnlockChunk<BalanceOf<T>>>
+	>;
+
+	/// Drop the per-caller `Bonds` bookkeeping now that the vault is operated by
+	/// `StrategyOrigin`, and seed `TotalUnlocking` from the pending unlock chunks.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain != 1 {
+				log::info!(
+					target: "runtime::liquid-staking",
+					"MigrateToV2 should be removed, on-chain version is {:?}",
+					onchain
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 2u64;
+			for _ in Bonds::<T>::drain() {
+				reads += 1;
+				writes += 1;
+			}
+			let mut total_unlocking: BalanceOf<T> = Zero::zero();
+			for chunks in Unlockings::<T>::iter_values() {
+				reads += 1;
+				total_unlocking = chunks
+					.iter()
+					.fold(total_unlocking, |acc, chunk| acc.saturating_add(chunk.value));
+			}
+			TotalUnlocking::<T>::put(total_unlocking);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::liquid-staking",
+				"migrated to v2, total unlocking {:?}",
+				total_unlocking
+			);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Bonds::<T>::iter().next().is_none(), "Bonds was not drained");
+			let total_unlocking = Unlockings::<T>
+				::iter_values()
+				.flatten()
+				.fold(0u128, |acc, chunk| acc.saturating_add(chunk.value));
+			ensure!(
+				TotalUnlocking::<T>::get() == total_unlocking,
+				"TotalUnlocking does not match the pending unlockings"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"storage version was not bumped"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	#[storage_alias]
+	type Unlockings<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Vec<UnlockChunk<BalanceOf<T>>>
+	>;
+
+	/// Bound the unlock chunks of every account by `MaxUnlockings`.
+	///
+	/// Accounts with more chunks than that get their latest chunks merged into one that unlocks
+	/// at the latest of their eras, so no pending redemption is lost.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain != 2 {
+				log::info!(
+					target: "runtime::liquid-staking",
+					"MigrateToV3 should be removed, on-chain version is {:?}",
+					onchain
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let max = T::MaxUnlockings::get().max(1) as usize;
+			let mut translated = 0u64;
+			let mut merged = 0u64;
+			crate::Unlockings::<T>::translate::<Vec<UnlockChunk<BalanceOf<T>>>, _>(
+				|_, mut chunks| {
+					translated += 1;
+					if chunks.len() > max {
+						merged += 1;
+						let excess = chunks.split_off(max - 1);
+						let merged_chunk = excess
+							.into_iter()
+							.reduce(|acc, chunk| UnlockChunk {
+								value: acc.value.saturating_add(chunk.value),
+								era: acc.era.max(chunk.era),
+							})
+							.expect("more than `max` chunks, so at least one is split off; qed");
+						chunks.push(merged_chunk);
+					}
+					Some(BoundedVec::truncate_from(chunks))
+				}
+			);
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::liquid-staking",
+				"migrated {} unlockings to v3, merged the chunks of {}",
+				translated,
+				merged
+			);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let total_unlocking = Unlockings::<T>
+				::iter_values()
+				.flatten()
+				.fold(0u128, |acc, chunk| acc.saturating_add(chunk.value));
+			Ok((Unlockings::<T>::iter_keys().count() as u32, total_unlocking).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (accounts, total_unlocking) = <(u32, u128) as Decode>
+				::decode(&mut &state[..])
+				.map_err(|_| "cannot decode the pre-upgrade state")?;
+			let mut migrated_accounts = 0u32;
+			let mut migrated_unlocking = 0u128;
+			for chunks in crate::Unlockings::<T>::iter_values() {
+				migrated_accounts += 1;
+				migrated_unlocking = chunks
+					.iter()
+					.fold(migrated_unlocking, |acc, chunk| acc.saturating_add(chunk.value));
+			}
+			ensure!(migrated_accounts == accounts, "unlockings were lost");
+			ensure!(migrated_unlocking == total_unlocking, "unlocking amounts were lost");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"storage version was not bumped"
+			);
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::mock::{ new_test_ext, Assets, Balances, Test as T, ALICE, BOB };
+	use frame_support::assert_ok;
+	use liquid_staking_primitives::SSAITA;
+
+	#[test]
+	fn migration_to_v1_derives_the_exchange_rate() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<T>>();
+			let vault = Pallet::<T>::account_id();
+			v1::AccountStake::<T>::insert(ALICE, 1_000);
+			v1::AccountStake::<T>::insert(BOB, 2_000);
+			v1::StakedAccounts::<T>::put(vec![ALICE, BOB]);
+			// Alice passed 100 of her receipts on to account 3.
+			assert_ok!(Assets::mint_into(SSAITA, &ALICE, 900));
+			assert_ok!(Assets::mint_into(SSAITA, &3, 100));
+			assert_ok!(Assets::mint_into(SSAITA, &BOB, 2_000));
+			// The pool thinks 3_400 are staked, but the vault holds 3_101, of which 100 are
+			// owed to an unstaker and 1 is its existential deposit.
+			MatchingPool::<T>::mutate(|pool| {
+				pool.total_stake_amount.total = 3_500;
+				pool.total_unstake_amount.total = 100;
+			});
+			v1::Unlockings::<T>::insert(BOB, vec![UnlockChunk { value: 100, era: 5 }]);
+			<Balances as Mutate<_>>::set_balance(&vault, 3_101);
+
+			v1::MigrateToV1::<T>::on_runtime_upgrade();
+
+			assert!(v1::AccountStake::<T>::iter().next().is_none());
+			assert!(v1::StakedAccounts::<T>::get().is_none());
+			// Alice's shortfall against her stake is minted, the receipts of 3 stay valid.
+			assert_eq!(Assets::balance(SSAITA, ALICE), 1_000);
+			assert_eq!(Assets::balance(SSAITA, 3), 100);
+			assert_eq!(Assets::total_supply(SSAITA), 3_100);
+			assert_eq!(v1::vault_backing::<T>(), 3_000);
+			assert_eq!(MatchingPool::<T>::get().total_active().unwrap(), 3_000);
+			let rate = Pallet::<T>::exchange_rate();
+			assert_eq!(rate, Rate::saturating_from_rational(3_000, 3_100));
+			assert!(rate.saturating_mul_int(3_100u128) <= 3_000);
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 1);
+		});
+	}
+
+	#[test]
+	fn oversized_unlockings_are_totalled_by_v2_and_merged_by_v3() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			// `MaxUnlockings` is 2 in the mock.
+			let chunk = |value, era| UnlockChunk { value, era };
+			v2::Unlockings::<T>::insert(ALICE, vec![chunk(100, 1), chunk(200, 2), chunk(300, 3)]);
+			v2::Unlockings::<T>::insert(BOB, vec![chunk(50, 2)]);
+
+			v2::MigrateToV2::<T>::on_runtime_upgrade();
+
+			assert_eq!(TotalUnlocking::<T>::get(), 650);
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 2);
+
+			v3::MigrateToV3::<T>::on_runtime_upgrade();
+
+			assert_eq!(
+				crate::Unlockings::<T>::get(ALICE).unwrap().into_inner(),
+				vec![chunk(100, 1), chunk(500, 3)]
+			);
+			assert_eq!(crate::Unlockings::<T>::get(BOB).unwrap().into_inner(), vec![chunk(50, 2)]);
+			assert_eq!(TotalUnlocking::<T>::get(), 650);
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 3);
+		});
+	}
+}
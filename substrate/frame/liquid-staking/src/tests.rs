@@ -0,0 +1,527 @@
+use crate::{
+	mock::*,
+	Error,
+	EraFees,
+	Event,
+	MatchingPool,
+	TotalUnlocking,
+	UnlockTickets,
+	Unlockings,
+	TICKET_AMOUNT_KEY,
+};
+use frame_support::{
+	assert_noop,
+	assert_ok,
+	traits::{
+		fungible::Inspect as _,
+		fungibles::Inspect,
+		liquid_staking::DerivativeRewardAccount,
+		nonfungibles_v2::Inspect as _,
+		Hooks,
+	},
+};
+use pallet_reward::{ EraReward, NominatorRewardAccounts, NominatorRewardValidators };
+use pallet_nfts::{ CollectionConfig, CollectionSetting, CollectionSettings };
+use parity_scale_codec::Encode;
+use liquid_staking_primitives::SSAITA;
+use sp_runtime::{ BuildStorage, DispatchError, FixedPointNumber, FixedU128, Perbill, Permill };
+use sp_staking::OnStakingUpdate;
+use std::collections::BTreeMap;
+
+fn total_active() -> Balance {
+	MatchingPool::<Test>::get().total_active().unwrap()
+}
+
+/// Alice and Bob stake 1_000 each, Bob unstakes 500 and the vault bonds what is left while
+/// Bob's redemption unlocks.
+fn bonded_pool_with_unstaker() {
+	assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+	assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 1_000));
+	assert_ok!(LiquidStaking::bond(RuntimeOrigin::root(), 2_000));
+	assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(BOB), 500));
+	assert_ok!(LiquidStaking::unbond(RuntimeOrigin::root(), 500));
+	assert_eq!(total_active(), 1_500);
+	assert_eq!(LiquidStaking::total_unlocking(), 500);
+}
+
+#[test]
+fn stake_and_unstake_track_the_pool() {
+	new_test_ext().execute_with(|| {
+		bonded_pool_with_unstaker();
+		assert_eq!(Assets::balance(SSAITA, ALICE), 1_000);
+		assert_eq!(Assets::balance(SSAITA, BOB), 500);
+		assert_eq!(Unlockings::<Test>::get(BOB).unwrap()[0].value, 500);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::from_u32(1));
+	});
+}
+
+#[test]
+fn vault_slash_is_shared_by_holders_and_unstakers() {
+	new_test_ext().execute_with(|| {
+		bonded_pool_with_unstaker();
+		let mut ledger = pallet_staking::Ledger::<Test>::get(LiquidStaking::account_id()).unwrap();
+
+		// 10% of the vault's bond: 1_500 active and 500 unlocking.
+		assert_eq!(ledger.slash(200, 1, 0), 200);
+
+		System::assert_has_event(Event::<Test>::Slashed(150, 50).into());
+		assert_eq!(total_active(), 1_350);
+		assert_eq!(LiquidStaking::total_unlocking(), 450);
+		assert_eq!(LiquidStaking::unlocking_slash_factor(), FixedU128::from_rational(9, 10));
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::from_rational(9, 10));
+		// The pending redemption is left alone, the slash reaches it when it is claimed.
+		assert_eq!(Unlockings::<Test>::get(BOB).unwrap()[0].value, 500);
+		assert_eq!(LiquidStaking::api_staking_position(BOB).unlocking[0].value, 450);
+
+		// Holders now redeem at the slashed rate, and later slashes reach their redemptions.
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_eq!(LiquidStaking::api_staking_position(ALICE).unlocking[0].value, 900);
+		assert_eq!(LiquidStaking::total_unlocking(), 1_350);
+		assert_ok!(LiquidStaking::do_try_state());
+
+		pallet_staking::CurrentEra::<Test>::put(LiquidStaking::target_era());
+		assert_ok!(LiquidStaking::withdraw_unbonded(RuntimeOrigin::root()));
+		assert_ok!(LiquidStaking::claim_for(RuntimeOrigin::signed(BOB), BOB));
+		System::assert_has_event(Event::<Test>::ClaimedFor(BOB, 450).into());
+		assert_eq!(TotalUnlocking::<Test>::get(), 1_000);
+	});
+}
+
+#[test]
+fn slash_of_the_settled_stake_leaves_the_matching_window_alone() {
+	new_test_ext().execute_with(|| {
+		bonded_pool_with_unstaker();
+		MatchingPool::<Test>::mutate(|p| p.settle().unwrap());
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 100));
+		let matchable = MatchingPool::<Test>::get().matchable().unwrap();
+
+		assert_ok!(LiquidStaking::do_slash(200));
+
+		assert_eq!(MatchingPool::<Test>::get().matchable().unwrap(), matchable);
+	});
+}
+
+#[test]
+fn slash_of_another_staker_is_ignored() {
+	new_test_ext().execute_with(|| {
+		bonded_pool_with_unstaker();
+		let pool = MatchingPool::<Test>::get();
+
+		LiquidStaking::on_slash(&ALICE, 0, &BTreeMap::new());
+
+		assert_eq!(MatchingPool::<Test>::get(), pool);
+		assert_eq!(LiquidStaking::total_unlocking(), 500);
+		assert!(
+			!System::events()
+				.iter()
+				.any(|record| matches!(
+					record.event,
+					RuntimeEvent::LiquidStaking(Event::<Test>::Slashed(..))
+				))
+		);
+	});
+}
+
+#[test]
+fn unstakes_are_bounded_by_max_unlockings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 1_000));
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(BOB), 100));
+		// Unstakes of the same era share a chunk.
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(BOB), 100));
+		pallet_staking::CurrentEra::<Test>::put(1);
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(BOB), 100));
+		assert_eq!(Unlockings::<Test>::get(BOB).unwrap().len(), 2);
+
+		pallet_staking::CurrentEra::<Test>::put(2);
+		assert_noop!(
+			LiquidStaking::unstake(RuntimeOrigin::signed(BOB), 100),
+			Error::<Test>::TooManyUnlockings
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_drift_of_the_pool() {
+	new_test_ext().execute_with(|| {
+		bonded_pool_with_unstaker();
+		assert_ok!(LiquidStaking::do_try_state());
+
+		TotalUnlocking::<Test>::put(400);
+		assert!(LiquidStaking::do_try_state().is_err());
+		TotalUnlocking::<Test>::put(500);
+
+		MatchingPool::<Test>::mutate(|p| p.total_stake_amount.total += 1);
+		assert!(LiquidStaking::do_try_state().is_err());
+	});
+}
+
+/// Create the collection unlock tickets get minted in, owned by the pallet account and free of
+/// deposits.
+fn ticket_collection() -> u32 {
+	assert_ok!(
+		Nfts::force_create(RuntimeOrigin::root(), LiquidStaking::account_id(), CollectionConfig {
+			settings: CollectionSettings::from_disabled(CollectionSetting::DepositRequired.into()),
+			max_supply: None,
+			mint_settings: Default::default(),
+		})
+	);
+	assert_ok!(LiquidStaking::set_ticket_collection(RuntimeOrigin::root(), 0));
+	0
+}
+
+#[test]
+fn unlock_tickets_need_a_collection_of_the_pallet() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_noop!(
+			LiquidStaking::unstake_to_ticket(RuntimeOrigin::signed(ALICE), 100),
+			Error::<Test>::NoTicketCollection
+		);
+
+		assert_ok!(Nfts::create(RuntimeOrigin::signed(ALICE), ALICE, Default::default()));
+		assert_noop!(
+			LiquidStaking::set_ticket_collection(RuntimeOrigin::root(), 0),
+			Error::<Test>::TicketCollectionNotOwned
+		);
+	});
+}
+
+#[test]
+fn unlock_ticket_is_redeemed_by_its_holder_at_maturity() {
+	new_test_ext().execute_with(|| {
+		let collection = ticket_collection();
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_ok!(LiquidStaking::unstake_to_ticket(RuntimeOrigin::signed(ALICE), 400));
+
+		let target_era = LiquidStaking::target_era();
+		let ticket = UnlockTickets::<Test>::get(0).unwrap();
+		assert_eq!((ticket.value, ticket.era), (400, target_era));
+		assert_eq!(Nfts::owner(collection, 0), Some(ALICE));
+		assert_eq!(
+			Nfts::system_attribute(&collection, &0, TICKET_AMOUNT_KEY),
+			Some((400 as Balance).encode())
+		);
+		assert!(Unlockings::<Test>::get(ALICE).is_none());
+		assert_eq!(LiquidStaking::total_unlocking(), 400);
+		System::assert_has_event(Event::<Test>::TicketMinted(ALICE, 0, 400, target_era).into());
+		assert_ok!(LiquidStaking::do_try_state());
+
+		// The ticket gets sold before it matures.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(ALICE), collection, 0, BOB));
+		assert_noop!(
+			LiquidStaking::redeem_ticket(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::WaitTheEraToComplete
+		);
+
+		pallet_staking::CurrentEra::<Test>::put(target_era);
+		assert_noop!(
+			LiquidStaking::redeem_ticket(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NotTicketOwner
+		);
+		assert_ok!(LiquidStaking::redeem_ticket(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE + 400);
+		assert_eq!(Nfts::owner(collection, 0), None);
+		assert!(UnlockTickets::<Test>::get(0).is_none());
+		assert_eq!(LiquidStaking::total_unlocking(), 0);
+		assert_ok!(LiquidStaking::do_try_state());
+	});
+}
+
+#[test]
+fn vault_slash_is_shared_by_unlock_tickets() {
+	new_test_ext().execute_with(|| {
+		let collection = ticket_collection();
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 1_000));
+		assert_ok!(LiquidStaking::bond(RuntimeOrigin::root(), 2_000));
+		assert_ok!(LiquidStaking::unstake_to_ticket(RuntimeOrigin::signed(BOB), 500));
+		assert_ok!(LiquidStaking::unbond(RuntimeOrigin::root(), 500));
+		let mut ledger = pallet_staking::Ledger::<Test>::get(LiquidStaking::account_id()).unwrap();
+
+		assert_eq!(ledger.slash(200, 1, 0), 200);
+
+		System::assert_has_event(Event::<Test>::Slashed(150, 50).into());
+		assert_eq!(LiquidStaking::total_unlocking(), 450);
+
+		// The ticket keeps the amount it was minted for, but redeems for what is left of it.
+		pallet_staking::CurrentEra::<Test>::put(LiquidStaking::target_era());
+		assert_ok!(LiquidStaking::withdraw_unbonded(RuntimeOrigin::root()));
+		assert_eq!(
+			Nfts::system_attribute(&collection, &0, TICKET_AMOUNT_KEY),
+			Some((500 as Balance).encode())
+		);
+		assert_ok!(LiquidStaking::redeem_ticket(RuntimeOrigin::signed(BOB), 0));
+		System::assert_has_event(Event::<Test>::TicketRedeemed(BOB, 0, 450).into());
+		assert_eq!(TotalUnlocking::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn stake_and_unstake_fees_go_to_the_fee_collector() {
+	new_test_ext().execute_with(|| {
+		LiquidStaking::on_initialize(1);
+		assert_noop!(
+			LiquidStaking::set_fees(RuntimeOrigin::root(), Permill::from_percent(2), Permill::zero()),
+			Error::<Test>::FeeTooHigh
+		);
+		assert_ok!(
+			LiquidStaking::set_fees(
+				RuntimeOrigin::root(),
+				Permill::from_percent(1),
+				Permill::from_percent(1)
+			)
+		);
+
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_eq!(Assets::balance(SSAITA, ALICE), 990);
+		assert_eq!(Assets::balance(SSAITA, FEE_COLLECTOR), 10);
+		assert_eq!(total_active(), 1_000);
+
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(ALICE), 500));
+		assert_eq!(Assets::balance(SSAITA, ALICE), 490);
+		assert_eq!(Assets::balance(SSAITA, FEE_COLLECTOR), 15);
+		assert_eq!(Unlockings::<Test>::get(ALICE).unwrap()[0].value, 495);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::from_u32(1));
+		assert_eq!((EraFees::<Test>::get().stake_fees, EraFees::<Test>::get().unstake_fees), (10, 5));
+
+		// The fees are reported once the era is over.
+		pallet_staking::CurrentEra::<Test>::put(1);
+		LiquidStaking::on_initialize(2);
+		System::assert_has_event(Event::<Test>::FeesCollected(0, 0, 10, 5).into());
+		assert!(EraFees::<Test>::get().is_zero());
+	});
+}
+
+#[test]
+fn commission_on_the_vault_reward_is_minted_to_the_fee_collector() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidStaking::set_commission(RuntimeOrigin::root(), Perbill::from_percent(21)),
+			Error::<Test>::CommissionTooHigh
+		);
+		assert_ok!(LiquidStaking::set_commission(RuntimeOrigin::root(), Perbill::from_percent(10)));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+
+		assert_ok!(LiquidStaking::compound_reward(100));
+
+		// The commission of 10 buys liquid currency at the rate the other 90 lead to, 1.09.
+		assert_eq!(Assets::balance(SSAITA, FEE_COLLECTOR), 9);
+		assert_eq!(EraFees::<Test>::get().commission, 9);
+		assert_eq!(total_active(), 1_100);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::from_rational(1_100, 1_009));
+		System::assert_has_event(Event::<Test>::RewardCompounded(100).into());
+	});
+}
+
+#[test]
+fn genesis_sets_the_fees_and_funds_the_vault() {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> {
+		commission: Perbill::from_percent(10),
+		stake_fee: Permill::from_percent(1),
+		unstake_fee: Permill::zero(),
+		vault_funds: 100,
+	}
+		.assimilate_storage(&mut t)
+		.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(LiquidStaking::commission(), Perbill::from_percent(10));
+		assert_eq!(LiquidStaking::stake_fee(), Permill::from_percent(1));
+		assert_eq!(LiquidStaking::unstake_fee(), Permill::zero());
+		assert_eq!(Balances::balance(&LiquidStaking::account_id()), 100);
+		assert_eq!(Balances::total_issuance(), 100);
+	});
+}
+
+#[test]
+#[should_panic = "the commission can't be above `MaxCommission`."]
+fn genesis_commission_is_bounded() {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let _ = crate::GenesisConfig::<Test> {
+		commission: Perbill::from_percent(21),
+		..Default::default()
+	}.assimilate_storage(&mut t);
+}
+
+#[test]
+fn staking_to_liquid_rounds_once() {
+	new_test_ext().execute_with(|| {
+		crate::ExchangeRate::<Test>::put(FixedU128::from_u32(3));
+		let amount: Balance = 1_000_000_000_000_000_000_000_000_000_000;
+
+		// Rounding the reciprocal of 3 first would lose the last 12 digits.
+		assert_eq!(
+			LiquidStaking::staking_to_liquid(amount),
+			Some(333_333_333_333_333_333_333_333_333_333)
+		);
+		assert_eq!(LiquidStaking::staking_to_liquid(10), Some(3));
+	});
+}
+
+#[test]
+fn vault_strategy_needs_the_strategy_origin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		let origin = || RuntimeOrigin::signed(ALICE);
+
+		assert_noop!(LiquidStaking::bond(origin(), 1_000), DispatchError::BadOrigin);
+		assert_noop!(LiquidStaking::bond_extra(origin(), 100), DispatchError::BadOrigin);
+		assert_noop!(LiquidStaking::unbond(origin(), 100), DispatchError::BadOrigin);
+		assert_noop!(LiquidStaking::rebond(origin(), 100), DispatchError::BadOrigin);
+		assert_noop!(LiquidStaking::withdraw_unbonded(origin()), DispatchError::BadOrigin);
+		assert_noop!(LiquidStaking::nominate(origin(), vec![(10, 1)]), DispatchError::BadOrigin);
+		assert_noop!(LiquidStaking::set_ticket_collection(origin(), 0), DispatchError::BadOrigin);
+		assert!(pallet_staking::Ledger::<Test>::get(LiquidStaking::account_id()).is_none());
+	});
+}
+
+#[test]
+fn vault_is_rebalanced_once_per_era() {
+	new_test_ext().execute_with(|| {
+		let vault = LiquidStaking::account_id();
+		assert_ok!(
+			LiquidStaking::nominate(RuntimeOrigin::root(), vec![(10, 1), (11, 3), (12, 0)])
+		);
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+
+		// The new stake gets bonded, and the weighted targets nominated heaviest first.
+		LiquidStaking::on_initialize(1);
+		assert_eq!(pallet_staking::Ledger::<Test>::get(vault).unwrap().active, 1_000);
+		assert_eq!(
+			pallet_staking::Nominators::<Test>::get(vault).unwrap().targets.into_inner(),
+			vec![11, 10]
+		);
+		System::assert_has_event(Event::<Test>::VaultBonded(1_000).into());
+
+		// Stakes of the next era are bonded extra, but only once the era starts.
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 500));
+		LiquidStaking::on_initialize(2);
+		assert_eq!(pallet_staking::Ledger::<Test>::get(vault).unwrap().active, 1_000);
+		pallet_staking::CurrentEra::<Test>::put(1);
+		LiquidStaking::on_initialize(3);
+		assert_eq!(pallet_staking::Ledger::<Test>::get(vault).unwrap().active, 1_500);
+		System::assert_has_event(Event::<Test>::VaultBonded(500).into());
+
+		// Unstakes get unbonded.
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(ALICE), 600));
+		pallet_staking::CurrentEra::<Test>::put(2);
+		LiquidStaking::on_initialize(4);
+		let ledger = pallet_staking::Ledger::<Test>::get(vault).unwrap();
+		assert_eq!((ledger.active, ledger.total), (900, 1_500));
+		System::assert_has_event(Event::<Test>::VaultUnbonded(600).into());
+		assert_ok!(LiquidStaking::do_try_state());
+	});
+}
+
+#[test]
+fn instant_unstake_is_paid_out_of_the_stakes_of_the_era() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 1_000));
+
+		// 0.3% of 500, rounded up, stays in the pool.
+		assert_ok!(LiquidStaking::instant_unstake(RuntimeOrigin::signed(BOB), 500));
+		System::assert_has_event(Event::<Test>::InstantUnstaked(BOB, 500, 498, 2).into());
+		assert_eq!(Assets::balance(SSAITA, BOB), 500);
+		assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE - 1_000 + 498);
+		assert!(Unlockings::<Test>::get(BOB).is_none());
+		assert_eq!(total_active(), 1_502);
+		assert_eq!(MatchingPool::<Test>::get().matchable().unwrap(), 1_502);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::from_rational(1_502, 1_500));
+		assert_ok!(LiquidStaking::do_try_state());
+	});
+}
+
+#[test]
+fn instant_unstake_pays_the_unstake_fee_to_the_fee_collector() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(
+			LiquidStaking::set_fees(
+				RuntimeOrigin::root(),
+				Permill::zero(),
+				Permill::from_percent(1)
+			)
+		);
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 1_000));
+
+		// 1% of 500 goes to the fee collector, 0.3% of the other 495, rounded up, to the pool.
+		assert_ok!(LiquidStaking::instant_unstake(RuntimeOrigin::signed(BOB), 500));
+		System::assert_has_event(Event::<Test>::InstantUnstaked(BOB, 500, 493, 2).into());
+		assert_eq!(Assets::balance(SSAITA, BOB), 500);
+		assert_eq!(Assets::balance(SSAITA, FEE_COLLECTOR), 5);
+		assert_eq!(EraFees::<Test>::get().unstake_fees, 5);
+		assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE - 1_000 + 493);
+		assert_eq!(total_active(), 1_507);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::from_rational(1_507, 1_505));
+		assert_ok!(LiquidStaking::do_try_state());
+	});
+}
+
+#[test]
+fn instant_unstake_needs_enough_matchable_stakes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		MatchingPool::<Test>::mutate(|p| p.settle().unwrap());
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 100));
+
+		assert_noop!(
+			LiquidStaking::instant_unstake(RuntimeOrigin::signed(ALICE), 500),
+			Error::<Test>::NotEnoughMatchable
+		);
+		assert_ok!(LiquidStaking::instant_unstake(RuntimeOrigin::signed(ALICE), 100));
+	});
+}
+
+#[test]
+fn only_the_net_of_the_era_is_bonded_once_it_is_settled() {
+	new_test_ext().execute_with(|| {
+		let vault = LiquidStaking::account_id();
+		assert_ok!(LiquidStaking::nominate(RuntimeOrigin::root(), vec![(10, 1)]));
+		LiquidStaking::on_initialize(1);
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(BOB), 1_000));
+		assert_ok!(LiquidStaking::instant_unstake(RuntimeOrigin::signed(BOB), 400));
+
+		pallet_staking::CurrentEra::<Test>::put(1);
+		LiquidStaking::on_initialize(2);
+
+		let pool = MatchingPool::<Test>::get();
+		assert_eq!(
+			(pool.total_stake_amount.reserved, pool.total_unstake_amount.reserved),
+			(2_000, 398)
+		);
+		assert_eq!(pool.matchable().unwrap(), 0);
+		assert_eq!(pallet_staking::Ledger::<Test>::get(vault).unwrap().active, 1_602);
+		System::assert_has_event(Event::<Test>::VaultBonded(1_602).into());
+		assert_noop!(
+			LiquidStaking::instant_unstake(RuntimeOrigin::signed(BOB), 100),
+			Error::<Test>::NotEnoughMatchable
+		);
+	});
+}
+
+#[test]
+fn only_paid_vault_rewards_are_compounded_and_reset() {
+	new_test_ext().execute_with(|| {
+		let vault = LiquidStaking::account_id();
+		assert_ok!(LiquidStaking::nominate(RuntimeOrigin::root(), vec![(10, 1), (11, 1)]));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(ALICE), 1_000));
+		LiquidStaking::on_initialize(1);
+		// The payout of the vault by 10 failed, 11 paid it.
+		EraReward::<Test>::insert(10, frame_support::BoundedVec::truncate_from(vec![vault]));
+		for (validator, reward) in [(10, 100), (11, 200)] {
+			NominatorRewardAccounts::<Test>::insert(validator, vault, reward);
+			NominatorRewardValidators::<Test>::insert(vault, validator, ());
+		}
+
+		assert_eq!(LiquidStaking::paid_vault_reward(), 200);
+		assert_ok!(LiquidStaking::reset_reward());
+
+		assert_eq!(NominatorRewardAccounts::<Test>::get(10, vault), 100);
+		assert_eq!(NominatorRewardAccounts::<Test>::get(11, vault), 0);
+		assert_eq!(Reward::api_nominator_pending_rewards(vault), vec![(10, 100)]);
+		assert_eq!(EraReward::<Test>::get(10).into_inner(), vec![vault]);
+	});
+}
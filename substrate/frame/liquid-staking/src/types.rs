@@ -1,5 +1,5 @@
 use sp_runtime::RuntimeDebug;
-use parity_scale_codec::{ Decode, Encode };
+use parity_scale_codec::{ Decode, Encode, MaxEncodedLen };
 use scale_info::TypeInfo;
 use crate::Config;
 use crate::AssetIdOf;
@@ -10,6 +10,18 @@ use frame_support::pallet_prelude::DispatchError;
 use sp_runtime::FixedPointOperand;
 use sp_runtime::traits::Get;
 use frame_support::traits::tokens::Balance as BalanceT;
+use sp_runtime::FixedU128;
+use scale_info::prelude::vec::Vec;
+use liquid_staking_primitives::EraIndex;
+#[cfg(feature = "std")]
+use serde::{ Deserialize, Serialize };
+
+/// The exchange rate between the staking currency and the liquid currency.
+pub type Rate = FixedU128;
+
+/// Identifies an unlock ticket within the ticket collection.
+pub type TicketId = u32;
+
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum Staking {
 	Bond,
@@ -37,7 +49,7 @@ impl<T: Config> LiquidStakingCurrenciesProvider<AssetIdOf<T>> for Pallet<T> {
 }
 
 /// The matching pool's total stake & unstake amount in one era
-#[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct MatchingLedger<Balance> {
 	/// The total stake amount in one era
 	pub total_stake_amount: ReservableAmount<Balance>,
@@ -45,7 +57,7 @@ pub struct MatchingLedger<Balance> {
 	pub total_unstake_amount: ReservableAmount<Balance>,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct ReservableAmount<Balance> {
 	pub total: Balance,
 	pub reserved: Balance,
@@ -58,6 +70,15 @@ impl<Balance: BalanceT + FixedPointOperand> ReservableAmount<Balance> {
 }
 
 impl<Balance: BalanceT + FixedPointOperand> MatchingLedger<Balance> {
+	/// The amount of staking currency backing the outstanding liquid currency.
+	pub fn total_active(&self) -> Result<Balance, DispatchError> {
+		Ok(
+			self.total_stake_amount.total
+				.checked_sub(&self.total_unstake_amount.total)
+				.ok_or(ArithmeticError::Underflow)?
+		)
+	}
+
 	pub fn add_stake_amount(&mut self, amount: Balance) -> DispatchResult {
 		self.total_stake_amount.total = self.total_stake_amount.total
 			.checked_add(&amount)
@@ -117,4 +138,74 @@ impl<Balance: BalanceT + FixedPointOperand> MatchingLedger<Balance> {
 		self.total_unstake_amount.reserved = new_reserved_unstake_amount;
 		Ok(())
 	}
-}
\ No newline at end of file
+
+	/// Take a slash of the bonded stake out of the pool. The slash hits the settled stake
+	/// first, so this era's matching window is left alone.
+	pub fn slash(&mut self, amount: Balance) -> DispatchResult {
+		if self.total_active()? < amount {
+			return Err(ArithmeticError::Underflow.into());
+		}
+		self.total_stake_amount.total = self.total_stake_amount.total
+			.checked_sub(&amount)
+			.ok_or(ArithmeticError::Underflow)?;
+		self.total_stake_amount.reserved = self.total_stake_amount.reserved.saturating_sub(amount);
+		Ok(())
+	}
+
+	/// The part of this era's stakes not yet netted against this era's unstakes. This much can
+	/// be redeemed instantly, without going through `pallet_staking`.
+	pub fn matchable(&self) -> Result<Balance, DispatchError> {
+		Ok(self.total_stake_amount.free()?.saturating_sub(self.total_unstake_amount.free()?))
+	}
+
+	/// Mark every stake and unstake so far as settled on `pallet_staking`, starting a new
+	/// matching window.
+	pub fn settle(&mut self) -> DispatchResult {
+		self.set_stake_amount_lock(self.total_stake_amount.free()?)?;
+		self.set_unstake_amount_lock(self.total_unstake_amount.free()?)
+	}
+}
+
+/// The protocol fees collected in one era, in the liquid currency.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollectedFees<Balance> {
+	/// The commission taken on the vault's rewards
+	pub commission: Balance,
+	/// The fees charged on stakes
+	pub stake_fees: Balance,
+	/// The fees charged on unstakes
+	pub unstake_fees: Balance,
+}
+
+impl<Balance: BalanceT> CollectedFees<Balance> {
+	pub fn is_zero(&self) -> bool {
+		self.commission.is_zero() && self.stake_fees.is_zero() && self.unstake_fees.is_zero()
+	}
+}
+/// A pending redemption of an account, as reported by the runtime API.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct UnlockingInfo<Balance> {
+	/// The amount of staking currency that gets unlocked.
+	pub value: Balance,
+	/// The era from which on the amount can be claimed.
+	pub era: EraIndex,
+}
+
+/// The liquid staking position of an account, as reported by the runtime API.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct StakingPosition<Balance> {
+	/// The liquid currency held by the account.
+	pub liquid_balance: Balance,
+	/// The staking currency the liquid balance can be redeemed for at the current rate.
+	pub staked_amount: Balance,
+	/// The pending redemptions of the account.
+	pub unlocking: Vec<UnlockingInfo<Balance>>,
+	/// The part of `unlocking` that can be claimed right now.
+	pub claimable: Balance,
+	/// The estimated reward `staked_amount` earns over the next era.
+	pub estimated_era_reward: Balance,
+}
@@ -0,0 +1,509 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_liquid_staking
+//!
+//! THESE WEIGHTS WERE NOT GENERATED BY THE SUBSTRATE BENCHMARK CLI. The benchmarks of this
+//! pallet have not been run on reference hardware yet, so every function charges a
+//! deliberately pessimistic 500 µs and 16 KiB of proof, plus 50 µs and 4 KiB per item of its
+//! components, on top of the storage it reads and writes. Replace this file with the output
+//! of the command below before a release.
+
+// Command to generate the weights:
+// target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_liquid_staking
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/liquid-staking/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_liquid_staking.
+pub trait WeightInfo {
+	fn stake() -> Weight;
+	fn unstake() -> Weight;
+	fn claim_for() -> Weight;
+	fn bond() -> Weight;
+	fn unbond() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn nominate(n: u32, ) -> Weight;
+	fn claim_reward(n: u32, ) -> Weight;
+	fn bond_extra() -> Weight;
+	fn rebond(l: u32, ) -> Weight;
+	fn instant_unstake() -> Weight;
+	fn rebalance() -> Weight;
+	fn set_ticket_collection() -> Weight;
+	fn unstake_to_ticket() -> Weight;
+	fn redeem_ticket() -> Weight;
+	fn set_commission() -> Weight;
+	fn set_fees() -> Weight;
+}
+
+/// Placeholder weights for pallet_liquid_staking, see the module documentation.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::Unlockings` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	fn unstake() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::Unlockings` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	fn claim_for() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Staking::Bonded` (r:1 w:1)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `Staking::Payee` (r:0 w:1)
+	fn bond() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Staking::MinNominatorBond` (r:1 w:0)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	fn unbond() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:0)
+	/// Storage: `Staking::MinNominatorBond` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::MaxNominatorsCount` (r:1 w:0)
+	/// Storage: `Staking::Validators` (r:16 w:0)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:2 w:2)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	/// Storage: `LiquidStaking::ValidatorTargets` (r:0 w:1)
+	/// The range of component `n` is `[1, 16]`.
+	fn nominate(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:16 w:0)
+	/// The range of component `n` is `[1, 16]`.
+	fn claim_reward(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	fn bond_extra() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	/// The range of component `l` is `[1, 32]`.
+	fn rebond(l: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	fn instant_unstake() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::LastRebalancedEra` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:0)
+	/// Storage: `LiquidStaking::ValidatorTargets` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::Validators` (r:16 w:0)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:2 w:2)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	fn rebalance() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `LiquidStaking::UnlockTickets` (r:1 w:0)
+	/// Storage: `LiquidStaking::TicketCollection` (r:0 w:1)
+	fn set_ticket_collection() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LiquidStaking::TicketCollection` (r:1 w:0)
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::NextTicketId` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Attribute` (r:2 w:2)
+	/// Storage: `Nfts::Account` (r:0 w:1)
+	/// Storage: `LiquidStaking::UnlockTickets` (r:0 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	fn unstake_to_ticket() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `LiquidStaking::TicketCollection` (r:1 w:0)
+	/// Storage: `LiquidStaking::UnlockTickets` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Attribute` (r:2 w:2)
+	/// Storage: `Nfts::ItemMetadataOf` (r:1 w:0)
+	/// Storage: `Nfts::Account` (r:0 w:1)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::ItemAttributesApprovalsOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	fn redeem_ticket() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `LiquidStaking::Commission` (r:0 w:1)
+	fn set_commission() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LiquidStaking::StakeFee` (r:0 w:1)
+	/// Storage: `LiquidStaking::UnstakeFee` (r:0 w:1)
+	fn set_fees() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::Unlockings` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	fn unstake() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::Unlockings` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	fn claim_for() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Staking::Bonded` (r:1 w:1)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `Staking::Payee` (r:0 w:1)
+	fn bond() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Staking::MinNominatorBond` (r:1 w:0)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	fn unbond() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:0)
+	/// Storage: `Staking::MinNominatorBond` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::MaxNominatorsCount` (r:1 w:0)
+	/// Storage: `Staking::Validators` (r:16 w:0)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:2 w:2)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	/// Storage: `LiquidStaking::ValidatorTargets` (r:0 w:1)
+	/// The range of component `n` is `[1, 16]`.
+	fn nominate(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:16 w:0)
+	/// The range of component `n` is `[1, 16]`.
+	fn claim_reward(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	fn bond_extra() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	/// The range of component `l` is `[1, 32]`.
+	fn rebond(l: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	fn instant_unstake() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::LastRebalancedEra` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:0)
+	/// Storage: `LiquidStaking::ValidatorTargets` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::Validators` (r:16 w:0)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:2 w:2)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	fn rebalance() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `LiquidStaking::UnlockTickets` (r:1 w:0)
+	/// Storage: `LiquidStaking::TicketCollection` (r:0 w:1)
+	fn set_ticket_collection() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LiquidStaking::TicketCollection` (r:1 w:0)
+	/// Storage: `Assets::Asset` (r:2 w:1)
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Storage: `LiquidStaking::MatchingPool` (r:1 w:1)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `LiquidStaking::NextTicketId` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Attribute` (r:2 w:2)
+	/// Storage: `Nfts::Account` (r:0 w:1)
+	/// Storage: `LiquidStaking::UnlockTickets` (r:0 w:1)
+	/// Storage: `LiquidStaking::ExchangeRate` (r:1 w:1)
+	fn unstake_to_ticket() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `LiquidStaking::TicketCollection` (r:1 w:0)
+	/// Storage: `LiquidStaking::UnlockTickets` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Attribute` (r:2 w:2)
+	/// Storage: `Nfts::ItemMetadataOf` (r:1 w:0)
+	/// Storage: `Nfts::Account` (r:0 w:1)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::ItemAttributesApprovalsOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `LiquidStaking::TotalUnlocking` (r:1 w:1)
+	fn redeem_ticket() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `LiquidStaking::Commission` (r:0 w:1)
+	fn set_commission() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LiquidStaking::StakeFee` (r:0 w:1)
+	/// Storage: `LiquidStaking::UnstakeFee` (r:0 w:1)
+	fn set_fees() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
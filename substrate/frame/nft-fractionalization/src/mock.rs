@@ -89,6 +89,10 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ConstU32<1>;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl pallet_assets::Config for Test {
@@ -113,6 +117,8 @@ impl pallet_assets::Config for Test {
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
+		type Blacklist = ();
+		type MaxMemoLen = ConstU32<256>;
 }
 
 parameter_types! {
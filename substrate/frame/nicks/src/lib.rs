@@ -314,6 +314,10 @@ mod tests {
 		type MaxFreezes = ();
 		type RuntimeHoldReason = ();
 		type MaxHolds = ();
+		type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+		type MaxMemoLen = frame_support::traits::ConstU32<256>;
+		type MaxMembers = frame_support::traits::ConstU32<100>;
+		type MaxPendingMints = frame_support::traits::ConstU32<10>;
 	}
 
 	ord_parameter_types! {
@@ -331,9 +335,12 @@ mod tests {
 
 	fn new_test_ext() -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
-		pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 10), (2, 10)] }
-			.assimilate_storage(&mut t)
-			.unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(1, 10), (2, 10)],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
 		t.into()
 	}
 
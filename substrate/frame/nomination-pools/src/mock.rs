@@ -226,6 +226,10 @@ impl pallet_balances::Config for Runtime {
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
 
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 pub struct BalanceToU256;
@@ -64,6 +64,10 @@ impl pallet_balances::Config for Test {
 	type DustRemoval = ();
 	type AccountStore = System;
 	type ExistentialDeposit = ConstU64<1>;
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl pallet_utility::Config for Test {
@@ -150,6 +154,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
 		balances: vec![(1, 10), (2, 10), (3, 10), (4, 10), (5, 3)],
+		..Default::default()
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
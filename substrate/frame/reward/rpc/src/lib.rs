@@ -0,0 +1,197 @@
+//! RPC interface for the reward pallet.
+
+use std::{convert::TryInto, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use pallet_reward_runtime_api::{EraRewardRecord, RewardPercentInfo};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	traits::{Block as BlockT, MaybeDisplay},
+	Perbill,
+};
+
+pub use pallet_reward_runtime_api::RewardApi as RewardRuntimeApi;
+
+#[rpc(client, server)]
+pub trait RewardApi<BlockHash, AccountId> {
+	#[method(name = "reward_validatorPendingReward")]
+	fn validator_pending_reward(
+		&self,
+		validator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "reward_nominatorPendingRewards")]
+	fn nominator_pending_rewards(
+		&self,
+		nominator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, NumberOrHex)>>;
+
+	#[method(name = "reward_totalRewards")]
+	fn total_rewards(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "reward_queuedValidators")]
+	fn queued_validators(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	#[method(name = "reward_rewardPercent")]
+	fn reward_percent(&self, at: Option<BlockHash>) -> RpcResult<RewardPercentInfo>;
+
+	#[method(name = "reward_projectedApr")]
+	fn projected_apr(&self, validator: AccountId, at: Option<BlockHash>) -> RpcResult<Perbill>;
+
+	#[method(name = "reward_rewardHistory")]
+	fn reward_history(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<EraRewardRecord<AccountId, NumberOrHex>>>;
+}
+
+/// Provides RPC methods to query pending and paid out staking rewards.
+pub struct Reward<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Reward<C, P> {
+	/// Creates a new instance of the Reward Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string())))
+		.into()
+}
+
+fn try_into_rpc_balance<Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>>(
+	value: Balance,
+) -> RpcResult<NumberOrHex> {
+	value.try_into().map_err(|_| {
+		JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+			ErrorCode::InvalidParams.code(),
+			format!("{} doesn't fit in NumberOrHex representation", value),
+			None::<()>,
+		)))
+	})
+}
+
+impl<C, Block, AccountId, Balance> RewardApiServer<<Block as BlockT>::Hash, AccountId>
+	for Reward<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RewardRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn validator_pending_reward(
+		&self,
+		validator: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let reward = api
+			.validator_pending_reward(at_hash, validator)
+			.map_err(|e| map_err(e, "Unable to query the pending validator reward."))?;
+		try_into_rpc_balance(reward)
+	}
+
+	fn nominator_pending_rewards(
+		&self,
+		nominator: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(AccountId, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.nominator_pending_rewards(at_hash, nominator)
+			.map_err(|e| map_err(e, "Unable to query the pending nominator rewards."))?
+			.into_iter()
+			.map(|(validator, reward)| Ok((validator, try_into_rpc_balance(reward)?)))
+			.collect()
+	}
+
+	fn total_rewards(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let reward = api
+			.total_rewards(at_hash, who)
+			.map_err(|e| map_err(e, "Unable to query the total rewards."))?;
+		try_into_rpc_balance(reward)
+	}
+
+	fn queued_validators(&self, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.queued_validators(at_hash)
+			.map_err(|e| map_err(e, "Unable to query the queued validators."))
+	}
+
+	fn reward_percent(&self, at: Option<Block::Hash>) -> RpcResult<RewardPercentInfo> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.reward_percent(at_hash)
+			.map_err(|e| map_err(e, "Unable to query the reward percent."))
+	}
+
+	fn projected_apr(&self, validator: AccountId, at: Option<Block::Hash>) -> RpcResult<Perbill> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.projected_apr(at_hash, validator)
+			.map_err(|e| map_err(e, "Unable to query the projected APR."))
+	}
+
+	fn reward_history(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<EraRewardRecord<AccountId, NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.reward_history(at_hash, who)
+			.map_err(|e| map_err(e, "Unable to query the reward history."))?
+			.into_iter()
+			.map(|record| {
+				Ok(EraRewardRecord {
+					era: record.era,
+					validator: record.validator,
+					reward: try_into_rpc_balance(record.reward)?,
+					commission: record.commission,
+					points: record.points,
+				})
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,39 @@
+//! Runtime API definition for the reward pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+pub use pallet_reward::{EraRewardRecord, RewardPercentInfo};
+
+sp_api::decl_runtime_apis! {
+	pub trait RewardApi<AccountId, Balance>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+	{
+		/// Returns the reward of `validator` that is waiting to be paid out.
+		fn validator_pending_reward(validator: AccountId) -> Balance;
+
+		/// Returns the rewards of `nominator` that are waiting to be paid out, per validator.
+		fn nominator_pending_rewards(nominator: AccountId) -> Vec<(AccountId, Balance)>;
+
+		/// Returns the total reward ever paid out to `who`.
+		fn total_rewards(who: AccountId) -> Balance;
+
+		/// Returns the validators whose rewards get paid out at the end of the era.
+		fn queued_validators() -> Vec<AccountId>;
+
+		/// Returns the current and upcoming annual reward percent.
+		fn reward_percent() -> RewardPercentInfo;
+
+		/// Returns the projected annual return of staking behind `validator`.
+		fn projected_apr(validator: AccountId) -> Perbill;
+
+		/// Returns the rewards `who` earned in the last `HistoryDepth` eras, as a validator and
+		/// as a nominator, oldest first.
+		fn reward_history(who: AccountId) -> Vec<EraRewardRecord<AccountId, Balance>>;
+	}
+}
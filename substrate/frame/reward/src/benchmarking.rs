@@ -0,0 +1,117 @@
+//! Reward pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Reward;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use pallet_staking::{EraRewardPoints, IndividualExposure, ValidatorPrefs};
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+const ERA: EraIndex = 1;
+
+fn reward<T: Config>(amount: u128) -> T::Balance {
+	T::Balance::from(amount)
+}
+
+/// A validator of `ERA` backed by `n` nominators, holding all of the era's points.
+fn validator_with_nominators<T: Config>(n: u32) -> (T::AccountId, Vec<T::AccountId>) {
+	let validator: T::AccountId = account("validator", 0, SEED);
+	let stake: BalanceOf<T> = 1_000_000_000_000u128.into();
+	let nominators: Vec<T::AccountId> = (0..n).map(|i| account("nominator", i, SEED)).collect();
+	let exposure = Exposure {
+		total: stake.saturating_mul((n + 1).into()),
+		own: stake,
+		others: nominators
+			.iter()
+			.map(|who| IndividualExposure { who: who.clone(), value: stake })
+			.collect(),
+	};
+	ErasStakers::<T>::insert(ERA, &validator, exposure);
+	Validators::<T>::insert(&validator, ValidatorPrefs::default());
+	let mut points = EraRewardPoints::<T::AccountId>::default();
+	points.total = 100;
+	points.individual.insert(validator.clone(), 100);
+	ErasRewardPoints::<T>::insert(ERA, points);
+	(validator, nominators)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn get_rewards(v: Linear<0, { MaxWinnersOf::<T>::get().saturating_sub(1) }>) {
+		let queued: Vec<T::AccountId> = (0..v).map(|i| account("queued", i, SEED)).collect();
+		EraRewardsVault::<T>::put(BoundedVec::truncate_from(queued));
+		let validator: T::AccountId = account("validator", 0, SEED);
+		ValidatorRewardAccounts::<T>::insert(&validator, reward::<T>(1_000));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), validator.clone());
+
+		assert!(EraRewardsVault::<T>::get().unwrap().contains(&validator));
+	}
+
+	#[benchmark]
+	fn set_reward_percent_value() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 10);
+
+		assert_eq!(RewardPercent::<T>::get(), Some(10));
+	}
+
+	#[benchmark]
+	fn distribute_calculate(
+		n: Linear<0, { T::MaxNominatorRewardedPerValidator::get() }>,
+	) {
+		let (validator, _) = validator_with_nominators::<T>(n);
+		DistributionValidators::<T>::insert(ERA, BoundedVec::truncate_from(vec![validator.clone()]));
+		CurrentDistribution::<T>::put(DistributionState {
+			era: ERA,
+			phase: DistributionPhase::Calculate,
+			cursor: 0,
+		});
+
+		#[block]
+		{
+			Reward::<T>::distribution_step();
+		}
+
+		assert!(!ValidatorRewardAccounts::<T>::get(&validator).is_zero());
+	}
+
+	#[benchmark]
+	fn distribute_payout(n: Linear<0, { T::MaxNominatorRewardedPerValidator::get() }>) {
+		let (validator, nominators) = validator_with_nominators::<T>(n);
+		T::RewardCurrency::make_free_balance_be(
+			&T::TreasuryAccount::accountid(),
+			T::Balance::max_value() / reward::<T>(2),
+		);
+		ValidatorRewardAccounts::<T>::insert(&validator, reward::<T>(1_000_000));
+		for nominator in &nominators {
+			NominatorRewardAccounts::<T>::insert(&validator, nominator, reward::<T>(1_000_000));
+			NominatorRewardValidators::<T>::insert(nominator, &validator, ());
+		}
+		EraReward::<T>::insert(&validator, BoundedVec::truncate_from(nominators));
+		EraRewardsVault::<T>::put(BoundedVec::truncate_from(vec![validator.clone()]));
+		CurrentDistribution::<T>::put(DistributionState {
+			era: ERA,
+			phase: DistributionPhase::Payout,
+			cursor: 0,
+		});
+
+		#[block]
+		{
+			Reward::<T>::distribution_step();
+		}
+
+		assert!(ValidatorRewardAccounts::<T>::get(&validator).is_zero());
+	}
+
+	impl_benchmark_test_suite!(Reward, crate::mock::new_test_ext(), crate::mock::Test);
+}
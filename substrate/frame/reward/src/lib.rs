@@ -9,21 +9,51 @@ use frame_election_provider_support::{DataProviderBounds, ElectionDataProvider};
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{
-		liquid_staking::StakingAccount, reward::Rewards, Currency, ExistenceRequirement,
-		ExistenceRequirement::KeepAlive, Get, LockableCurrency, ValidatorSet,
+		liquid_staking::{DerivativeRewardAccount, StakingAccount},
+		reward::Rewards,
+		Currency, ExistenceRequirement,
+		ExistenceRequirement::KeepAlive,
+		Get, LockableCurrency, ValidatorSet,
 	},
+	weights::Weight,
+	BoundedVec,
 };
 use pallet_staking::BalanceOf;
 pub use pallet::*;
-use pallet_staking::{CurrentEra, ErasRewardPoints, ErasStakers, Exposure, IndividualExposure, Validators};
+use pallet_staking::{
+	EraPayout, ErasRewardPoints, ErasStakers, ErasTotalStake, Exposure, MaxWinnersOf, Validators,
+};
 use pallet_treasury::TreasuryAccountId;
 use parity_scale_codec::Codec;
 use scale_info::prelude::{fmt::Debug, vec::Vec};
+use frame_support::sp_std::marker::PhantomData;
+use sp_staking::EraIndex;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, Convert, Zero},
-	FixedPointOperand,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32BitUnsigned, Convert, SaturatedConversion, Saturating, Zero},
+	FixedPointOperand, PerThing, Perbill, Rounding,
 };
 use frame_support::ensure;
+#[cfg(any(feature = "try-runtime", test))]
+use sp_runtime::TryRuntimeError;
+
+/// The number of milliseconds in a minute.
+const MILLISECONDS_PER_MINUTE: u128 = 60_000;
+
+/// The longest storage key `PruneCursor` keeps.
+const MAX_PRUNE_CURSOR_LEN: u32 = 512;
+
+pub mod migration;
+pub mod types;
+pub mod weights;
+pub use weights::WeightInfo;
+pub use types::{
+	DistributionPhase, DistributionState, EraRewardRecord, RewardPercentInfo, ShortfallFallback,
+	ValidatorEraReward,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -37,8 +67,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -67,8 +100,6 @@ pub mod pallet {
 			+ TypeInfo
 			+ FixedPointOperand;
 
-		type Precision: Get<u32>;
-
 		#[pallet::constant]
 		type TotalMinutesPerYear: Get<u128>;
 		type EraMinutes: Get<u128>;
@@ -78,6 +109,27 @@ pub mod pallet {
 			Moment = BlockNumberFor<Self>,
 			Balance = Self::Balance,
 		>;
+		/// The number of distribution steps processed in `on_initialize` of every block, so a
+		/// distribution makes progress even when blocks are full. Further steps are processed
+		/// in `on_idle`.
+		#[pallet::constant]
+		type DistributionStepsPerBlock: Get<u32>;
+		/// The payout of an era given the stake and the issuance. `pallet_staking::ConvertCurve`
+		/// pays out along a piecewise linear curve of the staking ratio, `FlatRewardPercent`
+		/// pays `BaseRewardPercent` of the stake regardless of it.
+		type EraPayout: EraPayout<Self::Balance>;
+		/// The most that is paid out for a single era.
+		#[pallet::constant]
+		type MaxEraPayout: Get<Self::Balance>;
+		/// The share of the era payout that is minted into the treasury, the rest is drawn
+		/// from its funds.
+		#[pallet::constant]
+		type MintedShare: Get<Perbill>;
+		/// What to do when the treasury can't cover the payout of a validator.
+		#[pallet::constant]
+		type OnTreasuryShortfall: Get<ShortfallFallback>;
+		/// Weight information for extrinsics and hooks in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// The era reward which are distributed among the validator and nominator
@@ -86,11 +138,17 @@ pub mod pallet {
 	pub type BeneficialRewardRecord<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
 
-	/// Specifies the total nominators of the validator
+	/// Specifies the total nominators of the validator, at most
+	/// `MaxNominatorRewardedPerValidator` of them
 	#[pallet::storage]
 	#[pallet::getter(fn era_reward)]
-	pub type EraReward<T: Config> = 
-	StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>,ValueQuery>;	
+	pub type EraReward<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxNominatorRewardedPerValidator>,
+		ValueQuery,
+	>;
 
 	/// Specifics regarding the rewards distributed within the designated era of the validator
 	#[pallet::storage]
@@ -104,10 +162,86 @@ pub mod pallet {
 	pub type NominatorRewardAccounts<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat,T::AccountId, T::Balance, ValueQuery>;
 
-	/// Era reward accounts
+	/// The validators a nominator has a pending reward from in `NominatorRewardAccounts`,
+	/// keyed by nominator first.
+	#[pallet::storage]
+	pub type NominatorRewardValidators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Era reward accounts, at most one per election winner
 	#[pallet::storage]
 	#[pallet::getter(fn era_reward_vault)]
-	pub type EraRewardsVault<T: Config> = StorageValue<_, Vec<T::AccountId>>;
+	pub type EraRewardsVault<T: Config> = StorageValue<_, BoundedVec<T::AccountId, MaxWinnersOf<T>>>;
+
+	/// Ended eras whose rewards still have to be distributed, oldest first. Eras older than
+	/// `HistoryDepth` can't be computed any more, so at most that many are queued.
+	#[pallet::storage]
+	#[pallet::getter(fn distribution_queue)]
+	pub type DistributionQueue<T: Config> =
+		StorageValue<_, BoundedVec<EraIndex, T::HistoryDepth>, ValueQuery>;
+
+	/// The reward distribution in progress.
+	#[pallet::storage]
+	#[pallet::getter(fn current_distribution)]
+	pub type CurrentDistribution<T> = StorageValue<_, DistributionState, OptionQuery>;
+
+	/// The payout of the era being distributed, set once its rewards start being computed.
+	#[pallet::storage]
+	#[pallet::getter(fn current_era_payout)]
+	pub type CurrentEraPayout<T: Config> = StorageValue<_, T::Balance, OptionQuery>;
+
+	/// The validators of a queued era whose rewards still have to be computed.
+	#[pallet::storage]
+	pub type DistributionValidators<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, BoundedVec<T::AccountId, MaxWinnersOf<T>>, ValueQuery>;
+
+	/// The payout of the eras whose rewards were computed, for the last `HistoryDepth` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn eras_reward_payout)]
+	pub type ErasRewardPayout<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, T::Balance, OptionQuery>;
+
+	/// The reward, commission and points of every rewarded validator per era, for the last
+	/// `HistoryDepth` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn eras_validator_reward)]
+	pub type ErasValidatorRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		ValidatorEraReward<T::Balance>,
+		OptionQuery,
+	>;
+
+	/// The reward of every rewarded nominator per era and validator, for the last
+	/// `HistoryDepth` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn eras_nominator_reward)]
+	pub type ErasNominatorRewards<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Where pruning the reward history of an era continues from, if it took more than one
+	/// step.
+	#[pallet::storage]
+	pub type PruneCursor<T> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_PRUNE_CURSOR_LEN>>, OptionQuery>;
 
 	// Storage for the default value
 	#[pallet::storage]
@@ -125,6 +259,34 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The reward percent paid out from the first era on.
+		pub reward_percent: u32,
+		/// The funds deposited into the treasury account for the rewards of the first eras.
+		pub treasury_funds: T::Balance,
+	}
+
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { reward_percent: DefaultVal::get(), treasury_funds: Zero::zero() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			BaseRewardPercent::<T>::put(self.reward_percent);
+			RewardPercent::<T>::put(self.reward_percent);
+			if !self.treasury_funds.is_zero() {
+				drop(T::RewardCurrency::deposit_creating(
+					&Pallet::<T>::treasury_account(),
+					self.treasury_funds,
+				));
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -134,8 +296,29 @@ pub mod pallet {
 		Rewarded { who: T::AccountId },
 		/// The storage value has been set or updated.
 		ValueSet { value: u32 },
-		/// Insufficient Reward Balance
-		InsufficientRewardBalance
+		/// The payout of the era was computed, `minted` of it was minted into the treasury.
+		EraPayoutComputed { era: EraIndex, payout: T::Balance, minted: T::Balance },
+		/// The treasury couldn't cover the payout of the validator, the shortfall was minted.
+		ShortfallMinted { validator: T::AccountId, amount: T::Balance },
+		/// The treasury couldn't cover the payout of the validator, it stays queued.
+		PayoutDeferred { validator: T::AccountId, shortfall: T::Balance },
+		/// The rewards of the era were queued for distribution.
+		DistributionQueued { era: EraIndex, validators: u32 },
+		/// The distribution of the era's rewards started.
+		DistributionStarted { era: EraIndex },
+		/// A phase of the era's distribution was completed.
+		DistributionPhaseCompleted { era: EraIndex, phase: DistributionPhase, processed: u32 },
+		/// The distribution of the era's rewards was completed.
+		DistributionCompleted { era: EraIndex },
+		/// Paying out the reward of `account`, the validator itself or one of its nominators,
+		/// failed. It stays pending until the validator gets paid out again.
+		PayoutFailed { validator: T::AccountId, account: T::AccountId, error: DispatchError },
+		/// Claiming the reward of a derivative account failed.
+		DerivativeClaimFailed { account: T::AccountId, error: DispatchError },
+		/// Resetting the paid rewards of the derivative accounts failed.
+		DerivativeResetFailed { era: EraIndex, error: DispatchError },
+		/// Applying the reward percent of the next era failed.
+		RewardPercentUpdateFailed { era: EraIndex, error: DispatchError },
 	}
 
 	#[pallet::error]
@@ -146,25 +329,61 @@ pub mod pallet {
 		WaitTheEraToComplete,
 		/// Insufficient Reward Balance
 		InsufficientRewardBalance,
+		/// As many validators as there are election winners are queued already
+		TooManyQueuedValidators,
+		/// `HistoryDepth` eras are waiting for their distribution already
+		DistributionQueueFull,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(2);
+			for _ in 0..T::DistributionStepsPerBlock::get() {
+				match Self::distribution_step() {
+					Some(step_weight) => weight = weight.saturating_add(step_weight),
+					None => break,
+				}
+			}
+			weight
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads(2);
+			while remaining_weight.all_gte(consumed.saturating_add(Self::max_step_weight())) {
+				match Self::distribution_step() {
+					Some(step_weight) => consumed = consumed.saturating_add(step_weight),
+					None => break,
+				}
+			}
+			consumed
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(Weight::zero())]
+		#[pallet::weight(<T as Config>::WeightInfo::get_rewards(MaxWinnersOf::<T>::get()))]
 		pub fn get_rewards(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
 			ensure_signed(origin)?;
 			Self::verify_validator(validator.clone())?;
-			let mut era_reward_accounts = EraRewardsVault::<T>::get().unwrap_or_else(Vec::new);
+			let mut era_reward_accounts = EraRewardsVault::<T>::get().unwrap_or_default();
 			ensure!(!era_reward_accounts.contains(&validator), Error::<T>::WaitTheEraToComplete);
-			era_reward_accounts.push(validator.clone());
+			era_reward_accounts
+				.try_push(validator.clone())
+				.map_err(|_| Error::<T>::TooManyQueuedValidators)?;
 			EraRewardsVault::<T>::put(era_reward_accounts);
 			Self::deposit_event(Event::<T>::Rewarded { who: validator });
 			Ok(())
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(Weight::zero())]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_percent_value())]
 		pub fn set_reward_percent_value(origin: OriginFor<T>, value: u32) -> DispatchResult {
 			ensure_root(origin)?;
 			RewardPercent::<T>::put(value);
@@ -177,90 +396,379 @@ pub mod pallet {
 impl<T: Config> Rewards<T::AccountId> for Pallet<T> {
 	/// List of the validators who will recieve reward after the era
 	fn payout_validators() -> Vec<T::AccountId> {
-		let validators = EraRewardsVault::<T>::get().unwrap_or_else(Vec::new);
-		validators
+		let validators = EraRewardsVault::<T>::get().unwrap_or_default();
+		validators.into_inner()
 	}
 
 	/// Distributing rewards to validators and nominators.
+	///
+	/// Nominators whose payout fails stay in `EraReward`, so they are paid out together with
+	/// the validator's next reward.
 	fn claim_rewards(validator: T::AccountId) -> DispatchResult {
-		let nominators = EraReward::<T>::get(validator.clone());
-		let reward_balance = Self::verify_balance(validator.clone());
-		if let Err(e) = reward_balance {
-			Self::deposit_event(Event::InsufficientRewardBalance);
-			return Err(e);
-		}
+		Self::cover_payout(&validator)?;
 		Self::distribute_reward(validator.clone(), None)?;
 		Self::update_rewarded_accounts(validator.clone())?;
-		if nominators.is_empty() {
-			return Ok(());
-		}
-		nominators.iter().for_each(|nominator| {
-			let _ = Self::distribute_reward(validator.clone(), Some(nominator.clone()));
-		});
-		EraReward::<T>::remove(validator.clone());
-		return Ok(());
-	}
-
-
-	/// Function for computing the rewards of validators and nominators at the end of each era
-	fn calculate_reward() -> DispatchResult {
-		let validators = T::Validators::validators();
-
-		validators.iter().for_each(|validator_id| {
-			let validator = T::ValidatorId::convert(validator_id.clone()).unwrap();
-			let era_validator_points = Self::retrieve_validator_point(validator.clone());
-			let validator_exposure= ErasStakers::<T>::get(Self::current_era(), validator.clone());
-			let annual_validator_stake_reward = Self::calculate_annual_validator_reward(validator_exposure.clone());
-			let total_era_reward = Self::compute_era_reward_from_annual(annual_validator_stake_reward.into());
-			let validator_era_reward = Self::calculate_validator_era_reward(era_validator_points.into(), total_era_reward as f64);
-			let nominators = Self::check_nominators(validator.clone());
-			if nominators.is_empty() {
-				Self::allocate_rewards(
-					validator.clone(),
-					None,
-					(validator_era_reward as u128).into(),
-				);
-				return;
-			}
-			let (total_validator_reward,remaining_reward_for_nominators) = Self::calculate_validator_commission_reward(validator.clone(),validator_era_reward,validator_exposure.clone());
-			Self::allocate_rewards(
-				validator.clone(),
-				None,
-				(total_validator_reward as u128).into(),
-			);
-			if remaining_reward_for_nominators.is_zero(){
-				return;
+		let mut unpaid = EraReward::<T>::get(validator.clone());
+		unpaid.retain(|nominator| {
+			match Self::distribute_reward(validator.clone(), Some(nominator.clone())) {
+				Ok(()) => false,
+				Err(error) => {
+					Self::deposit_event(Event::PayoutFailed {
+						validator: validator.clone(),
+						account: nominator.clone(),
+						error,
+					});
+					true
+				},
 			}
-			nominators.iter().for_each(|nominator| {
-				let mut current_nominators = EraReward::<T>::get(validator.clone());
-				if !current_nominators.contains(&nominator.who.clone()) {
-					current_nominators.push(nominator.who.clone());
-					EraReward::<T>::insert(validator.clone(), current_nominators);
-				}
-				let nominator_stake = nominator.value;
-				let nominator_reward = Self::calculate_reward_share(
-					nominator_stake.into(),
-					validator_exposure.total.into(),
-					remaining_reward_for_nominators.into(),
-				);
-				Self::allocate_rewards(
-					validator.clone(),
-					Some(nominator.who.clone()),
-					(nominator_reward as u128).into(),
-				);
-			});
 		});
+		if unpaid.is_empty() {
+			EraReward::<T>::remove(validator);
+		} else {
+			EraReward::<T>::insert(validator, unpaid);
+		}
+		Ok(())
+	}
+
+
+	/// Queue the rewards of the ended era, they get computed and paid out over the following
+	/// blocks.
+	fn queue_distribution(era: EraIndex) -> DispatchResult {
+		let validators: Vec<T::AccountId> = T::Validators::validators()
+			.into_iter()
+			.filter_map(T::ValidatorId::convert)
+			.collect();
+		let count = validators.len() as u32;
+		DistributionQueue::<T>::try_append(era).map_err(|_| Error::<T>::DistributionQueueFull)?;
+		DistributionValidators::<T>::insert(era, BoundedVec::truncate_from(validators));
+		Self::deposit_event(Event::DistributionQueued { era, validators: count });
 		Ok(())
 	}
 
 	fn reward_percent() -> DispatchResult {
-		let new_reward_percent = RewardPercent::<T>::get().unwrap_or(8);
+		let new_reward_percent = RewardPercent::<T>::get().unwrap_or_else(DefaultVal::get);
 		BaseRewardPercent::<T>::put(new_reward_percent);
 		Ok(())
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Process the next step of the reward distribution, starting the next queued era if none
+	/// is in progress.
+	///
+	/// Returns the weight consumed, or `None` if there is nothing to distribute.
+	fn distribution_step() -> Option<Weight> {
+		let db = T::DbWeight::get();
+		let Some(mut state) = CurrentDistribution::<T>::get() else {
+			let mut queue = DistributionQueue::<T>::get();
+			if queue.is_empty() {
+				return None;
+			}
+			let era = queue.remove(0);
+			DistributionQueue::<T>::put(queue);
+			CurrentDistribution::<T>::put(DistributionState {
+				era,
+				phase: DistributionPhase::Calculate,
+				cursor: 0,
+			});
+			Self::deposit_event(Event::DistributionStarted { era });
+			return Some(Self::start_step_weight());
+		};
+
+		let weight = match state.phase {
+			DistributionPhase::Calculate => {
+				let (era_payout, payout_weight) = match CurrentEraPayout::<T>::get() {
+					Some(era_payout) => (era_payout, Weight::zero()),
+					None => (Self::begin_era_payout(state.era), Self::begin_payout_weight()),
+				};
+				let validators = DistributionValidators::<T>::get(state.era);
+				let weight = if let Some(validator) = validators.get(state.cursor as usize) {
+					let nominators = Self::calculate_validator_reward(
+						state.era,
+						validator.clone(),
+						era_payout.into(),
+					);
+					state.cursor += 1;
+					Self::calculate_step_weight(nominators)
+				} else {
+					DistributionValidators::<T>::remove(state.era);
+					CurrentEraPayout::<T>::kill();
+					if let Err(error) = Self::reward_percent() {
+						Self::deposit_event(Event::RewardPercentUpdateFailed {
+							era: state.era,
+							error,
+						});
+					}
+					Self::complete_phase(&mut state, DistributionPhase::Payout);
+					Self::complete_calculate_weight()
+				};
+				weight.saturating_add(payout_weight)
+			},
+			DistributionPhase::Payout => {
+				let queued = Self::payout_validators();
+				if let Some(validator) = queued.get(state.cursor as usize) {
+					let nominators = EraReward::<T>::decode_len(validator).unwrap_or(0) as u32;
+					// A paid validator leaves the queue, a failed one is skipped over.
+					if let Err(error) = Self::claim_rewards(validator.clone()) {
+						Self::deposit_event(Event::PayoutFailed {
+							validator: validator.clone(),
+							account: validator.clone(),
+							error,
+						});
+						state.cursor += 1;
+					}
+					Self::payout_step_weight(nominators)
+				} else {
+					Self::complete_phase(&mut state, DistributionPhase::Derivative);
+					db.reads_writes(1, 1)
+				}
+			},
+			DistributionPhase::Derivative => {
+				let accounts = <T as pallet_staking::Config>::DerivativeReward::derivative_reward_accounts();
+				if let Some(account) = accounts.get(state.cursor as usize) {
+					if let Err(error) =
+						<T as pallet_staking::Config>::DerivativeReward::claim_derivative(account.clone())
+					{
+						Self::deposit_event(Event::DerivativeClaimFailed {
+							account: account.clone(),
+							error,
+						});
+					}
+					state.cursor += 1;
+					Self::derivative_step_weight()
+				} else {
+					if !accounts.is_empty() {
+						if let Err(error) =
+							<T as pallet_staking::Config>::DerivativeReward::reset_reward()
+						{
+							Self::deposit_event(Event::DerivativeResetFailed {
+								era: state.era,
+								error,
+							});
+						}
+					}
+					Self::complete_phase(&mut state, DistributionPhase::Prune);
+					Self::derivative_step_weight()
+				}
+			},
+			DistributionPhase::Prune => {
+				// The cursor counts the eras pruned.
+				let stale = state.era.checked_sub(T::HistoryDepth::get());
+				match stale.and_then(Self::prune_history) {
+					Some(true) => state.cursor += 1,
+					Some(false) => {},
+					None => {
+						CurrentDistribution::<T>::kill();
+						Self::deposit_event(Event::DistributionPhaseCompleted {
+							era: state.era,
+							phase: DistributionPhase::Prune,
+							processed: state.cursor,
+						});
+						Self::deposit_event(Event::DistributionCompleted { era: state.era });
+						return Some(Self::prune_step_weight());
+					},
+				}
+				Self::prune_step_weight()
+			},
+		};
+		CurrentDistribution::<T>::put(state);
+		Some(weight)
+	}
+
+	/// Move the distribution on to the `next` phase.
+	fn complete_phase(state: &mut DistributionState, next: DistributionPhase) {
+		Self::deposit_event(Event::DistributionPhaseCompleted {
+			era: state.era,
+			phase: state.phase,
+			processed: state.cursor,
+		});
+		state.phase = next;
+		state.cursor = 0;
+	}
+
+	/// The weight of starting the distribution of the next queued era.
+	fn start_step_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	/// The weight of computing the payout of an era and minting its share into the treasury.
+	fn begin_payout_weight() -> Weight {
+		T::DbWeight::get().reads_writes(4, 4)
+	}
+
+	/// The weight of computing the rewards of a validator with `nominators` nominators.
+	fn calculate_step_weight(nominators: u32) -> Weight {
+		<T as Config>::WeightInfo::distribute_calculate(nominators)
+	}
+
+	/// The weight of ending the calculate phase and applying the next reward percent.
+	fn complete_calculate_weight() -> Weight {
+		T::DbWeight::get().reads_writes(3, 5)
+	}
+
+	/// The weight of paying out a validator with `nominators` nominators.
+	fn payout_step_weight(nominators: u32) -> Weight {
+		<T as Config>::WeightInfo::distribute_payout(nominators)
+	}
+
+	/// The weight of a step of the derivative phase, charged like paying out a validator with
+	/// the most nominators: compounding or resetting the reward of the liquid staking vault
+	/// only goes over the few validators it nominates.
+	fn derivative_step_weight() -> Weight {
+		Self::payout_step_weight(T::MaxNominatorRewardedPerValidator::get())
+	}
+
+	/// The worst case weight of a single distribution step, whatever phase it is in.
+	///
+	/// The first calculate step of an era also begins its payout, and the step ending the
+	/// calculate phase applies the next reward percent.
+	fn max_step_weight() -> Weight {
+		let nominators = T::MaxNominatorRewardedPerValidator::get();
+		let calculate = Self::begin_payout_weight().saturating_add(
+			Self::calculate_step_weight(nominators).max(Self::complete_calculate_weight()),
+		);
+		Self::start_step_weight()
+			.max(calculate)
+			.max(Self::payout_step_weight(nominators))
+			.max(Self::derivative_step_weight())
+			.max(Self::prune_step_weight())
+	}
+
+	/// Compute the payout of `era` and mint the `MintedShare` of it into the treasury.
+	fn begin_era_payout(era: EraIndex) -> T::Balance {
+		let total_staked: u128 = ErasTotalStake::<T>::get(era).into();
+		let era_payout = Self::era_payout(total_staked.into(), Self::era_duration_millis());
+		let minted = T::MintedShare::get().mul_floor(era_payout);
+		if !minted.is_zero() {
+			drop(T::RewardCurrency::deposit_creating(&Self::treasury_account(), minted));
+		}
+		CurrentEraPayout::<T>::put(era_payout);
+		ErasRewardPayout::<T>::insert(era, era_payout);
+		Self::deposit_event(Event::EraPayoutComputed { era, payout: era_payout, minted });
+		era_payout
+	}
+
+	/// Drop part of the reward history of the oldest era up to `era`, like `pallet_staking`
+	/// does with its own era information once it is older than `HistoryDepth`.
+	///
+	/// At most `prune_limit` entries are cleared per call: the validator rewards of the era
+	/// first, then its nominator rewards and last its payout. `PruneCursor` keeps where the
+	/// clearing continues from, so several calls in the same block make progress.
+	///
+	/// Returns whether the era was pruned completely, or `None` if no era up to `era` is left.
+	fn prune_history(era: EraIndex) -> Option<bool> {
+		// Eras that were never distributed leave gaps, so every stale era is looked for.
+		let stale = ErasRewardPayout::<T>::iter_keys().filter(|stale| *stale <= era).min()?;
+		let limit = Self::prune_limit();
+		let cursor = PruneCursor::<T>::take();
+		let cursor = cursor.as_ref().map(|cursor| cursor.as_slice());
+		let (result, nominators) = if ErasValidatorRewards::<T>::iter_key_prefix(stale)
+			.next()
+			.is_some()
+		{
+			(ErasValidatorRewards::<T>::clear_prefix(stale, limit, cursor), false)
+		} else {
+			(ErasNominatorRewards::<T>::clear_prefix((stale,), limit, cursor), true)
+		};
+		match result.maybe_cursor {
+			Some(cursor) => {
+				// A cursor too long to be kept only makes the next call in the same block
+				// start over on the entries cleared already.
+				if let Ok(cursor) = BoundedVec::try_from(cursor) {
+					PruneCursor::<T>::put(cursor);
+				}
+				Some(false)
+			},
+			None if nominators => {
+				ErasRewardPayout::<T>::remove(stale);
+				Some(true)
+			},
+			None => Some(false),
+		}
+	}
+
+	/// The number of reward history entries a single prune step clears, as many as a
+	/// validator has rewarded nominators at most.
+	fn prune_limit() -> u32 {
+		T::MaxNominatorRewardedPerValidator::get().max(1)
+	}
+
+	/// The worst case weight of a prune step.
+	fn prune_step_weight() -> Weight {
+		let limit = Self::prune_limit() as u64;
+		// The payouts of the history, the cursor and the probe of the validator rewards are
+		// read, then up to `limit` entries, the cursor and the payout are cleared.
+		T::DbWeight::get().reads_writes(T::HistoryDepth::get() as u64 + 3 + limit, limit + 2)
+	}
+
+	/// The payout of an era of `era_duration_millis` with `total_staked` at stake, capped at
+	/// `MaxEraPayout`.
+	fn era_payout(total_staked: T::Balance, era_duration_millis: u64) -> T::Balance {
+		let (payout, _rest) = T::EraPayout::era_payout(
+			total_staked,
+			T::RewardCurrency::total_issuance(),
+			era_duration_millis,
+		);
+		payout.min(T::MaxEraPayout::get())
+	}
+
+	/// The duration of an era in milliseconds.
+	fn era_duration_millis() -> u64 {
+		T::EraMinutes::get().saturating_mul(MILLISECONDS_PER_MINUTE).saturated_into()
+	}
+
+	/// Compute the rewards of `validator` and its nominators for `era`, whose payout is
+	/// `era_payout`.
+	///
+	/// Returns the number of nominators backing the validator.
+	fn calculate_validator_reward(era: EraIndex, validator: T::AccountId, era_payout: u128) -> u32 {
+		let era_validator_points = Self::retrieve_validator_point(era, validator.clone());
+		let validator_exposure = ErasStakers::<T>::get(era, validator.clone());
+		let nominators = validator_exposure.others.len() as u32;
+		let validator_era_reward =
+			Self::calculate_validator_era_reward(era, era_validator_points, era_payout);
+		let commission = Validators::<T>::get(validator.clone()).commission;
+		let (validator_reward, nominator_rewards) = split_era_reward(
+			validator_era_reward,
+			commission,
+			validator_exposure.total.into(),
+			validator_exposure
+				.others
+				.iter()
+				.map(|nominator| (nominator.who.clone(), nominator.value.into()))
+				.collect(),
+		);
+		Self::allocate_rewards(validator.clone(), None, validator_reward.into());
+		ErasValidatorRewards::<T>::insert(
+			era,
+			validator.clone(),
+			ValidatorEraReward {
+				reward: validator_reward.into(),
+				commission,
+				points: era_validator_points,
+			},
+		);
+		nominator_rewards.into_iter().for_each(|(nominator, nominator_reward)| {
+			if nominator_reward.is_zero() {
+				return;
+			}
+			let mut current_nominators = EraReward::<T>::get(validator.clone());
+			if !current_nominators.contains(&nominator) {
+				// Like on `pallet_staking`, only `MaxNominatorRewardedPerValidator` nominators
+				// of a validator get rewarded.
+				if current_nominators.try_push(nominator.clone()).is_err() {
+					return;
+				}
+				EraReward::<T>::insert(validator.clone(), current_nominators);
+			}
+			ErasNominatorRewards::<T>::insert(
+				(era, validator.clone(), nominator.clone()),
+				T::Balance::from(nominator_reward),
+			);
+			Self::allocate_rewards(validator.clone(), Some(nominator), nominator_reward.into());
+		});
+		nominators
+	}
 
 	/// Transfer an amount to the accounts with respecting the `keep_alive` requirements.
 	fn transfer(
@@ -285,24 +793,41 @@ impl<T: Config> Pallet<T> {
 		Self::check_reward(validator)
 	}
 
-	/// Verify the balance of reward 
-	fn verify_balance(validator: T::AccountId) -> DispatchResult {
-		let validator_reward = ValidatorRewardAccounts::<T>::get(validator.clone());
-		let free_balance =T::RewardCurrency::free_balance(&Self::treasury_account());
-		let nominators = EraReward::<T>::get(validator.clone());
-		if nominators.is_empty(){
-			ensure!(free_balance >= validator_reward,Error::<T>::InsufficientRewardBalance);
+	/// Make sure the treasury can pay out `validator` and its nominators, falling back on
+	/// `OnTreasuryShortfall` if it can't.
+	fn cover_payout(validator: &T::AccountId) -> DispatchResult {
+		let required = EraReward::<T>::get(validator).iter().fold(
+			ValidatorRewardAccounts::<T>::get(validator),
+			|acc, nominator| {
+				acc.saturating_add(NominatorRewardAccounts::<T>::get(validator, nominator))
+			},
+		);
+		// Payouts keep the treasury alive.
+		let shortfall = required
+			.saturating_add(T::RewardCurrency::minimum_balance())
+			.saturating_sub(T::RewardCurrency::free_balance(&Self::treasury_account()));
+		if shortfall.is_zero() {
 			return Ok(());
 		}
-		let mut total_nominator_reward:T::Balance = 0u128.into();
-		nominators.iter().for_each(|nominator| {
-			let nominator_reward = NominatorRewardAccounts::<T>::get(validator.clone(),nominator);
-			total_nominator_reward += nominator_reward;
-		});
-		ensure!(free_balance >= total_nominator_reward + validator_reward,Error::<T>::InsufficientRewardBalance);
-		return Ok(());
+		match T::OnTreasuryShortfall::get() {
+			ShortfallFallback::Mint => {
+				drop(T::RewardCurrency::deposit_creating(&Self::treasury_account(), shortfall));
+				Self::deposit_event(Event::ShortfallMinted {
+					validator: validator.clone(),
+					amount: shortfall,
+				});
+				Ok(())
+			},
+			ShortfallFallback::Defer => {
+				Self::deposit_event(Event::PayoutDeferred {
+					validator: validator.clone(),
+					shortfall,
+				});
+				Err(Error::<T>::InsufficientRewardBalance.into())
+			},
+		}
 	}
-	
+
 
 	/// Check if the reward exist
 	fn check_reward(reward: T::Balance) -> DispatchResult {
@@ -313,33 +838,9 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Calculates the total reward for a validator based on their commission and stake, as well as the remaining reward for nominators.
-	fn calculate_validator_commission_reward(validator: T::AccountId, validator_era_reward: f64, exposure : Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>,) -> (f64,f64) {
-		let validator_commission = Self::validator_commission(validator.clone());
-		let validator_share = ((validator_era_reward as f64) * (validator_commission as f64)) / 100.0;
-		let validator_stake = exposure.own;
-		let remaining_reward = validator_era_reward - validator_share;
-		let validator_own_share_reward = Self::calculate_reward_share(
-			validator_stake.into(),
-			exposure.total.into(),
-			remaining_reward,
-		);
-		let total_validator_reward = validator_share + validator_own_share_reward;
-		(total_validator_reward,remaining_reward)
-	}
-
-	/// Get the commission for the validator.
-	fn validator_commission(validator: T::AccountId) -> u32 {
-		let validator_prefs = Validators::<T>::get(validator.clone());
-		let validator_commission = validator_prefs.commission.deconstruct();
-		let precision: u32 = 7;
-		let scaled_commission: u32 = validator_commission / (10u32).pow(precision);
-		scaled_commission
-	}
-
 	/// Update the list of validators who have already been rewarded.
 	fn update_rewarded_accounts(account: T::AccountId) -> DispatchResult {
-		let mut era_reward_accounts = EraRewardsVault::<T>::get().unwrap_or_else(Vec::new);
+		let mut era_reward_accounts = EraRewardsVault::<T>::get().unwrap_or_default();
 		if let Some(index) = era_reward_accounts.iter().position(|a| a == &account.clone()) {
 			era_reward_accounts.remove(index);
 		}
@@ -347,32 +848,213 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Compute the annual reward of the validator's total stake for distribution
-	pub fn calculate_annual_validator_reward(exposure : Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>) -> u128 {
-		let validator_stake = exposure.total;
-		let divisor: T::CurrencyBalance = 100u128.into();
-		let reward_percent = (validator_stake * BaseRewardPercent::<T>::get().into()) / divisor;
-		reward_percent.into()
+	/// Compute the annual reward of the validator's total stake for distribution.
+	///
+	/// Rounds down.
+	pub fn calculate_annual_validator_reward(
+		exposure: Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+	) -> u128 {
+		Self::annual_reward(exposure.total.into())
 	}
 
-	/// Compute the total era reward allotted to the validator
-	pub fn compute_era_reward_from_annual(reward_percent: u128) -> f64 {
-		let total_minutes_per_year = T::TotalMinutesPerYear::get();
-		let era_minutes = T::EraMinutes::get();
-		let era = total_minutes_per_year / era_minutes;
-		let total_reward = reward_percent;
-		let era_reward = total_reward as f64 / era as f64;
-		era_reward 
+	/// Compute the annual reward earned by `stake` at the current `BaseRewardPercent`.
+	///
+	/// Rounds down.
+	pub fn annual_reward(stake: u128) -> u128 {
+		Perbill::from_percent(BaseRewardPercent::<T>::get()).mul_floor(stake)
+	}
+
+	/// Estimate the reward `stake` earns over one era at the current staking ratio, ignoring
+	/// era points and commission.
+	///
+	/// Rounds down.
+	pub fn estimate_era_reward(stake: u128) -> u128 {
+		let total_staked = Self::active_total_stake().max(stake);
+		let era_payout = Self::era_payout(total_staked.into(), Self::era_duration_millis());
+		multiply_by_rational_with_rounding(era_payout.into(), stake, total_staked, Rounding::Down)
+			.unwrap_or_default()
+	}
+
+	/// The total stake of the active era.
+	fn active_total_stake() -> u128 {
+		pallet_staking::ActiveEra::<T>::get()
+			.map(|active_era| ErasTotalStake::<T>::get(active_era.index).into())
+			.unwrap_or_default()
 	}
 
-	/// Compute the reward share for a validator and nominator based on their stake.
-	pub fn calculate_reward_share(share: u128, total_stake: u128, reward: f64) -> f64 {
-		let precision = T::Precision::get();
-		let scaled_share = share / (10u128).pow(precision);
-		let scaled_total_stake: u64 = (total_stake / (10u128).pow(precision)) as u64;
-		let division: f64 = ((scaled_share as f64) / (scaled_total_stake as f64)) as f64;
-		let total_reward = division * reward;
-		total_reward
+	/// The pending reward of `validator`, for the runtime API.
+	pub fn api_validator_pending_reward(validator: T::AccountId) -> T::Balance {
+		ValidatorRewardAccounts::<T>::get(validator)
+	}
+
+	/// The pending rewards of `nominator` per validator, for the runtime API.
+	///
+	/// Only reads the validators of `nominator` in `NominatorRewardValidators`.
+	pub fn api_nominator_pending_rewards(
+		nominator: T::AccountId,
+	) -> Vec<(T::AccountId, T::Balance)> {
+		NominatorRewardValidators::<T>::iter_key_prefix(&nominator)
+			.map(|validator| {
+				let reward = NominatorRewardAccounts::<T>::get(&validator, &nominator);
+				(validator, reward)
+			})
+			.collect()
+	}
+
+	/// Drop the pending reward of `nominator` from `validator`.
+	pub fn remove_nominator_reward(validator: &T::AccountId, nominator: &T::AccountId) {
+		NominatorRewardAccounts::<T>::remove(validator, nominator);
+		NominatorRewardValidators::<T>::remove(nominator, validator);
+	}
+
+	/// The validators whose rewards get paid out at the end of the era, for the runtime API.
+	pub fn api_queued_validators() -> Vec<T::AccountId> {
+		EraRewardsVault::<T>::get().unwrap_or_default().into_inner()
+	}
+
+	/// The rewards `who` earned in the eras kept in the reward history, as a validator and as a
+	/// nominator, oldest first. For the runtime API.
+	///
+	/// Iterates over the whole reward history, so this must not be used on-chain.
+	pub fn api_reward_history(who: T::AccountId) -> Vec<EraRewardRecord<T::AccountId, T::Balance>> {
+		let mut records: Vec<_> = ErasValidatorRewards::<T>::iter()
+			.filter(|(_, validator, _)| *validator == who)
+			.map(|(era, validator, reward)| EraRewardRecord {
+				era,
+				validator,
+				reward: reward.reward,
+				commission: reward.commission,
+				points: reward.points,
+			})
+			.collect();
+		records.extend(
+			ErasNominatorRewards::<T>::iter()
+				.filter(|((_, _, nominator), _)| *nominator == who)
+				.map(|((era, validator, _), reward)| {
+					let ValidatorEraReward { commission, points, .. } =
+						ErasValidatorRewards::<T>::get(era, &validator).unwrap_or_default();
+					EraRewardRecord { era, validator, reward, commission, points }
+				}),
+		);
+		records.sort_by_key(|record| record.era);
+		records
+	}
+
+	/// The current and upcoming reward percent, for the runtime API.
+	pub fn api_reward_percent() -> RewardPercentInfo {
+		RewardPercentInfo {
+			current: BaseRewardPercent::<T>::get(),
+			next: RewardPercent::<T>::get().unwrap_or_else(DefaultVal::get),
+		}
+	}
+
+	/// The projected annual return of staking behind `validator`, for the runtime API.
+	///
+	/// This is the payout of a year of eras at the current staking ratio per unit of stake,
+	/// net of the validator's commission.
+	pub fn api_projected_apr(validator: T::AccountId) -> Perbill {
+		let commission = Validators::<T>::get(validator).commission;
+		let total_staked = Self::active_total_stake();
+		if total_staked.is_zero() {
+			return Perbill::zero();
+		}
+		let era_payout = Self::era_payout(total_staked.into(), Self::era_duration_millis());
+		let yearly_payout = multiply_by_rational_with_rounding(
+			era_payout.into(),
+			T::TotalMinutesPerYear::get(),
+			T::EraMinutes::get().max(1),
+			Rounding::Down,
+		)
+		.unwrap_or(u128::MAX);
+		Perbill::from_rational(yearly_payout.min(total_staked), total_staked)
+			.saturating_mul(commission.left_from_one())
+	}
+
+	/// Check that the pending rewards are consistent and covered by the treasury.
+	///
+	/// * Every pending nominator reward belongs to a nominator listed in `EraReward`. The
+	///   liquid staking vault is the exception: its paid rewards stay recorded until they are
+	///   compounded, and they are not owed any more.
+	/// * `NominatorRewardValidators` indexes exactly the pending nominator rewards.
+	/// * Unless shortfalls get minted, the treasury can pay out every pending validator and
+	///   nominator reward.
+	/// * No validator is queued for payout twice, and the distribution queue holds every era
+	///   once, in order, without the era being distributed.
+	/// * The reward history spans at most `HistoryDepth` eras, and only eras whose payout it
+	///   records.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), TryRuntimeError> {
+		let vault = T::LiquidStakeVault::staking_account();
+		let mut owed = ValidatorRewardAccounts::<T>::iter_values()
+			.fold(T::Balance::zero(), |acc, reward| acc.saturating_add(reward));
+		for (validator, nominator, reward) in NominatorRewardAccounts::<T>::iter() {
+			ensure!(
+				NominatorRewardValidators::<T>::contains_key(&nominator, &validator),
+				"a nominator reward is not indexed"
+			);
+			if EraReward::<T>::get(&validator).contains(&nominator) {
+				owed = owed.saturating_add(reward);
+			} else {
+				ensure!(nominator == vault, "a nominator reward is not listed in EraReward");
+			}
+		}
+		ensure!(
+			T::OnTreasuryShortfall::get() == ShortfallFallback::Mint ||
+				T::RewardCurrency::free_balance(&Self::treasury_account()) >= owed,
+			"the treasury can't cover the pending rewards"
+		);
+		ensure!(
+			NominatorRewardValidators::<T>::iter_keys().all(|(nominator, validator)| {
+				NominatorRewardAccounts::<T>::contains_key(&validator, &nominator)
+			}),
+			"an indexed nominator reward is not pending"
+		);
+
+		let queued = EraRewardsVault::<T>::get().unwrap_or_default();
+		for (index, validator) in queued.iter().enumerate() {
+			ensure!(
+				!queued[index + 1..].contains(validator),
+				"a validator is queued for payout twice"
+			);
+		}
+
+		let eras = DistributionQueue::<T>::get();
+		ensure!(
+			eras.windows(2).all(|pair| pair[0] < pair[1]),
+			"the distribution queue is not ordered"
+		);
+		if let Some(state) = CurrentDistribution::<T>::get() {
+			ensure!(!eras.contains(&state.era), "the era being distributed is still queued");
+		}
+
+		let history: Vec<EraIndex> = ErasRewardPayout::<T>::iter_keys().collect();
+		if let (Some(oldest), Some(latest)) = (history.iter().min(), history.iter().max()) {
+			ensure!(
+				latest - oldest < T::HistoryDepth::get(),
+				"the reward history is older than HistoryDepth"
+			);
+		}
+		ensure!(
+			ErasValidatorRewards::<T>::iter_keys().all(|(era, _)| history.contains(&era)) &&
+				ErasNominatorRewards::<T>::iter_keys().all(|(era, _, _)| history.contains(&era)),
+			"the reward history has rewards of an era without a payout"
+		);
+		Ok(())
+	}
+
+	/// Compute the share of an annual reward that is paid out in a single era.
+	///
+	/// Rounds down.
+	pub fn compute_era_reward_from_annual(annual_reward: u128) -> u128 {
+		let total_minutes_per_year = T::TotalMinutesPerYear::get();
+		let era_minutes = T::EraMinutes::get().min(total_minutes_per_year);
+		multiply_by_rational_with_rounding(
+			annual_reward,
+			era_minutes,
+			total_minutes_per_year,
+			Rounding::Down,
+		)
+		.unwrap_or_default()
 	}
 
 	/// Allocates rewards to the specified validator.
@@ -382,6 +1064,7 @@ impl<T: Config> Pallet<T> {
 		reward: T::Balance
 	) {
 		if let Some(nominator) = nominator {
+			NominatorRewardValidators::<T>::insert(&nominator, &validator, ());
 			NominatorRewardAccounts::<T>::mutate(validator, nominator.clone(), |earlier_reward| {
 				*earlier_reward += reward;
 			})
@@ -392,14 +1075,16 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Retrieves the points of the validator.
-	fn retrieve_validator_point(account: T::AccountId) -> u32 {
-		let era_reward_points = <ErasRewardPoints<T>>::get(Self::active_era());
+	/// Retrieves the points of the validator in `era`.
+	fn retrieve_validator_point(era: EraIndex, account: T::AccountId) -> u32 {
+		let era_reward_points = <ErasRewardPoints<T>>::get(era);
 		let validator_points = era_reward_points.individual.get(&account).unwrap_or(&0);
 		*validator_points
 	}
 
 	/// Distributes rewards to the validator and nominators.
+	///
+	/// The paid reward of the liquid staking vault stays recorded until it is compounded.
 	fn distribute_reward(
 		validator: T::AccountId,
 		nominator: Option<T::AccountId>
@@ -408,18 +1093,8 @@ impl<T: Config> Pallet<T> {
 			let reward = NominatorRewardAccounts::<T>::get(validator.clone(), nominator.clone());
 			Self::check_reward(reward)?;
 			Self::transfer(Self::treasury_account(), nominator.clone(), reward, KeepAlive)?;
-			let staking_account = T::LiquidStakeVault::staking_account();
-			if nominator != staking_account {
-				let mut nominators = EraReward::<T>::get(validator.clone());
-				if
-					let Some(index) = nominators
-						.iter()
-						.position(|nominator_account| nominator_account == &nominator.clone())
-				{
-					nominators.remove(index);
-				}
-				EraReward::<T>::insert(validator.clone(),nominators.clone());
-				NominatorRewardAccounts::<T>::remove(validator.clone(), nominator.clone());
+			if nominator != T::LiquidStakeVault::staking_account() {
+				Self::remove_nominator_reward(&validator, &nominator);
 			}
 			(reward, nominator)
 		} else {
@@ -433,17 +1108,6 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Current era index
-	fn current_era() -> u32 {
-		CurrentEra::<T>::get().unwrap_or(0)
-	}
-
-	/// Active era index
-	fn active_era() -> u32 {
-		let active_era = pallet_staking::Pallet::<T>::active_era().unwrap();
-		let era = active_era.index;
-		era
-	}
 	/// Store the received reward for a specific account.
 	fn store_reward_received(account: T::AccountId, reward: T::Balance) {
 		BeneficialRewardRecord::<T>::mutate(account.clone(), |earlier_reward| {
@@ -451,20 +1115,74 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
-	/// Compute the reward of the validator within the era 
-	fn calculate_validator_era_reward(validator_points: u32, era_reward: f64) -> f64 {
-		let era_reward_points = <ErasRewardPoints<T>>::get(Self::active_era());
-		let total_points = era_reward_points.total as u32;
-		let reward = ((validator_points as f64) / (total_points as f64)) * (era_reward as f64);
-		reward
-	}	
+	/// Compute the reward of the validator within the era, pro rata to its era points.
+	///
+	/// Rounds down.
+	fn calculate_validator_era_reward(
+		era: EraIndex,
+		validator_points: u32,
+		era_reward: u128,
+	) -> u128 {
+		let era_reward_points = <ErasRewardPoints<T>>::get(era);
+		multiply_by_rational_with_rounding(
+			era_reward,
+			validator_points.into(),
+			era_reward_points.total.into(),
+			Rounding::Down,
+		)
+		.unwrap_or_default()
+	}
 
-	/// Determine whether the validator has nominators in the current era.
-	fn check_nominators(
-		validator: T::AccountId,
-	) -> Vec<IndividualExposure<T::AccountId, <T as pallet_staking::Config>::CurrencyBalance>> {
-		let exposure = ErasStakers::<T>::get(Self::current_era(), validator.clone());
-		let nominators = exposure.others;
-		nominators
+}
+
+/// Pays `BaseRewardPercent` of the stake per year, whatever the staking ratio.
+pub struct FlatRewardPercent<T>(PhantomData<T>);
+impl<T: Config> EraPayout<T::Balance> for FlatRewardPercent<T> {
+	fn era_payout(
+		total_staked: T::Balance,
+		_total_issuance: T::Balance,
+		era_duration_millis: u64,
+	) -> (T::Balance, T::Balance) {
+		let annual_reward = Pallet::<T>::annual_reward(total_staked.into());
+		let payout = multiply_by_rational_with_rounding(
+			annual_reward,
+			era_duration_millis.into(),
+			T::TotalMinutesPerYear::get().saturating_mul(MILLISECONDS_PER_MINUTE),
+			Rounding::Down,
+		)
+		.unwrap_or_default();
+		(payout.into(), Zero::zero())
 	}
-}
\ No newline at end of file
+}
+
+/// Split the era reward of a validator between the validator and the nominators backing it.
+///
+/// The validator takes its `commission` off the top and the rest is shared pro rata to the
+/// stakes in the exposure, `total` being the validator's own stake plus every stake in
+/// `others`. Nominator shares are rounded down and the validator receives everything they
+/// don't, including the rounding dust, so the payouts always add up to exactly `era_reward`.
+pub fn split_era_reward<AccountId>(
+	era_reward: u128,
+	commission: Perbill,
+	total: u128,
+	others: Vec<(AccountId, u128)>,
+) -> (u128, Vec<(AccountId, u128)>) {
+	let validator_commission = commission.mul_floor(era_reward);
+	let remaining_reward = era_reward.saturating_sub(validator_commission);
+	if total.is_zero() {
+		return (era_reward, others.into_iter().map(|(who, _)| (who, 0)).collect())
+	}
+
+	let pro_rata = |stake: u128| {
+		multiply_by_rational_with_rounding(remaining_reward, stake, total, Rounding::Down)
+			.unwrap_or_default()
+	};
+	let nominator_rewards: Vec<(AccountId, u128)> =
+		others.into_iter().map(|(who, stake)| (who, pro_rata(stake))).collect();
+	let total_nominator_reward = nominator_rewards
+		.iter()
+		.fold(0u128, |acc, (_, reward)| acc.saturating_add(*reward));
+	let validator_reward = era_reward.saturating_sub(total_nominator_reward);
+
+	(validator_reward, nominator_rewards)
+}
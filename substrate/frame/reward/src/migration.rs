@@ -0,0 +1,159 @@
+//! Storage migrations for the reward pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	#[storage_alias]
+	type EraReward<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Vec<<T as frame_system::Config>::AccountId>,
+	>;
+
+	#[storage_alias]
+	type EraRewardsVault<T: Config> =
+		StorageValue<Pallet<T>, Vec<<T as frame_system::Config>::AccountId>>;
+
+	#[storage_alias]
+	type DistributionQueue<T: Config> = StorageValue<Pallet<T>, Vec<EraIndex>>;
+
+	/// Bound the nominator lists, the payout queue and the distribution queue.
+	///
+	/// Nominators beyond `MaxNominatorRewardedPerValidator` and validators beyond the number
+	/// of election winners are dropped, as are the oldest eras beyond `HistoryDepth`: the
+	/// staking data those eras are computed from is gone by then anyway.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 1 {
+				log::info!(
+					target: "runtime::reward",
+					"MigrateToV1 should be removed, on-chain version is {:?}",
+					onchain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			let mut truncated = 0u64;
+			crate::EraReward::<T>::translate::<Vec<T::AccountId>, _>(|_, nominators| {
+				translated += 1;
+				if nominators.len() > T::MaxNominatorRewardedPerValidator::get() as usize {
+					truncated += 1;
+				}
+				Some(BoundedVec::truncate_from(nominators))
+			});
+			crate::DistributionValidators::<T>::translate::<Vec<T::AccountId>, _>(
+				|_, validators| {
+					translated += 1;
+					if validators.len() > MaxWinnersOf::<T>::get() as usize {
+						truncated += 1;
+					}
+					Some(BoundedVec::truncate_from(validators))
+				},
+			);
+			if let Some(validators) = EraRewardsVault::<T>::take() {
+				if validators.len() > MaxWinnersOf::<T>::get() as usize {
+					truncated += 1;
+				}
+				crate::EraRewardsVault::<T>::put(BoundedVec::truncate_from(validators));
+			}
+			if let Some(mut eras) = DistributionQueue::<T>::take() {
+				let history_depth = T::HistoryDepth::get() as usize;
+				if eras.len() > history_depth {
+					truncated += 1;
+					for era in eras.drain(..eras.len() - history_depth) {
+						crate::DistributionValidators::<T>::remove(era);
+					}
+				}
+				crate::DistributionQueue::<T>::put(BoundedVec::truncate_from(eras));
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::reward",
+				"migrated {} entries to v1, truncated {} of them",
+				translated + 2,
+				truncated
+			);
+			T::DbWeight::get().reads_writes(translated + 3, translated + 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let validators = EraReward::<T>::iter_keys().count() as u32;
+			Ok(validators.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let validators = <u32 as Decode>::decode(&mut &state[..])
+				.map_err(|_| "cannot decode the pre-upgrade state")?;
+			ensure!(
+				crate::EraReward::<T>::iter_values().count() as u32 == validators,
+				"nominator lists were lost"
+			);
+			ensure!(
+				crate::DistributionQueue::<T>::decode_len().unwrap_or_default() <=
+					T::HistoryDepth::get() as usize,
+				"distribution queue is not bounded"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version was not bumped");
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Index the pending nominator rewards by nominator in `NominatorRewardValidators`.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain != 1 {
+				log::info!(
+					target: "runtime::reward",
+					"MigrateToV2 should be removed, on-chain version is {:?}",
+					onchain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut indexed = 0u64;
+			for (validator, nominator) in crate::NominatorRewardAccounts::<T>::iter_keys() {
+				crate::NominatorRewardValidators::<T>::insert(&nominator, &validator, ());
+				indexed += 1;
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "runtime::reward", "indexed {} nominator rewards", indexed);
+			T::DbWeight::get().reads_writes(indexed + 1, indexed + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				crate::NominatorRewardAccounts::<T>::iter_keys().all(|(validator, nominator)| {
+					crate::NominatorRewardValidators::<T>::contains_key(&nominator, &validator)
+				}),
+				"a nominator reward is not indexed"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version was not bumped");
+			Ok(())
+		}
+	}
+}
@@ -1,5 +1,5 @@
 #![cfg(test)]
-use crate::{ self as pallet_reward };
+use crate::{ self as pallet_reward, ShortfallFallback };
 use frame_support::{ parameter_types, traits::{ ConstU16, ConstU64 } };
 use sp_runtime::BuildStorage;
 use sp_runtime::traits::IdentityLookup;
@@ -13,8 +13,10 @@ use frame_election_provider_support::{
 	 SequentialPhragmen,
 };
 use frame_support::pallet_prelude::ConstU32;
-use saitama_core_primitives::BlockNumber;
-use saitama_core_primitives::AccountId;
+use frame_support::traits::liquid_staking::StakingAccount;
+pub type AccountId = u64;
+pub type Balance = u128;
+pub type BlockNumber = u64;
 pub type RewardBalance = pallet_balances::Pallet<Test>;
 use frame_support::pallet_prelude::Weight;
 use sp_runtime::Perbill;
@@ -77,7 +79,7 @@ parameter_types! {
 }
 
 impl pallet_session::historical::Config for Test {
-	type FullIdentification = pallet_staking::Exposure<AccountId, saitama_core_primitives::Balance>;
+	type FullIdentification = pallet_staking::Exposure<AccountId, Balance>;
 	type FullIdentificationOf = pallet_staking::ExposureOf<Test>;
 }
 
@@ -138,7 +140,8 @@ impl pallet_staking::Config for Test {
 	type SessionInterface = Self;
 	type UnixTime = pallet_timestamp::Pallet<Self>;
 	type EraPayout = ();
-	type RewardDistribute = Reward;
+	type RewardDistribution = Reward;
+	type DerivativeReward = ();
 	type MaxNominatorRewardedPerValidator = ConstU32<64>;
 	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
 	type NextNewSession = Session;
@@ -160,7 +163,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Nonce = u64;
@@ -213,12 +216,16 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
-	pub const SpendLimit: saitama_core_primitives::Balance = u128::MAX;
+	pub const SpendLimit: Balance = u128::MAX;
 }
 
 impl pallet_treasury::Config for Test {
@@ -228,7 +235,6 @@ impl pallet_treasury::Config for Test {
 	type RejectOrigin =frame_system::EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
-	type MyReward = Reward;
 	type ProposalBond = ProposalBond;
 	type ProposalBondMinimum = ();
 	type ProposalBondMaximum = ();
@@ -239,15 +245,38 @@ impl pallet_treasury::Config for Test {
 	type SpendOrigin = frame_system::EnsureRootWithSuccess<Self::AccountId, SpendLimit>;
 }
 
+pub const VAULT: AccountId = 100;
+
+pub struct LiquidStakeVault;
+impl StakingAccount<AccountId> for LiquidStakeVault {
+	fn staking_account() -> AccountId {
+		VAULT
+	}
+}
+
+parameter_types! {
+	pub static MaxEraPayout: Balance = Balance::MAX;
+	pub static MintedShare: Perbill = Perbill::zero();
+	pub static OnTreasuryShortfall: ShortfallFallback = ShortfallFallback::Defer;
+}
+
 impl pallet_reward::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type TreasuryAccount = Treasury;
-	type RewardCurrency = Balances ;
-	type Balance = saitama_core_primitives::Balance;
-	type DataProvider = Staking;
-	type ValidatorIdOf = pallet_staking::StashOf<Self>;
 	type ValidatorSet = Historical;
-	
+	type Validators = Historical;
+	type LiquidStakeVault = LiquidStakeVault;
+	type ValidatorId = pallet_staking::StashOf<Self>;
+	type Balance = Balance;
+	type TotalMinutesPerYear = ConstU128<525_600>;
+	type EraMinutes = ConstU128<60>;
+	type TreasuryAccount = Treasury;
+	type RewardCurrency = Balances;
+	type DistributionStepsPerBlock = ConstU32<1>;
+	type EraPayout = pallet_reward::FlatRewardPercent<Self>;
+	type MaxEraPayout = MaxEraPayout;
+	type MintedShare = MintedShare;
+	type OnTreasuryShortfall = OnTreasuryShortfall;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,30 +1,410 @@
-use crate::mock::*;
-use frame_support::assert_ok;
-use crate::AuthorBlockList;
-use frame_support::traits::Currency;
+use crate::{
+	mock::*, split_era_reward, CurrentDistribution, DistributionPhase, DistributionQueue,
+	EraReward, EraRewardRecord, EraRewardsVault, ErasNominatorRewards, ErasRewardPayout,
+	ErasValidatorRewards, Event, NominatorRewardAccounts, NominatorRewardValidators,
+	PruneCursor, ShortfallFallback, ValidatorEraReward, ValidatorRewardAccounts,
+};
+use frame_support::{ assert_noop, assert_ok, BoundedVec };
+use frame_support::traits::{ reward::Rewards, Currency, Get, Hooks };
+use frame_support::weights::Weight;
+use rand::{ rngs::SmallRng, Rng, SeedableRng };
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, BuildStorage, Perbill, Rounding,
+};
 
-type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
+fn account(id: u8) -> AccountId {
+	id.into()
+}
+
+fn transfer_balance() {
+	System::set_block_number(1);
+	let _ = RewardBalance::deposit_creating(&account(2), 150000000000);
+	let _ = RewardBalance::deposit_creating(&account(3), 15000);
+}
+
+#[test]
+fn get_rewards() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		transfer_balance();
+		assert_noop!(
+			crate::Pallet::<Test>::get_rewards(RuntimeOrigin::signed(account(1)), account(1)),
+			crate::Error::<Test>::NoReward
+		);
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+		assert_ok!(crate::Pallet::<Test>::get_rewards(RuntimeOrigin::signed(account(1)), account(1)));
+		assert_noop!(
+			crate::Pallet::<Test>::get_rewards(RuntimeOrigin::signed(account(1)), account(1)),
+			crate::Error::<Test>::WaitTheEraToComplete
+		);
+	});
+}
+
+#[test]
+fn era_reward_is_computed_without_truncating_stakes() {
+	new_test_ext().execute_with(|| {
+		// 8% of a sub-unit stake still yields a reward.
+		let exposure = pallet_staking::Exposure::<AccountId, Balance> {
+			total: 999,
+			own: 999,
+			others: vec![],
+		};
+		assert_eq!(Reward::calculate_annual_validator_reward(exposure), 79);
+		// One hour out of a year, rounded down.
+		assert_eq!(Reward::compute_era_reward_from_annual(8_760_000), 1_000);
+		assert_eq!(Reward::compute_era_reward_from_annual(8_759), 0);
+	});
+}
+
+#[test]
+fn split_era_reward_pays_commission_and_dust_to_validator() {
+	let (validator, nominators) = split_era_reward(
+		1_000,
+		Perbill::from_percent(10),
+		300,
+		vec![(account(2), 100), (account(3), 100)],
+	);
+	// 100 commission, 900 shared: 300 per third, the validator keeps its own third.
+	assert_eq!(nominators, vec![(account(2), 300), (account(3), 300)]);
+	assert_eq!(validator, 400);
+
+	let (validator, nominators) = split_era_reward(
+		10,
+		Perbill::zero(),
+		3,
+		vec![(account(2), 1), (account(3), 1)],
+	);
+	// 10 / 3 rounds down to 3 for every nominator, the dust goes to the validator.
+	assert_eq!(nominators, vec![(account(2), 3), (account(3), 3)]);
+	assert_eq!(validator, 4);
+}
+
+#[test]
+fn split_era_reward_without_stake_pays_validator() {
+	let (validator, nominators) =
+		split_era_reward(1_000, Perbill::from_percent(5), 0, vec![(account(2), 0)]);
+	assert_eq!(validator, 1_000);
+	assert_eq!(nominators, vec![(account(2), 0)]);
+}
+
+#[test]
+fn split_era_reward_conserves_era_reward() {
+	let mut rng = SmallRng::seed_from_u64(42);
+	for _ in 0..10_000 {
+		let era_reward: u128 = rng.gen_range(0..u64::MAX as u128);
+		let commission = Perbill::from_parts(rng.gen_range(0..=1_000_000_000));
+		let own: u128 = rng.gen_range(0..u64::MAX as u128);
+		let others: Vec<(AccountId, u128)> = (0..rng.gen_range(0..64u64))
+			.map(|who| (who, rng.gen_range(0..u64::MAX as u128)))
+			.collect();
+		let total = others.iter().fold(own, |acc, (_, stake)| acc + stake);
+		let accounts: Vec<AccountId> = others.iter().map(|(who, _)| *who).collect();
+
+		let (validator, nominators) = split_era_reward(era_reward, commission, total, others);
+		let nominator_total = nominators.iter().fold(0u128, |acc, (_, reward)| acc + reward);
+		assert_eq!(validator + nominator_total, era_reward);
+		// Every nominator is paid and the validator only gets its commission, its own share
+		// and the rounding dust of the nominator shares on top.
+		assert_eq!(nominators.iter().map(|(who, _)| *who).collect::<Vec<_>>(), accounts);
+		let validator_commission = commission.mul_floor(era_reward);
+		let own_share = multiply_by_rational_with_rounding(
+			era_reward - validator_commission,
+			own,
+			total.max(1),
+			Rounding::Up,
+		)
+		.unwrap();
+		assert!(validator >= validator_commission);
+		assert!(
+			total == 0 ||
+				validator <= validator_commission + own_share + nominators.len() as u128
+		);
+	}
+}
+
+#[test]
+fn distribution_is_paged_across_blocks() {
+	new_test_ext().execute_with(|| {
+		transfer_balance();
+		let _ = RewardBalance::deposit_creating(&Treasury::account_id(), 1_000_000);
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+		EraRewardsVault::<Test>::put(BoundedVec::truncate_from(vec![account(1)]));
+
+		assert_ok!(Reward::queue_distribution(0));
+		assert_eq!(DistributionQueue::<Test>::get(), vec![0]);
+
+		// A single step per block in `on_initialize`: the era gets started, nothing is paid.
+		Reward::on_initialize(1);
+		let state = CurrentDistribution::<Test>::get().unwrap();
+		assert_eq!((state.era, state.phase), (0, DistributionPhase::Calculate));
+		assert_eq!(RewardBalance::free_balance(account(1)), 0);
+
+		// The idle weight of the block finishes the distribution.
+		Reward::on_idle(1, Weight::MAX);
+		assert_eq!(RewardBalance::free_balance(account(1)), 1_000);
+		assert!(CurrentDistribution::<Test>::get().is_none());
+		assert!(DistributionQueue::<Test>::get().is_empty());
+		System::assert_last_event(crate::Event::DistributionCompleted { era: 0 }.into());
+	});
+}
+
+#[test]
+fn idle_steps_stay_within_the_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		transfer_balance();
+		let _ = RewardBalance::deposit_creating(&Treasury::account_id(), 1_000_000);
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+		EraRewardsVault::<Test>::put(BoundedVec::truncate_from(vec![account(1)]));
+		assert_ok!(Reward::queue_distribution(0));
+
+		// The first calculate step also begins the payout of the era.
+		let nominators = <Test as pallet_staking::Config>::MaxNominatorRewardedPerValidator::get();
+		let calculate =
+			Reward::begin_payout_weight().saturating_add(Reward::calculate_step_weight(nominators));
+		assert!(Reward::max_step_weight().all_gte(calculate));
+		assert!(Reward::max_step_weight().all_gte(Reward::prune_step_weight()));
+
+		// Enough for the reads of the hook and a single step, whatever its phase.
+		let budget = <Test as frame_system::Config>::DbWeight::get()
+			.reads(2)
+			.saturating_add(Reward::max_step_weight());
+		for _ in 0..10 {
+			assert!(budget.all_gte(Reward::on_idle(1, budget)));
+		}
+		assert!(CurrentDistribution::<Test>::get().is_none());
+		assert_eq!(RewardBalance::free_balance(account(1)), 1_000);
+		System::assert_last_event(crate::Event::DistributionCompleted { era: 0 }.into());
+	});
+}
+
+#[test]
+fn distribution_queue_is_bounded_by_history_depth() {
+	new_test_ext().execute_with(|| {
+		let history_depth = <Test as pallet_staking::Config>::HistoryDepth::get();
+		for era in 0..history_depth {
+			assert_ok!(Reward::queue_distribution(era));
+		}
+
+		assert_noop!(
+			Reward::queue_distribution(history_depth),
+			crate::Error::<Test>::DistributionQueueFull
+		);
+		assert_eq!(DistributionQueue::<Test>::get().len(), history_depth as usize);
+	});
+}
+
+#[test]
+fn try_state_checks_the_pending_rewards() {
+	new_test_ext().execute_with(|| {
+		let _ = RewardBalance::deposit_creating(&Treasury::account_id(), 1_500);
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+		EraReward::<Test>::insert(account(1), BoundedVec::truncate_from(vec![account(2)]));
+		NominatorRewardAccounts::<Test>::insert(account(1), account(2), 500);
+		NominatorRewardValidators::<Test>::insert(account(2), account(1), ());
+		assert_ok!(Reward::do_try_state());
+
+		// The treasury can't cover more.
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_001);
+		assert!(Reward::do_try_state().is_err());
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+
+		// A nominator reward missing from the index of its nominator.
+		NominatorRewardValidators::<Test>::remove(account(2), account(1));
+		assert!(Reward::do_try_state().is_err());
+		NominatorRewardValidators::<Test>::insert(account(2), account(1), ());
+
+		// A nominator reward that would never be paid out.
+		EraReward::<Test>::remove(account(1));
+		assert!(Reward::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn era_payout_is_capped_and_partly_minted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 8% a year of 109_500_000 is 1_000 per hour-long era.
+		pallet_staking::ErasTotalStake::<Test>::insert(0, 109_500_000);
+		MaxEraPayout::set(600);
+		MintedShare::set(Perbill::from_percent(50));
+		let issuance = RewardBalance::total_issuance();
+
+		assert_eq!(Reward::begin_era_payout(0), 600);
+		assert_eq!(Reward::current_era_payout(), Some(600));
+		assert_eq!(RewardBalance::free_balance(Treasury::account_id()), 300);
+		assert_eq!(RewardBalance::total_issuance(), issuance + 300);
+		System::assert_last_event(
+			crate::Event::EraPayoutComputed { era: 0, payout: 600, minted: 300 }.into(),
+		);
+	});
+}
 
-fn account(id: u8) -> AccountIdOf<Test> {
-	[id; 20].into()
+#[test]
+fn treasury_shortfall_triggers_the_fallback() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+
+		// The payout waits for the treasury to be funded.
+		assert_eq!(
+			Reward::claim_rewards(account(1)),
+			Err(crate::Error::<Test>::InsufficientRewardBalance.into())
+		);
+		System::assert_last_event(
+			crate::Event::PayoutDeferred { validator: account(1), shortfall: 1_100 }.into(),
+		);
+		assert_eq!(ValidatorRewardAccounts::<Test>::get(account(1)), 1_000);
+
+		// The lacking amount, existential deposit included, gets minted.
+		OnTreasuryShortfall::set(ShortfallFallback::Mint);
+		assert_ok!(Reward::claim_rewards(account(1)));
+		System::assert_has_event(
+			crate::Event::ShortfallMinted { validator: account(1), amount: 1_100 }.into(),
+		);
+		assert_eq!(RewardBalance::free_balance(account(1)), 1_000);
+		assert_eq!(RewardBalance::free_balance(Treasury::account_id()), 100);
+	});
 }
 
-fn set_validator(){
-    let validator = pallet_staking::ValidatorPrefs;
+#[test]
+fn reward_history_is_recorded_and_pruned() {
+	new_test_ext().execute_with(|| {
+		pallet_staking::ErasRewardPoints::<Test>::insert(
+			0,
+			pallet_staking::EraRewardPoints { total: 20, individual: [(account(1), 20)].into() },
+		);
+		pallet_staking::ErasStakers::<Test>::insert(
+			0,
+			account(1),
+			pallet_staking::Exposure::<AccountId, Balance> {
+				total: 1_000,
+				own: 400,
+				others: vec![pallet_staking::IndividualExposure { who: account(2), value: 600 }],
+			},
+		);
+		pallet_staking::Validators::<Test>::insert(
+			account(1),
+			pallet_staking::ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false },
+		);
+		ErasRewardPayout::<Test>::insert(0, 1_000);
+
+		// 100 commission, 540 for the nominator's 60% of the rest.
+		Reward::calculate_validator_reward(0, account(1), 1_000);
+		assert_eq!(
+			ErasValidatorRewards::<Test>::get(0, account(1)),
+			Some(ValidatorEraReward { reward: 460, commission: Perbill::from_percent(10), points: 20 }),
+		);
+		assert_eq!(ErasNominatorRewards::<Test>::get((0, account(1), account(2))), 540);
+		assert_eq!(
+			Reward::api_reward_history(account(2)),
+			vec![EraRewardRecord {
+				era: 0,
+				validator: account(1),
+				reward: 540,
+				commission: Perbill::from_percent(10),
+				points: 20,
+			}],
+		);
+		let _ = RewardBalance::deposit_creating(&Treasury::account_id(), 2_000);
+		assert_ok!(Reward::do_try_state());
+
+		// The history is kept for `HistoryDepth` eras.
+		let history_depth = <Test as pallet_staking::Config>::HistoryDepth::get();
+		ErasRewardPayout::<Test>::insert(history_depth, 1_000);
+		assert!(Reward::do_try_state().is_err());
+		// More nominator rewards than a single step clears.
+		let limit = Reward::prune_limit() as u64;
+		for nominator in 0..limit {
+			ErasNominatorRewards::<Test>::insert((0, account(3), 1_000 + nominator), 1);
+		}
+		// The validator rewards, then the nominator rewards in two steps of the same block.
+		assert_eq!(Reward::prune_history(0), Some(false));
+		assert!(ErasValidatorRewards::<Test>::get(0, account(1)).is_none());
+		assert_eq!(Reward::prune_history(0), Some(false));
+		assert!(PruneCursor::<Test>::get().is_some());
+		assert_eq!(Reward::prune_history(0), Some(true));
+		assert!(PruneCursor::<Test>::get().is_none());
+		assert_eq!(ErasNominatorRewards::<Test>::iter_prefix((0,)).count(), 0);
+		assert_eq!(Reward::prune_history(0), None);
+		assert!(ErasRewardPayout::<Test>::get(0).is_none());
+		assert!(ErasValidatorRewards::<Test>::get(0, account(1)).is_none());
+		assert!(Reward::api_reward_history(account(2)).is_empty());
+		assert_ok!(Reward::do_try_state());
+	});
 }
 
-fn transfer_balance(){
-    System::set_block_number(1);
-    let _ =  RewardBalance::deposit_creating(&account(2),150000000000);
-    let _ = RewardBalance::transfer(RuntimeOrigin::signed(account(2)), account(3), 15000);
-    AuthorBlockList::<Test>::insert(account(1), 1)
+#[test]
+fn genesis_sets_the_reward_percent_and_funds_the_treasury() {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> { reward_percent: 5, treasury_funds: 10_000 }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let percent = crate::Pallet::<Test>::api_reward_percent();
+		assert_eq!((percent.current, percent.next), (5, 5));
+		assert_eq!(Balances::free_balance(Treasury::account_id()), 10_000);
+	});
 }
 
 #[test]
-fn get_rewards(){
-    new_test_ext().execute_with(||{
-        System::set_block_number(1);
-        transfer_balance();
-        assert_ok!(crate::Pallet::<Test>::get_rewards(RuntimeOrigin::signed(account(1))));
-    });
-}
\ No newline at end of file
+fn failed_nominator_payouts_stay_pending() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = RewardBalance::deposit_creating(&Treasury::account_id(), 10_000);
+		ValidatorRewardAccounts::<Test>::insert(account(1), 1_000);
+		EraReward::<Test>::insert(
+			account(1),
+			BoundedVec::truncate_from(vec![account(2), account(3), VAULT]),
+		);
+		for (nominator, reward) in [(account(2), 500), (VAULT, 1_000)] {
+			NominatorRewardAccounts::<Test>::insert(account(1), nominator, reward);
+			NominatorRewardValidators::<Test>::insert(nominator, account(1), ());
+		}
+		// Too little to open the account of the nominator.
+		NominatorRewardAccounts::<Test>::insert(account(1), account(3), 50);
+		NominatorRewardValidators::<Test>::insert(account(3), account(1), ());
+
+		assert_ok!(Reward::claim_rewards(account(1)));
+
+		assert_eq!(RewardBalance::free_balance(account(2)), 500);
+		assert_eq!(RewardBalance::free_balance(account(3)), 0);
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::Reward(Event::PayoutFailed { validator, account: who, .. })
+				if *validator == account(1) && *who == account(3)
+		)));
+		// The unpaid nominator stays listed, the paid vault reward stays recorded until it is
+		// compounded.
+		assert_eq!(EraReward::<Test>::get(account(1)).into_inner(), vec![account(3)]);
+		assert_eq!(NominatorRewardAccounts::<Test>::get(account(1), account(3)), 50);
+		assert_eq!(NominatorRewardAccounts::<Test>::get(account(1), VAULT), 1_000);
+		assert_eq!(NominatorRewardAccounts::<Test>::get(account(1), account(2)), 0);
+		assert_ok!(Reward::do_try_state());
+	});
+}
+
+#[test]
+fn pending_nominator_rewards_are_indexed_by_nominator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = RewardBalance::deposit_creating(&Treasury::account_id(), 10_000);
+		for validator in [account(1), account(2)] {
+			Reward::allocate_rewards(validator, Some(account(4)), 500);
+		}
+		Reward::allocate_rewards(account(1), Some(account(5)), 700);
+		Reward::allocate_rewards(account(1), None, 1_000);
+		let nominators = vec![account(4), account(5)];
+		EraReward::<Test>::insert(account(1), BoundedVec::truncate_from(nominators));
+		EraReward::<Test>::insert(account(2), BoundedVec::truncate_from(vec![account(4)]));
+		assert_ok!(Reward::do_try_state());
+
+		let mut pending = Reward::api_nominator_pending_rewards(account(4));
+		pending.sort();
+		assert_eq!(pending, vec![(account(1), 500), (account(2), 500)]);
+
+		assert_ok!(Reward::claim_rewards(account(1)));
+		assert_eq!(Reward::api_nominator_pending_rewards(account(4)), vec![(account(2), 500)]);
+		assert!(Reward::api_nominator_pending_rewards(account(5)).is_empty());
+		assert_ok!(Reward::do_try_state());
+	});
+}
@@ -0,0 +1,79 @@
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{Perbill, RuntimeDebug};
+use sp_staking::EraIndex;
+
+/// The reward percentages, as reported by the runtime API.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RewardPercentInfo {
+	/// The annual reward percent applied in the current era.
+	pub current: u32,
+	/// The annual reward percent that takes effect from the next era on.
+	pub next: u32,
+}
+
+/// The reward of a validator in an era, as kept in the reward history.
+#[derive(Clone, Copy, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ValidatorEraReward<Balance> {
+	/// The reward of the validator itself, its commission included.
+	pub reward: Balance,
+	/// The commission of the validator in the era.
+	pub commission: Perbill,
+	/// The era points of the validator.
+	pub points: u32,
+}
+
+/// A reward an account earned in an era, as reported by the runtime API.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct EraRewardRecord<AccountId, Balance> {
+	/// The era the reward was earned in.
+	pub era: EraIndex,
+	/// The validator the reward was earned with: the account itself for the reward of a
+	/// validator, the nominated validator for the reward of a nominator.
+	pub validator: AccountId,
+	/// The reward.
+	pub reward: Balance,
+	/// The commission of the validator in the era.
+	pub commission: Perbill,
+	/// The era points of the validator.
+	pub points: u32,
+}
+
+/// The phases an era's reward distribution goes through, in order.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DistributionPhase {
+	/// Computing the rewards of the era's validators and their nominators.
+	Calculate,
+	/// Paying out the validators queued through `get_rewards`.
+	Payout,
+	/// Compounding the rewards of the derivative (liquid staking) accounts.
+	Derivative,
+	/// Dropping the reward history of the eras older than `HistoryDepth`.
+	Prune,
+}
+
+/// The reward distribution in progress.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DistributionState {
+	/// The era whose rewards are distributed.
+	pub era: EraIndex,
+	/// The current phase.
+	pub phase: DistributionPhase,
+	/// The position within the current phase.
+	pub cursor: u32,
+}
+
+/// What to do when the treasury can't cover the payout of a validator and its nominators.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ShortfallFallback {
+	/// Mint what the treasury lacks and pay out in full.
+	Mint,
+	/// Keep the validator queued until the treasury can pay it out.
+	Defer,
+}
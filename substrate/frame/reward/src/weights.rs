@@ -0,0 +1,168 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_reward
+//!
+//! THESE WEIGHTS WERE NOT GENERATED BY THE SUBSTRATE BENCHMARK CLI. The benchmarks of this
+//! pallet have not been run on reference hardware yet, so every function charges a
+//! deliberately pessimistic 500 µs and 16 KiB of proof, plus 50 µs and 4 KiB per item of its
+//! components, on top of the storage it reads and writes. Replace this file with the output
+//! of the command below before a release.
+
+// Command to generate the weights:
+// target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_reward
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/reward/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_reward.
+pub trait WeightInfo {
+	fn get_rewards(v: u32, ) -> Weight;
+	fn set_reward_percent_value() -> Weight;
+	fn distribute_calculate(n: u32, ) -> Weight;
+	fn distribute_payout(n: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_reward, see the module documentation.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:0)
+	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn get_rewards(v: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Reward::RewardPercent` (r:0 w:1)
+	fn set_reward_percent_value() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Reward::DistributionValidators` (r:1 w:0)
+	/// Storage: `Staking::ErasRewardPoints` (r:1 w:0)
+	/// Storage: `Staking::ErasStakers` (r:1 w:0)
+	/// Storage: `Reward::BaseRewardPercent` (r:1 w:0)
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:1)
+	/// Storage: `Reward::EraReward` (r:1 w:1)
+	/// Storage: `Reward::NominatorRewardAccounts` (r:64 w:64)
+	/// Storage: `Reward::NominatorRewardValidators` (r:0 w:64)
+	/// Storage: `Reward::ErasValidatorRewards` (r:0 w:1)
+	/// Storage: `Reward::ErasNominatorRewards` (r:0 w:64)
+	/// Storage: `Reward::CurrentDistribution` (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn distribute_calculate(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
+	/// Storage: `Reward::EraReward` (r:1 w:1)
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:1)
+	/// Storage: `Reward::NominatorRewardAccounts` (r:64 w:64)
+	/// Storage: `Reward::NominatorRewardValidators` (r:0 w:64)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:65 w:65)
+	/// Storage: `Reward::CurrentDistribution` (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn distribute_payout(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:0)
+	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn get_rewards(v: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Reward::RewardPercent` (r:0 w:1)
+	fn set_reward_percent_value() -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Reward::DistributionValidators` (r:1 w:0)
+	/// Storage: `Staking::ErasRewardPoints` (r:1 w:0)
+	/// Storage: `Staking::ErasStakers` (r:1 w:0)
+	/// Storage: `Reward::BaseRewardPercent` (r:1 w:0)
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:1)
+	/// Storage: `Reward::EraReward` (r:1 w:1)
+	/// Storage: `Reward::NominatorRewardAccounts` (r:64 w:64)
+	/// Storage: `Reward::NominatorRewardValidators` (r:0 w:64)
+	/// Storage: `Reward::ErasValidatorRewards` (r:0 w:1)
+	/// Storage: `Reward::ErasNominatorRewards` (r:0 w:64)
+	/// Storage: `Reward::CurrentDistribution` (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn distribute_calculate(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
+	/// Storage: `Reward::EraReward` (r:1 w:1)
+	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:1)
+	/// Storage: `Reward::NominatorRewardAccounts` (r:64 w:64)
+	/// Storage: `Reward::NominatorRewardValidators` (r:0 w:64)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:65 w:65)
+	/// Storage: `Reward::CurrentDistribution` (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn distribute_payout(n: u32, ) -> Weight {
+		Weight::from_parts(500_000_000, 16_384)
+			.saturating_add(Weight::from_parts(50_000_000, 4_096).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
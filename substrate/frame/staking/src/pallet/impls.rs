@@ -31,7 +31,6 @@ use frame_support::{
 	},
 	weights::Weight,
 };
-use frame_support::traits::liquid_staking::DerivativeRewardAccount;
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_session::historical;
 use sp_runtime::{
@@ -299,18 +298,14 @@ impl<T: Config> Pallet<T> {
 			let (validator_payout, remainder) =
 				T::EraPayout::era_payout(staked, issuance, era_duration);
 
-			let _ = T::RewardDistribution::calculate_reward();
-			let reward = T::RewardDistribution::payout_validators();
-			reward.iter().for_each(|accounts| {
-				let _ = T::RewardDistribution::claim_rewards(accounts.clone());
-			});
-			let _ = T::RewardDistribution::reward_percent();
-			let derivative_reward = T::DerivativeReward::derivative_reward_accounts();
-			if !derivative_reward.is_empty() {
-				derivative_reward.into_iter().for_each(|accounts| {
-					let _ = T::DerivativeReward::claim_derivative(accounts);
-				});
-				let _ = T::DerivativeReward::reset_reward();
+			// The rewards are computed and paid out by `RewardDistribution` over the next blocks.
+			if let Err(e) = T::RewardDistribution::queue_distribution(active_era.index) {
+				log!(
+					error,
+					"failed to queue the reward distribution of era {}: {:?}",
+					active_era.index,
+					e
+				);
 			}
 			// Set ending era reward.
 			<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
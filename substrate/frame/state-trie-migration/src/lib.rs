@@ -1127,6 +1127,10 @@ mod mock {
 		type MaxFreezes = ();
 		type RuntimeHoldReason = ();
 		type MaxHolds = ();
+		type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+		type MaxMemoLen = frame_support::traits::ConstU32<256>;
+		type MaxMembers = frame_support::traits::ConstU32<100>;
+		type MaxPendingMints = frame_support::traits::ConstU32<10>;
 	}
 
 	/// Test only Weights for state migration.
@@ -1239,9 +1243,12 @@ mod mock {
 			frame_system::GenesisConfig::<Test>::default()
 				.assimilate_storage(&mut custom_storage)
 				.unwrap();
-			pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1000)] }
-				.assimilate_storage(&mut custom_storage)
-				.unwrap();
+			pallet_balances::GenesisConfig::<Test> {
+				balances: vec![(1, 1000)],
+				..Default::default()
+			}
+			.assimilate_storage(&mut custom_storage)
+			.unwrap();
 		}
 
 		sp_tracing::try_init_simple();
@@ -1,5 +1,11 @@
-use scale_info::prelude::vec::Vec;
-
+/// Tells whether an account is barred from paying fees and moving funds.
 pub trait BlackListAccounts<AccountId> {
-    fn blacklisted_accounts() -> Vec<AccountId>;
-}
\ No newline at end of file
+    /// Whether `who` is currently blacklisted.
+    fn is_blacklisted(who: &AccountId) -> bool;
+}
+
+impl<AccountId> BlackListAccounts<AccountId> for () {
+    fn is_blacklisted(_who: &AccountId) -> bool {
+        false
+    }
+}
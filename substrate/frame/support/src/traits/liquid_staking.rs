@@ -6,6 +6,18 @@ pub trait DerivativeRewardAccount<AccountId>{
 	fn reset_reward() -> DispatchResult;
 }
 
+impl<AccountId> DerivativeRewardAccount<AccountId> for () {
+	fn derivative_reward_accounts() -> Vec<AccountId> {
+		Vec::new()
+	}
+	fn claim_derivative(_account: AccountId) -> DispatchResult {
+		Ok(())
+	}
+	fn reset_reward() -> DispatchResult {
+		Ok(())
+	}
+}
+
 pub trait StakingAccount<AccountId> {
 	fn staking_account() -> AccountId;
 }
@@ -4,6 +4,7 @@ use sp_runtime::DispatchResult;
 pub trait Rewards<AccountId>{
 	fn payout_validators() -> Vec<AccountId>;
 	fn claim_rewards(account:AccountId) -> Result<(), DispatchError>;
-	fn calculate_reward() -> DispatchResult;
+	/// Queue the rewards of the ended `era` to be computed and paid out over the next blocks.
+	fn queue_distribution(era: u32) -> DispatchResult;
 	fn reward_percent() -> DispatchResult;
 }
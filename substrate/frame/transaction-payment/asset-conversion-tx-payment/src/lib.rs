@@ -181,7 +181,12 @@ where
 		call: &T::RuntimeCall,
 		info: &DispatchInfoOf<T::RuntimeCall>,
 		len: usize,
+		exempt: bool,
 	) -> Result<(BalanceOf<T>, InitialPayment<T>), TransactionValidityError> {
+		if exempt {
+			// Fee exempt transactions pay no tip either.
+			return Ok((Zero::zero(), InitialPayment::Nothing))
+		}
 		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
 		debug_assert!(self.tip <= fee, "tip should be included in the computed fee");
 		if fee.is_zero() {
@@ -265,8 +270,10 @@ where
 		len: usize,
 	) -> TransactionValidity {
 		use pallet_transaction_payment::ChargeTransactionPayment;
-		let (fee, _) = self.withdraw_fee(who, call, info, len)?;
-		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+		let exempt = pallet_transaction_payment::Pallet::<T>::is_fee_exempt(who, call);
+		let (fee, _) = self.withdraw_fee(who, call, info, len, exempt)?;
+		let tip = if exempt { Zero::zero() } else { self.tip };
+		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, tip, fee);
 		Ok(ValidTransaction { priority, ..Default::default() })
 	}
 
@@ -277,7 +284,12 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (_fee, initial_payment) = self.withdraw_fee(who, call, info, len)?;
+		let exempt = pallet_transaction_payment::Pallet::<T>::is_fee_exempt(who, call);
+		let (_fee, initial_payment) = self.withdraw_fee(who, call, info, len, exempt)?;
+		if exempt {
+			pallet_transaction_payment::Pallet::<T>::note_free_transaction(who);
+			return Ok((Zero::zero(), who.clone(), initial_payment, self.asset_id))
+		}
 		Ok((self.tip, who.clone(), initial_payment, self.asset_id))
 	}
 
@@ -296,7 +308,7 @@ where
 						"For that payment type the `asset_id` should be None"
 					);
 					pallet_transaction_payment::ChargeTransactionPayment::<T>::post_dispatch(
-						Some((tip, who, already_withdrawn)),
+						Some((tip, who, already_withdrawn, false)),
 						info,
 						post_info,
 						len,
@@ -122,6 +122,10 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl WeightToFeeT for WeightToFee {
@@ -168,6 +172,9 @@ impl pallet_transaction_payment::Config for Runtime {
 	type LengthToFee = TransactionByteFee;
 	type FeeMultiplierUpdate = ();
 	type OperationalFeeMultiplier = ConstU8<5>;
+	type BlackListAccounts = ();
+	type FeeExemption = ();
+	type FreeTransactionsPerBlock = ConstU32<0>;
 }
 
 type AssetId = u32;
@@ -194,6 +201,8 @@ impl pallet_assets::Config for Runtime {
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
+		type Blacklist = ();
+		type MaxMemoLen = ConstU32<256>;
 }
 
 impl pallet_assets::Config<Instance2> for Runtime {
@@ -218,6 +227,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
+		type Blacklist = ();
+		type MaxMemoLen = ConstU32<256>;
 }
 
 parameter_types! {
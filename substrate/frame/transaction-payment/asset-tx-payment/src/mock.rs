@@ -114,6 +114,10 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl WeightToFeeT for WeightToFee {
@@ -141,6 +145,9 @@ impl pallet_transaction_payment::Config for Runtime {
 	type LengthToFee = TransactionByteFee;
 	type FeeMultiplierUpdate = ();
 	type OperationalFeeMultiplier = ConstU8<5>;
+	type BlackListAccounts = ();
+	type FeeExemption = ();
+	type FreeTransactionsPerBlock = ConstU32<0>;
 }
 
 type AssetId = u32;
@@ -167,6 +174,8 @@ impl pallet_assets::Config for Runtime {
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
+		type Blacklist = ();
+		type MaxMemoLen = ConstU32<256>;
 }
 
 pub struct HardcodedAuthor;
@@ -44,6 +44,8 @@
 //!     final state of the chain at the end of the previous block. This can be configured via
 //!     [`Config::FeeMultiplierUpdate`]
 //!   - How the fees are paid via [`Config::OnChargeTransaction`].
+//!   - Which transactions go for free, and how many per account and block, via
+//!     [`Config::FeeExemption`] and [`Config::FreeTransactionsPerBlock`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -52,7 +54,7 @@ use scale_info::TypeInfo;
 use frame_support::{
 	dispatch::{
 		DispatchClass, DispatchInfo, DispatchResult, GetDispatchInfo, Pays, PostDispatchInfo,
-	}, pallet_prelude::InvalidTransaction, traits::{blacklist::BlackListAccounts, Defensive, EstimateCallFee, Get, ValidatorSet}, weights::{Weight, WeightToFee}
+	}, pallet_prelude::InvalidTransaction, traits::{blacklist::BlackListAccounts, Defensive, EstimateCallFee, Get}, weights::{Weight, WeightToFee}
 };
 pub use pallet::*;
 pub use payment::*;
@@ -68,8 +70,6 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 pub use types::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
-use frame_election_provider_support::ElectionDataProvider;
-use frame_election_provider_support::DataProviderBounds;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -364,16 +364,13 @@ pub mod pallet {
 		/// Update the multiplier of the next block, based on the previous block's weight.
 		type FeeMultiplierUpdate: MultiplierUpdate;
 
-		type ValidatorSet: frame_support::traits::ValidatorSetWithIdentification<Self::AccountId>;
-		type DataProvider: ElectionDataProvider<
-			AccountId = <Self::ValidatorSet as ValidatorSet<Self::AccountId>>::ValidatorId,
-			BlockNumber = BlockNumberFor<Self>,
-		>;
+		/// Decides which transactions are dispatched without paying a fee.
+		type FeeExemption: FeeExemption<Self::AccountId, Self::RuntimeCall>;
 
-		type ValidatorIdOf: Convert<Self::AccountId,
-		 Option<<<Self as Config>::ValidatorSet as ValidatorSet<<Self as frame_system::Config>::AccountId>>::ValidatorId>
-		 >;
-		 
+		/// The number of fee exempt transactions an account may get into a single block. Any
+		/// further transactions of the account in that block pay the fee as usual.
+		#[pallet::constant]
+		type FreeTransactionsPerBlock: Get<u32>;
 	}
 
 	#[pallet::type_value]
@@ -389,6 +386,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
 
+	/// The number of fee exempt transactions each account got into the current block.
+	///
+	/// Cleared at the end of every block.
+	#[pallet::storage]
+	pub type FreeTransactions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub multiplier: Multiplier,
@@ -429,6 +433,7 @@ pub mod pallet {
 			<NextFeeMultiplier<T>>::mutate(|fm| {
 				*fm = T::FeeMultiplierUpdate::convert(*fm);
 			});
+			let _ = FreeTransactions::<T>::clear(u32::MAX, None);
 		}
 
 		#[cfg(feature = "std")]
@@ -477,6 +482,23 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whether `who` gets to dispatch `call` without paying a fee: [`Config::FeeExemption`] lets
+	/// the call through and `who` has free transactions left in the current block.
+	pub fn is_fee_exempt(who: &T::AccountId, call: &T::RuntimeCall) -> bool {
+		T::FeeExemption::is_exempt(who, call) &&
+			FreeTransactions::<T>::get(who) < T::FreeTransactionsPerBlock::get()
+	}
+
+	/// Use up one of the free transactions `who` has in the current block.
+	pub fn note_free_transaction(who: &T::AccountId) {
+		FreeTransactions::<T>::mutate(who, |count| count.saturating_inc());
+		// The count gets cleared again in `on_finalize`.
+		<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+			T::DbWeight::get().reads_writes(1, 2),
+			DispatchClass::Mandatory,
+		);
+	}
+
 	/// Query the data that we know about the fee of a given `call`.
 	///
 	/// This pallet is not and cannot be aware of the internals of a signed extension, for example
@@ -712,6 +734,7 @@ where
 		call: &T::RuntimeCall,
 		info: &DispatchInfoOf<T::RuntimeCall>,
 		len: usize,
+		exempt: bool,
 	) -> Result<
 		(
 			BalanceOf<T>,
@@ -719,25 +742,16 @@ where
 		),
 		TransactionValidityError,
 	> {
-		let tip = self.0;
-		let mut fee = Pallet::<T>::compute_fee(len as u32, info, tip);
-		
-		let blacklist_accounts = T::BlackListAccounts::blacklisted_accounts();
-		if blacklist_accounts.iter().any(|account| account == who) {
+		if T::BlackListAccounts::is_blacklisted(who) {
 			return Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(0)));
 		}
 
-		let all_validators = T::DataProvider::electable_targets(
-			DataProviderBounds::default()).unwrap();
-
-		let val = all_validators
-			.iter()
-			.any(|c| T::ValidatorIdOf::convert(who.clone()) == Some(c.clone()));
+		if exempt {
+			return Ok((Zero::zero(), Default::default()))
+		}
 
-		if val
-	 	{
-			fee = Zero::zero();
-		}	
+		let tip = self.0;
+		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
 
 		<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::withdraw_fee(
 			who, call, info, fee, tip,
@@ -847,6 +861,8 @@ where
 		Self::AccountId,
 		// imbalance resulting from withdrawing the fee
 		<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo,
+		// whether the transaction was exempt from the fee
+		bool,
 	);
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
 		Ok(())
@@ -859,8 +875,9 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (final_fee, _) = self.withdraw_fee(who, call, info, len)?;
-		let tip = self.0;
+		let exempt = Pallet::<T>::is_fee_exempt(who, call);
+		let (final_fee, _) = self.withdraw_fee(who, call, info, len, exempt)?;
+		let tip = if exempt { Zero::zero() } else { self.0 };
 		Ok(ValidTransaction {
 			priority: Self::get_priority(info, len, tip, final_fee),
 			..Default::default()
@@ -874,8 +891,13 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (_fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, who.clone(), imbalance))
+		let exempt = Pallet::<T>::is_fee_exempt(who, call);
+		let (_fee, imbalance) = self.withdraw_fee(who, call, info, len, exempt)?;
+		if exempt {
+			Pallet::<T>::note_free_transaction(who);
+			return Ok((Zero::zero(), who.clone(), imbalance, true))
+		}
+		Ok((self.0, who.clone(), imbalance, false))
 	}
 
 	fn post_dispatch(
@@ -885,7 +907,10 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		if let Some((tip, who, imbalance)) = maybe_pre {
+		if let Some((tip, who, imbalance, exempt)) = maybe_pre {
+			if exempt {
+				return Ok(())
+			}
 			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
 			T::OnChargeTransaction::correct_and_deposit_fee(
 				&who, info, post_info, actual_fee, tip, imbalance,
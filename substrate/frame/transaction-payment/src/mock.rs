@@ -109,6 +109,10 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
+	type BlacklistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxMemoLen = frame_support::traits::ConstU32<256>;
+	type MaxMembers = frame_support::traits::ConstU32<100>;
+	type MaxPendingMints = frame_support::traits::ConstU32<10>;
 }
 
 impl WeightToFeeT for WeightToFee {
@@ -148,6 +152,18 @@ impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithFees
 	}
 }
 
+parameter_types! {
+	pub(crate) static ExemptAccounts: Vec<u64> = vec![];
+}
+
+/// Lets the `ExemptAccounts` make `System` calls for free.
+pub struct ExemptSystemCalls;
+impl FeeExemption<u64, RuntimeCall> for ExemptSystemCalls {
+	fn is_exempt(who: &u64, call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(_)) && ExemptAccounts::get().contains(who)
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
@@ -155,4 +171,7 @@ impl Config for Runtime {
 	type WeightToFee = WeightToFee;
 	type LengthToFee = TransactionByteFee;
 	type FeeMultiplierUpdate = ();
+	type BlackListAccounts = ();
+	type FeeExemption = ExemptSystemCalls;
+	type FreeTransactionsPerBlock = ConstU32<2>;
 }
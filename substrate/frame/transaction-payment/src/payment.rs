@@ -66,6 +66,22 @@ pub trait OnChargeTransaction<T: Config> {
 	) -> Result<(), TransactionValidityError>;
 }
 
+/// Decides which transactions get dispatched without paying a fee.
+///
+/// An exempt transaction pays neither the fee nor the tip. How many exempt transactions an
+/// account gets per block is capped by [`Config::FreeTransactionsPerBlock`], beyond that it pays
+/// as usual.
+pub trait FeeExemption<AccountId, Call> {
+	/// Whether `who` may dispatch `call` for free.
+	fn is_exempt(who: &AccountId, call: &Call) -> bool;
+}
+
+impl<AccountId, Call> FeeExemption<AccountId, Call> for () {
+	fn is_exempt(_: &AccountId, _: &Call) -> bool {
+		false
+	}
+}
+
 /// Implements the transaction payment for a pallet implementing the `Currency`
 /// trait (eg. the pallet_balances) using an unbalance handler (implementing
 /// `OnUnbalanced`).
@@ -27,7 +27,7 @@ use sp_runtime::{
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
-	traits::Currency,
+	traits::{Currency, Hooks},
 	weights::Weight,
 };
 use frame_system as system;
@@ -96,6 +96,7 @@ impl ExtBuilder {
 			} else {
 				vec![]
 			},
+			..Default::default()
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -841,3 +842,44 @@ fn genesis_default_works() {
 		assert_eq!(<NextFeeMultiplier<Runtime>>::get(), Multiplier::saturating_from_integer(1));
 	});
 }
+
+#[test]
+fn exempt_calls_are_free_up_to_the_block_quota() {
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	ExtBuilder::default().balance_factor(10).build().execute_with(|| {
+		ExemptAccounts::set(vec![1]);
+		let info = info_from_weight(Weight::from_parts(5, 0));
+		let len = 10;
+
+		// Only the filtered calls of the exempt accounts go for free.
+		assert!(!Pallet::<Runtime>::is_fee_exempt(&1, CALL));
+		assert!(!Pallet::<Runtime>::is_fee_exempt(&2, &remark));
+
+		for _ in 0..2 {
+			assert!(Pallet::<Runtime>::is_fee_exempt(&1, &remark));
+			let pre = ChargeTransactionPayment::<Runtime>::from(5)
+				.pre_dispatch(&1, &remark, &info, len)
+				.unwrap();
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				Some(pre),
+				&info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+			assert_eq!(Balances::free_balance(1), 100);
+		}
+		assert_eq!(FreeTransactions::<Runtime>::get(1), 2);
+
+		// The quota is used up, so the next one pays the fee and the tip.
+		assert!(!Pallet::<Runtime>::is_fee_exempt(&1, &remark));
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(5)
+			.pre_dispatch(&1, &remark, &info, len));
+		assert_eq!(Balances::free_balance(1), 100 - 5 - 5 - 10);
+
+		// It starts over in the next block.
+		TransactionPayment::on_finalize(1);
+		assert_eq!(FreeTransactions::<Runtime>::get(1), 0);
+		assert!(Pallet::<Runtime>::is_fee_exempt(&1, &remark));
+	});
+}
@@ -0,0 +1,43 @@
+//! Primitives for indexing transfers that carry a memo.
+//!
+//! The runtime reports the memo transfers of a block through [`MemoApi`]. A node-side indexer
+//! reads them for every finalized block and files them by memo and by destination account, so
+//! that the transfers can be looked up without scanning the chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// A transfer of the native currency or of an asset that carries a memo.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MemoTransfer<AccountId, Balance, AssetId> {
+	/// The index of the memo event among the events of its block.
+	pub event_index: u32,
+	/// The transferred asset, `None` for the native currency.
+	pub asset: Option<AssetId>,
+	/// The sender.
+	pub from: AccountId,
+	/// The receiver.
+	pub to: AccountId,
+	/// The amount that was transferred.
+	pub amount: Balance,
+	/// The memo attached to the transfer.
+	pub memo: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to read the transfers that carry a memo.
+	pub trait MemoApi<AccountId, Balance, AssetId>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+			AssetId: Codec,
+	{
+		/// Returns the transfers with a memo made in the block this is called at.
+		fn memo_transfers() -> Vec<MemoTransfer<AccountId, Balance, AssetId>>;
+	}
+}
@@ -137,6 +137,7 @@ pub use crate::{
 	trie_backend::{TrieBackend, TrieBackendBuilder},
 	trie_backend_essence::{Storage, TrieBackendStorage},
 };
+pub use sp_trie::MerkleValue;
 
 #[cfg(feature = "std")]
 mod std_reexport {
@@ -30,7 +30,7 @@ use codec::Codec;
 use hash_db::HashDB;
 use hash_db::Hasher;
 use sp_core::storage::{ChildInfo, StateVersion};
-use sp_trie::PrefixedMemoryDB;
+use sp_trie::{MerkleValue, PrefixedMemoryDB};
 #[cfg(feature = "std")]
 use sp_trie::{
 	cache::{LocalTrieCache, TrieCache},
@@ -397,6 +397,18 @@ where
 		self.essence.child_storage_hash(child_info, key)
 	}
 
+	fn closest_merkle_value(&self, key: &[u8]) -> Result<Option<MerkleValue<H::Out>>, Self::Error> {
+		self.essence.closest_merkle_value(key)
+	}
+
+	fn child_closest_merkle_value(
+		&self,
+		child_info: &ChildInfo,
+		key: &[u8],
+	) -> Result<Option<MerkleValue<H::Out>>, Self::Error> {
+		self.essence.child_closest_merkle_value(child_info, key)
+	}
+
 	fn child_storage(
 		&self,
 		child_info: &ChildInfo,
@@ -729,6 +741,37 @@ pub mod tests {
 		);
 	}
 
+	parameterized_test!(closest_merkle_value_works, closest_merkle_value_works_inner);
+	fn closest_merkle_value_works_inner(
+		state_version: StateVersion,
+		cache: Option<Cache>,
+		recorder: Option<Recorder>,
+	) {
+		let test_trie = test_trie(state_version, cache, recorder);
+		let child_info = ChildInfo::new_default(CHILD_KEY_1);
+
+		assert_eq!(
+			test_trie.closest_merkle_value(b"").unwrap(),
+			Some(MerkleValue::Hash(*test_trie.root()))
+		);
+		// `value1` and `value2` share the node closest to both prefixes.
+		assert!(test_trie.closest_merkle_value(b"value").unwrap().is_some());
+		assert_eq!(
+			test_trie.closest_merkle_value(b"valu").unwrap(),
+			test_trie.closest_merkle_value(b"value").unwrap()
+		);
+		assert_eq!(test_trie.closest_merkle_value(b"zzz").unwrap(), None);
+
+		assert!(test_trie.child_closest_merkle_value(&child_info, b"value3").unwrap().is_some());
+		assert_eq!(test_trie.child_closest_merkle_value(&child_info, b"key").unwrap(), None);
+		assert_eq!(
+			test_trie
+				.child_closest_merkle_value(&ChildInfo::new_default(b"unknown"), b"value3")
+				.unwrap(),
+			None
+		);
+	}
+
 	parameterized_test!(read_from_storage_returns_none, read_from_storage_returns_none_inner);
 	fn read_from_storage_returns_none_inner(
 		state_version: StateVersion,
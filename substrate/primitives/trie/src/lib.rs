@@ -45,13 +45,17 @@ pub use storage_proof::{CompactProof, StorageProof};
 /// for trie compact proof.
 pub use trie_codec::{decode_compact, encode_compact, Error as CompactProofError};
 pub use trie_db::proof::VerifyError;
-use trie_db::proof::{generate_proof, verify_proof};
+use trie_db::{
+	node::{Node, NodeHandle},
+	proof::{generate_proof, verify_proof},
+	NodeCodec as NodeCodecT,
+};
 /// Various re-exports from the `trie-db` crate.
 pub use trie_db::{
 	nibble_ops,
 	node::{NodePlan, ValuePlan},
-	CError, DBValue, Query, Recorder, Trie, TrieCache, TrieConfiguration, TrieDBIterator,
-	TrieDBKeyIterator, TrieDBRawIterator, TrieLayout, TrieMut, TrieRecorder,
+	CError, DBValue, NibbleSlice, Query, Recorder, Trie, TrieCache, TrieConfiguration,
+	TrieDBIterator, TrieDBKeyIterator, TrieDBRawIterator, TrieLayout, TrieMut, TrieRecorder,
 };
 /// The Substrate format implementation of `TrieStream`.
 pub use trie_stream::TrieStream;
@@ -176,6 +180,28 @@ pub type TrieDBMutBuilder<'a, L> = trie_db::TrieDBMutBuilder<'a, L>;
 pub type Lookup<'a, 'cache, L, Q> = trie_db::Lookup<'a, 'cache, L, Q>;
 /// Hash type for a trie layout.
 pub type TrieHash<L> = <<L as TrieLayout>::Hash as Hasher>::Out;
+
+/// The merkle value of a trie node.
+///
+/// Nodes whose encoding is shorter than a hash are inlined in their parent and referenced by
+/// their encoding, every other node is referenced by its hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MerkleValue<H> {
+	/// The encoding of a node inlined in its parent.
+	Node(Vec<u8>),
+	/// The hash of the node.
+	Hash(H),
+}
+
+impl<H: AsRef<[u8]>> AsRef<[u8]> for MerkleValue<H> {
+	fn as_ref(&self) -> &[u8] {
+		match self {
+			Self::Node(data) => data.as_slice(),
+			Self::Hash(hash) => hash.as_ref(),
+		}
+	}
+}
+
 /// This module is for non generic definition of trie type.
 /// Only the `Hasher` trait is generic in this case.
 pub mod trie_types {
@@ -309,6 +335,80 @@ pub fn read_trie_value_with<
 	TrieDBBuilder::<L>::new(db, root).build().get_with(key, query)
 }
 
+/// Read the merkle value of the closest descendant of `key` in the trie.
+///
+/// The closest descendant is the node with the shortest key that starts with `key`, which can be
+/// the node of `key` itself. Returns `None` if no key of the trie starts with `key`.
+pub fn read_trie_first_descendant_value<L, DB>(
+	db: &DB,
+	root: &TrieHash<L>,
+	key: &[u8],
+) -> Result<Option<MerkleValue<TrieHash<L>>>, Box<TrieError<L>>>
+where
+	L: TrieLayout,
+	DB: hash_db::HashDBRef<L::Hash, trie_db::DBValue>,
+{
+	let key = NibbleSlice::new(key);
+	let mut key_nibbles = 0;
+	// The hash of the last hashed node on the path, used to report errors of inline nodes.
+	let mut node_hash = *root;
+	let mut merkle_value = MerkleValue::Hash(*root);
+
+	loop {
+		let node_data = match &merkle_value {
+			MerkleValue::Hash(hash) => db
+				.get(hash, key.mid(key_nibbles).left())
+				.ok_or_else(|| Box::new(TrieError::<L>::IncompleteDatabase(*hash)))?,
+			MerkleValue::Node(data) => data.clone(),
+		};
+		let node = L::Codec::decode(&node_data)
+			.map_err(|e| Box::new(TrieError::<L>::DecoderError(node_hash, e)))?;
+
+		let partial = match &node {
+			Node::Empty => return Ok(None),
+			Node::Leaf(partial, _) |
+			Node::Extension(partial, _) |
+			Node::NibbledBranch(partial, _, _) => partial.clone(),
+			Node::Branch(_, _) => NibbleSlice::new(&[]),
+		};
+
+		// Either the partial key of the node covers what is left of `key`, making this node
+		// the closest descendant, or it must be a prefix of it to descend further.
+		let remaining = key.mid(key_nibbles);
+		if remaining.len() <= partial.len() {
+			return Ok(partial.starts_with(&remaining).then_some(merkle_value))
+		}
+		if !remaining.starts_with(&partial) {
+			return Ok(None)
+		}
+		key_nibbles += partial.len();
+
+		let child = match node {
+			Node::Extension(_, child) => Some(child),
+			Node::Branch(mut children, _) | Node::NibbledBranch(_, mut children, _) => {
+				let index = key.at(key_nibbles) as usize;
+				key_nibbles += 1;
+				children[index].take()
+			},
+			Node::Empty | Node::Leaf(_, _) => None,
+		};
+
+		merkle_value = match child {
+			Some(NodeHandle::Hash(data)) => {
+				let mut hash = TrieHash::<L>::default();
+				if data.len() != hash.as_ref().len() {
+					return Err(Box::new(TrieError::<L>::InvalidHash(node_hash, data.to_vec())))
+				}
+				hash.as_mut().copy_from_slice(data);
+				node_hash = hash;
+				MerkleValue::Hash(hash)
+			},
+			Some(NodeHandle::Inline(data)) => MerkleValue::Node(data.to_vec()),
+			None => return Ok(None),
+		};
+	}
+}
+
 /// Determine the empty trie root.
 pub fn empty_trie_root<L: TrieConfiguration>() -> <L::Hash as Hasher>::Out {
 	L::trie_root::<_, Vec<u8>, Vec<u8>>(core::iter::empty())
@@ -397,6 +497,20 @@ where
 		.get_hash(key)
 }
 
+/// Read the merkle value of the closest descendant of `key` in the child trie.
+pub fn read_child_trie_first_descendant_value<L: TrieConfiguration, DB>(
+	keyspace: &[u8],
+	db: &DB,
+	root: &TrieHash<L>,
+	key: &[u8],
+) -> Result<Option<MerkleValue<TrieHash<L>>>, Box<TrieError<L>>>
+where
+	DB: hash_db::HashDBRef<L::Hash, trie_db::DBValue>,
+{
+	let db = KeySpacedDB::new(db, keyspace);
+	read_trie_first_descendant_value::<L, _>(&db, root, key)
+}
+
 /// Read a value from the child trie with given query.
 pub fn read_child_trie_value_with<L, Q, DB>(
 	keyspace: &[u8],
@@ -860,6 +974,51 @@ mod tests {
 		assert_eq!(pairs, iter_pairs);
 	}
 
+	#[test]
+	fn first_descendant_value_works() {
+		first_descendant_value_works_inner::<LayoutV1>();
+		first_descendant_value_works_inner::<LayoutV0>();
+	}
+	fn first_descendant_value_works_inner<Layout: TrieConfiguration>() {
+		let pairs = vec![
+			(array_bytes::hex2bytes_unchecked("0102"), array_bytes::hex2bytes_unchecked("01")),
+			(array_bytes::hex2bytes_unchecked("0103"), vec![2; 64]),
+			(array_bytes::hex2bytes_unchecked("0203"), array_bytes::hex2bytes_unchecked("0405")),
+		];
+
+		let mut mdb = MemoryDB::default();
+		let mut root = Default::default();
+		let _ = populate_trie::<Layout>(&mut mdb, &mut root, &pairs);
+
+		let read = |key: &str| {
+			read_trie_first_descendant_value::<Layout, _>(
+				&mdb,
+				&root,
+				&array_bytes::hex2bytes_unchecked(key),
+			)
+			.unwrap()
+		};
+
+		// The root is the closest descendant of the empty key.
+		assert_eq!(read(""), Some(MerkleValue::Hash(root)));
+		// The branch at `010` holds a hashed leaf, so it is referenced by hash as well.
+		assert!(matches!(read("01"), Some(MerkleValue::Hash(hash)) if hash != root));
+		// Small leaves are inlined in their parent.
+		assert!(matches!(read("0102"), Some(MerkleValue::Node(_))));
+		// Leaves holding a large value are referenced by hash.
+		match read("0103") {
+			Some(MerkleValue::Hash(hash)) => assert!(mdb.contains(&hash, EMPTY_PREFIX)),
+			other => panic!("Expected a hashed node, got {:?}", other),
+		}
+		// A leaf is the closest descendant of any prefix of its key.
+		assert!(matches!(read("02"), Some(MerkleValue::Node(_))));
+		assert_eq!(read("02"), read("0203"));
+		// Keys without descendants.
+		assert_eq!(read("0104"), None);
+		assert_eq!(read("03"), None);
+		assert_eq!(read("010203"), None);
+	}
+
 	#[test]
 	fn proof_non_inclusion_works() {
 		let pairs = vec![